@@ -1,4 +1,6 @@
-use crate::pos::CodeRange;
+use num_bigint::BigInt;
+
+use crate::{Symbol, pos::CodeRange};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct StmtMultiNode {
@@ -16,8 +18,489 @@ pub struct StmtNode {
 // TODO: incomplete list of statement kinds
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum StmtKind {
-    // TODO: incomplete select structure
-    Select { select_list: Vec<ExprNode> },
+    /// An empty statement, which only appears for empty scripts.
+    Empty,
+    /// `SELECT` statement.
+    Select(Box<SelectNode>),
+    /// `INSERT` statement.
+    Insert(Box<InsertNode>),
+    /// A statement with a syntax error. The error has already been reported.
+    Error,
+}
+
+/// A query (`SelectStmt` in PostgreSQL).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SelectNode {
+    pub with: Option<WithClause>,
+    pub body: SelectBody,
+    pub order_by: Vec<SortByNode>,
+    /// `LIMIT` or `FETCH FIRST` count.
+    ///
+    /// As in PostgreSQL, `LIMIT ALL` is represented as `NULL`,
+    /// and `FETCH FIRST ROW ONLY` without a count as `1`.
+    pub limit: Option<ExprNode>,
+    pub offset: Option<ExprNode>,
+    /// Whether `FETCH FIRST ... WITH TIES` is specified.
+    pub with_ties: bool,
+    pub locking: Vec<LockingNode>,
+    pub range: CodeRange,
+}
+
+/// `WITH [RECURSIVE] cte, ...` before a statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WithClause {
+    pub recursive: bool,
+    pub ctes: Vec<CteNode>,
+    pub range: CodeRange,
+}
+
+/// A common table expression `name [(columns)] AS [[NOT] MATERIALIZED] (query)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CteNode {
+    pub name: Symbol,
+    pub columns: Vec<Symbol>,
+    pub materialized: CteMaterialized,
+    /// The query, which may also be a data-modifying statement.
+    pub query: Box<StmtNode>,
+    pub search: Option<CteSearch>,
+    pub cycle: Option<CteCycle>,
+    pub range: CodeRange,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CteMaterialized {
+    Default,
+    /// `MATERIALIZED`
+    Always,
+    /// `NOT MATERIALIZED`
+    Never,
+}
+
+/// `SEARCH {DEPTH | BREADTH} FIRST BY columns SET column`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CteSearch {
+    pub breadth_first: bool,
+    pub columns: Vec<Symbol>,
+    pub seq_column: Symbol,
+    pub range: CodeRange,
+}
+
+/// `CYCLE columns SET column [TO value DEFAULT value] USING column`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CteCycle {
+    pub columns: Vec<Symbol>,
+    pub mark_column: Symbol,
+    /// The values of the mark column, which are `true` and `false` if omitted.
+    pub mark_values: Option<(ExprNode, ExprNode)>,
+    pub path_column: Symbol,
+    pub range: CodeRange,
+}
+
+/// The part of a query before `ORDER BY`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SelectBody {
+    /// `SELECT ... FROM ... WHERE ...`
+    Simple(Box<SimpleSelect>),
+    /// `VALUES (...), (...)`
+    Values { rows: Vec<Vec<ExprNode>> },
+    /// `TABLE name`, a shorthand for `SELECT * FROM name`.
+    Table {
+        name: Vec<Symbol>,
+        /// Whether child tables are included, i.e. `ONLY` is not specified.
+        inherit: bool,
+    },
+    /// `left UNION right` and the like.
+    ///
+    /// The operands are queries because parenthesized ones can have their own `ORDER BY` and so on.
+    SetOp {
+        op: SetOp,
+        /// Whether `ALL` is specified, as opposed to `DISTINCT` or nothing.
+        all: bool,
+        left: Box<SelectNode>,
+        right: Box<SelectNode>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SetOp {
+    Union,
+    Intersect,
+    Except,
+}
+
+/// `simple_select` in gram.y, without set operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SimpleSelect {
+    pub distinct: Option<DistinctClause>,
+    /// Target list, which may be empty as in `SELECT FROM t`.
+    pub targets: Vec<TargetNode>,
+    pub from: Vec<TableRefNode>,
+    pub where_clause: Option<ExprNode>,
+    /// Whether `GROUP BY DISTINCT` is specified.
+    pub group_by_distinct: bool,
+    pub group_by: Vec<GroupByItem>,
+    pub having: Option<ExprNode>,
+    pub window: Vec<WindowDefNode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DistinctClause {
+    /// `DISTINCT`
+    Distinct,
+    /// `DISTINCT ON (exprs)`
+    DistinctOn { exprs: Vec<ExprNode> },
+}
+
+/// An item in a target list, such as `a + 1 AS b`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TargetNode {
+    /// The value, where `*` is represented as [`ExprKind::ColumnRef`] with no fields.
+    pub expr: ExprNode,
+    pub alias: Option<Symbol>,
+    pub range: CodeRange,
+}
+
+/// `INSERT INTO table ...` (`InsertStmt` in PostgreSQL).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InsertNode {
+    pub with: Option<WithClause>,
+    pub table: Vec<Symbol>,
+    /// `AS alias` after the table name.
+    pub alias: Option<Symbol>,
+    /// The columns to insert into, or empty for all columns.
+    pub columns: Vec<AssignTargetNode>,
+    /// `OVERRIDING {SYSTEM | USER} VALUE`
+    pub overriding: Option<OverridingKind>,
+    /// The query producing the rows, or `None` for `DEFAULT VALUES`.
+    pub source: Option<SelectNode>,
+    pub on_conflict: Option<OnConflictNode>,
+    /// The `RETURNING` list, or empty if omitted.
+    pub returning: Vec<TargetNode>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverridingKind {
+    System,
+    User,
+}
+
+/// A column to assign to, possibly with subscripts or fields such as `a[1].b`
+/// (`insert_column_item` and `set_target` in gram.y).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssignTargetNode {
+    pub name: Symbol,
+    pub indirection: Vec<IndirectionItem>,
+    pub range: CodeRange,
+}
+
+/// An assignment in `SET` (`set_clause`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SetClauseNode {
+    /// The columns, which are parenthesized as in `(a, b) = (1, 2)` if `multiple` is true.
+    pub targets: Vec<AssignTargetNode>,
+    pub multiple: bool,
+    pub value: ExprNode,
+    pub range: CodeRange,
+}
+
+/// `ON CONFLICT [target] DO {NOTHING | UPDATE ...}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OnConflictNode {
+    pub target: Option<ConflictTarget>,
+    pub action: ConflictAction,
+    pub range: CodeRange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConflictTarget {
+    /// `(index_elem, ...) [WHERE predicate]`, which infers a unique index.
+    Index {
+        elems: Vec<IndexElemNode>,
+        predicate: Option<ExprNode>,
+    },
+    /// `ON CONSTRAINT name`
+    Constraint(Symbol),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConflictAction {
+    /// `DO NOTHING`
+    Nothing,
+    /// `DO UPDATE SET ... [WHERE condition]`
+    Update {
+        set: Vec<SetClauseNode>,
+        where_clause: Option<ExprNode>,
+    },
+}
+
+/// A column or an expression of an index with its options (`index_elem`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IndexElemNode {
+    pub key: IndexKey,
+    /// `COLLATE name`, or empty if omitted.
+    pub collation: Vec<Symbol>,
+    /// The operator class, or empty if omitted.
+    pub opclass: Vec<Symbol>,
+    pub order: SortOrder,
+    pub nulls: NullsOrder,
+    pub range: CodeRange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IndexKey {
+    Column(Symbol),
+    /// A function call or a parenthesized expression.
+    Expr(ExprNode),
+}
+
+/// An item in a `FROM` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableRefNode {
+    pub kind: TableRefKind,
+    pub range: CodeRange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TableRefKind {
+    /// A table name, possibly qualified with the schema and the catalog.
+    Relation {
+        name: Vec<Symbol>,
+        /// Whether child tables are included, i.e. `ONLY` is not specified.
+        inherit: bool,
+        alias: Option<Alias>,
+        tablesample: Option<TableSample>,
+    },
+    /// A subquery `[LATERAL] (SELECT ...) [AS] alias`.
+    Subquery {
+        lateral: bool,
+        query: Box<SelectNode>,
+        alias: Option<Alias>,
+    },
+    /// A function call such as `generate_series(1, 10)`, or `ROWS FROM (...)`.
+    Function {
+        lateral: bool,
+        /// The functions, which are more than one only for `ROWS FROM`.
+        functions: Vec<RangeFunction>,
+        rows_from: bool,
+        /// Whether `WITH ORDINALITY` is specified.
+        ordinality: bool,
+        alias: Option<Alias>,
+        /// Column definitions of a function returning `record`, as in `AS x(a int)` or
+        /// `AS (a int)`. The alias, if any, has no column names in this case.
+        column_defs: Vec<ColumnDefNode>,
+    },
+    /// `[LATERAL] JSON_TABLE(...) [alias]`.
+    JsonTable {
+        lateral: bool,
+        table: Box<JsonTable>,
+        alias: Option<Alias>,
+    },
+    /// A joined table, such as `a LEFT JOIN b ON a.x = b.x`.
+    Join {
+        kind: JoinKind,
+        natural: bool,
+        left: Box<TableRefNode>,
+        right: Box<TableRefNode>,
+        /// The join condition, absent for `CROSS JOIN` and `NATURAL JOIN`.
+        qual: Option<JoinQual>,
+        /// Alias of a parenthesized join, as in `(a JOIN b USING (x)) AS j`.
+        alias: Option<Alias>,
+    },
+}
+
+/// `[AS] name [(columns)]` after a table reference.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Alias {
+    pub name: Symbol,
+    pub columns: Vec<Symbol>,
+}
+
+/// A function in a function table, or an item in `ROWS FROM (...)` (`rowsfrom_item`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeFunction {
+    pub call: ExprNode,
+    /// `AS (name type, ...)` after an item in `ROWS FROM`, or empty if omitted.
+    pub column_defs: Vec<ColumnDefNode>,
+}
+
+/// `name type [COLLATE collation]` in the column definitions of a function table
+/// (`TableFuncElement`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColumnDefNode {
+    pub name: Symbol,
+    pub type_name: TypeName,
+    /// `COLLATE name`, or empty if omitted.
+    pub collation: Vec<Symbol>,
+    pub range: CodeRange,
+}
+
+/// `TABLESAMPLE method (args) [REPEATABLE (seed)]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableSample {
+    pub method: Vec<Symbol>,
+    pub args: Vec<ExprNode>,
+    pub repeatable: Option<ExprNode>,
+    pub range: CodeRange,
+}
+
+/// The join type. `CROSS JOIN` is an inner join without a condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoinKind {
+    Inner,
+    Left,
+    Right,
+    Full,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JoinQual {
+    /// `ON expr`
+    On { expr: ExprNode },
+    /// `USING (columns) [AS alias]`
+    Using {
+        columns: Vec<Symbol>,
+        alias: Option<Symbol>,
+    },
+}
+
+/// An item in `GROUP BY`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GroupByItem {
+    Expr(ExprNode),
+    GroupingSet(GroupingSetNode),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GroupingSetNode {
+    pub kind: GroupingSetKind,
+    pub range: CodeRange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GroupingSetKind {
+    /// `()`
+    Empty,
+    /// `ROLLUP (exprs)`
+    Rollup { exprs: Vec<ExprNode> },
+    /// `CUBE (exprs)`
+    Cube { exprs: Vec<ExprNode> },
+    /// `GROUPING SETS (items)`
+    Sets { items: Vec<GroupByItem> },
+}
+
+/// A window definition in `WINDOW name AS (...)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowDefNode {
+    /// The name being defined.
+    pub name: Option<Symbol>,
+    /// The existing window to copy from, as in `(w ORDER BY a)`.
+    pub refname: Option<Symbol>,
+    pub partition_by: Vec<ExprNode>,
+    pub order_by: Vec<SortByNode>,
+    pub frame: Option<WindowFrame>,
+    pub range: CodeRange,
+}
+
+/// `{RANGE | ROWS | GROUPS} BETWEEN start AND end [EXCLUDE ...]` in a window definition.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowFrame {
+    pub mode: FrameMode,
+    pub start: FrameBound,
+    /// The end of the frame, which is `CURRENT ROW` if only the start is given.
+    pub end: FrameBound,
+    pub exclusion: FrameExclusion,
+    pub range: CodeRange,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameMode {
+    Range,
+    Rows,
+    Groups,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    /// `offset PRECEDING`
+    Preceding {
+        offset: Box<ExprNode>,
+    },
+    CurrentRow,
+    /// `offset FOLLOWING`
+    Following {
+        offset: Box<ExprNode>,
+    },
+    UnboundedFollowing,
+}
+
+/// `EXCLUDE ...` in a window frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameExclusion {
+    /// `EXCLUDE NO OTHERS`, the default
+    NoOthers,
+    CurrentRow,
+    Group,
+    Ties,
+}
+
+/// An item in `ORDER BY`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SortByNode {
+    pub expr: ExprNode,
+    pub order: SortOrder,
+    pub nulls: NullsOrder,
+    pub range: CodeRange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    Default,
+    Asc,
+    Desc,
+    /// `USING op`
+    Using {
+        op: OperatorName,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NullsOrder {
+    Default,
+    First,
+    Last,
+}
+
+/// A locking clause such as `FOR UPDATE OF t NOWAIT`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LockingNode {
+    pub strength: LockStrength,
+    /// Tables listed in `OF`, or empty for all tables.
+    pub tables: Vec<Vec<Symbol>>,
+    pub wait_policy: LockWaitPolicy,
+    pub range: CodeRange,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LockStrength {
+    /// `FOR KEY SHARE`
+    KeyShare,
+    /// `FOR SHARE`
+    Share,
+    /// `FOR NO KEY UPDATE`
+    NoKeyUpdate,
+    /// `FOR UPDATE`
+    Update,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LockWaitPolicy {
+    /// Wait for the lock (default)
+    Block,
+    /// `SKIP LOCKED`
+    Skip,
+    /// `NOWAIT`
+    Error,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -29,5 +512,595 @@ pub struct ExprNode {
 // TODO: incomplete list of expression kinds
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ExprKind {
-    IntegerLiteral { value: i64 },
+    IntegerLiteral {
+        value: i64,
+    },
+    /// Numeric literal whose value is `mantissa * 10^(-scale)`,
+    /// including integer literals too large for i64.
+    NumericLiteral {
+        mantissa: BigInt,
+        scale: i64,
+    },
+    /// Character string literal, including dollar-quoted strings.
+    StringLiteral {
+        value: String,
+    },
+    /// Bit-string literal `B'0101'`, or `X'1F'` when `hex` is true.
+    /// The value is the raw content between the quotes.
+    BitStringLiteral {
+        value: String,
+        hex: bool,
+    },
+    /// `TRUE` or `FALSE`.
+    BooleanLiteral {
+        value: bool,
+    },
+    /// `NULL`.
+    NullLiteral,
+    /// Positional parameter `$n`.
+    Param {
+        index: u32,
+    },
+    /// `DEFAULT` as a value in `VALUES` or `SET` (`SetToDefault` in PostgreSQL).
+    Default,
+    /// Column reference such as `a`, `s.t.a`, or `t.*`.
+    ///
+    /// Subscripts and the names following them are represented by [`ExprKind::Indirection`],
+    /// as in PostgreSQL.
+    ColumnRef {
+        /// Dotted name components.
+        fields: Vec<Symbol>,
+        /// Whether the reference ends with `.*`.
+        star: bool,
+    },
+    /// Subscripts and field selections, such as `a[1]`, `a[1:2].b`, or `(f(x)).*`.
+    Indirection {
+        operand: Box<ExprNode>,
+        /// The subscripts and fields, which end with [`IndirectionItem::Star`] if any.
+        items: Vec<IndirectionItem>,
+    },
+    /// `ARRAY[a, b]`. Nested arrays such as `[1, 2]` in `ARRAY[[1, 2], [3, 4]]` are also
+    /// represented as arrays.
+    Array {
+        elements: Vec<ExprNode>,
+    },
+    /// `ARRAY(SELECT ...)`
+    ArraySubquery {
+        query: Box<SelectNode>,
+    },
+    /// A scalar subquery `(SELECT ...)`.
+    ScalarSubquery {
+        query: Box<SelectNode>,
+    },
+    /// `EXISTS (SELECT ...)`
+    Exists {
+        query: Box<SelectNode>,
+    },
+    /// `ROW(a, b)`, or `(a, b)` when `explicit` is false.
+    Row {
+        fields: Vec<ExprNode>,
+        explicit: bool,
+    },
+    /// Function call such as `f(a, b)` or `count(*) OVER w`.
+    FuncCall(Box<FuncCall>),
+    /// Named argument in a function call, such as `b => 1` in `f(a, b => 1)`.
+    NamedArg {
+        name: Symbol,
+        value: Box<ExprNode>,
+    },
+    /// Prefix operator application, such as `-a`.
+    UnaryOp {
+        op: OperatorName,
+        operand: Box<ExprNode>,
+    },
+    /// Binary operator application, such as `a + b` or `a < b`.
+    BinaryOp {
+        op: OperatorName,
+        left: Box<ExprNode>,
+        right: Box<ExprNode>,
+    },
+    /// `a AND b`.
+    And {
+        left: Box<ExprNode>,
+        right: Box<ExprNode>,
+    },
+    /// `a OR b`.
+    Or {
+        left: Box<ExprNode>,
+        right: Box<ExprNode>,
+    },
+    /// `NOT a`.
+    Not {
+        operand: Box<ExprNode>,
+    },
+    /// `a IS [NOT] NULL`, also written as `a ISNULL` or `a NOTNULL`.
+    IsNull {
+        operand: Box<ExprNode>,
+        negated: bool,
+    },
+    /// `a IS [NOT] TRUE`, `a IS [NOT] FALSE`, or `a IS [NOT] UNKNOWN`.
+    IsBoolean {
+        operand: Box<ExprNode>,
+        /// The tested value, where `None` stands for `UNKNOWN`.
+        value: Option<bool>,
+        negated: bool,
+    },
+    /// `a IS [NOT] DISTINCT FROM b`.
+    IsDistinctFrom {
+        left: Box<ExprNode>,
+        right: Box<ExprNode>,
+        negated: bool,
+    },
+    /// `a [NOT] BETWEEN [SYMMETRIC] low AND high`.
+    Between {
+        operand: Box<ExprNode>,
+        low: Box<ExprNode>,
+        high: Box<ExprNode>,
+        negated: bool,
+        symmetric: bool,
+    },
+    /// `a [NOT] IN (list)`.
+    InList {
+        operand: Box<ExprNode>,
+        list: Vec<ExprNode>,
+        negated: bool,
+    },
+    /// `a [NOT] IN (SELECT ...)`.
+    InSubquery {
+        operand: Box<ExprNode>,
+        query: Box<SelectNode>,
+        negated: bool,
+    },
+    /// `a op ANY (SELECT ...)` or `a op ALL (SELECT ...)`, where `SOME` is a synonym for `ANY`.
+    ///
+    /// As in PostgreSQL, `LIKE` and `ILIKE` are represented by their operator names,
+    /// such as `!~~` for `NOT LIKE`.
+    QuantifiedSubquery {
+        op: OperatorName,
+        all: bool,
+        operand: Box<ExprNode>,
+        query: Box<SelectNode>,
+    },
+    /// `a op ANY (array)` or `a op ALL (array)`, in the same way as
+    /// [`ExprKind::QuantifiedSubquery`].
+    QuantifiedArray {
+        op: OperatorName,
+        all: bool,
+        operand: Box<ExprNode>,
+        array: Box<ExprNode>,
+    },
+    /// `a [NOT] LIKE b [ESCAPE c]`, and its `ILIKE` and `SIMILAR TO` variants.
+    Like {
+        kind: LikeKind,
+        operand: Box<ExprNode>,
+        pattern: Box<ExprNode>,
+        escape: Option<Box<ExprNode>>,
+        negated: bool,
+    },
+    /// `a AT TIME ZONE zone`, or `a AT LOCAL` when `zone` is `None`.
+    AtTimeZone {
+        operand: Box<ExprNode>,
+        zone: Option<Box<ExprNode>>,
+    },
+    /// `CASE [operand] WHEN ... THEN ... [ELSE ...] END`.
+    Case {
+        /// The value compared with each `WHEN` value, absent for a searched `CASE`.
+        operand: Option<Box<ExprNode>>,
+        whens: Vec<CaseWhen>,
+        default: Option<Box<ExprNode>>,
+    },
+    /// `COALESCE(a, b, ...)`.
+    Coalesce {
+        args: Vec<ExprNode>,
+    },
+    /// `NULLIF(a, b)`.
+    NullIf {
+        left: Box<ExprNode>,
+        right: Box<ExprNode>,
+    },
+    /// `GREATEST(a, b, ...)`.
+    Greatest {
+        args: Vec<ExprNode>,
+    },
+    /// `LEAST(a, b, ...)`.
+    Least {
+        args: Vec<ExprNode>,
+    },
+    /// `CURRENT_DATE`, `CURRENT_TIME`, `LOCALTIMESTAMP(3)`, and the like.
+    ///
+    /// The other keywords of this kind such as `CURRENT_USER` are represented as function calls,
+    /// as in PostgreSQL.
+    SqlValueFunction {
+        kind: SqlValueFunctionKind,
+        /// The precision in parentheses, as in `CURRENT_TIME(3)`.
+        precision: Option<i32>,
+    },
+    /// `MERGE_ACTION()`, the action taken for the current row by `MERGE ... RETURNING`.
+    MergeAction,
+    /// `a COLLATE name`.
+    Collate {
+        operand: Box<ExprNode>,
+        collation: Vec<Symbol>,
+    },
+    /// `CAST(a AS t)`, `a::t`, or a typed literal such as `DATE '2000-01-01'`,
+    /// whose operand is the string literal.
+    TypeCast {
+        operand: Box<ExprNode>,
+        type_name: Box<TypeName>,
+    },
+    /// `a IS [NOT] JSON [VALUE | ARRAY | OBJECT | SCALAR] [WITH UNIQUE KEYS]`.
+    IsJson {
+        operand: Box<ExprNode>,
+        item_type: JsonValueType,
+        /// Whether `WITH UNIQUE [KEYS]` is specified.
+        unique_keys: bool,
+        negated: bool,
+    },
+    /// `JSON_OBJECT(key : value, ...)`.
+    ///
+    /// As in PostgreSQL, `JSON_OBJECT` with ordinary arguments is a call to
+    /// `pg_catalog.json_object`.
+    JsonObject(Box<JsonObjectConstructor>),
+    /// `JSON_ARRAY(value, ...)`.
+    JsonArray(Box<JsonArrayConstructor>),
+    /// `JSON_ARRAY(SELECT ...)`.
+    JsonArrayQuery(Box<JsonArrayQueryConstructor>),
+    /// `JSON_OBJECTAGG(key : value)`, possibly with `FILTER` and `OVER`.
+    JsonObjectAgg(Box<JsonObjectAgg>),
+    /// `JSON_ARRAYAGG(value [ORDER BY ...])`, possibly with `FILTER` and `OVER`.
+    JsonArrayAgg(Box<JsonArrayAgg>),
+    /// `JSON(a [WITH UNIQUE KEYS])`.
+    JsonParse {
+        value: Box<JsonValueExpr>,
+        /// Whether `WITH UNIQUE [KEYS]` is specified.
+        unique_keys: bool,
+    },
+    /// `JSON_SCALAR(a)`.
+    JsonScalar {
+        operand: Box<ExprNode>,
+    },
+    /// `JSON_SERIALIZE(a [RETURNING t])`.
+    JsonSerialize {
+        value: Box<JsonValueExpr>,
+        output: Option<Box<JsonOutput>>,
+    },
+    /// `JSON_EXISTS(...)`, `JSON_QUERY(...)`, or `JSON_VALUE(...)`.
+    JsonFunc(Box<JsonFuncExpr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqlValueFunctionKind {
+    CurrentDate,
+    CurrentTime,
+    CurrentTimestamp,
+    LocalTime,
+    LocalTimestamp,
+}
+
+/// `WHEN condition THEN result` in `CASE`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CaseWhen {
+    /// The condition, or the value compared with the operand of `CASE` if any.
+    pub condition: ExprNode,
+    pub result: ExprNode,
+    pub range: CodeRange,
+}
+
+/// A subscript or a field selection (`indirection_el` in gram.y).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IndirectionItem {
+    /// `.name`
+    Field(Symbol),
+    /// `.*`
+    Star,
+    /// `[index]`
+    Subscript(Box<ExprNode>),
+    /// `[lower:upper]`, where either bound can be omitted.
+    Slice {
+        lower: Option<Box<ExprNode>>,
+        upper: Option<Box<ExprNode>>,
+    },
+}
+
+/// A function call (`func_expr` in gram.y).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FuncCall {
+    pub name: Vec<Symbol>,
+    pub args: Vec<ExprNode>,
+    /// Whether the argument is `*`, as in `count(*)`.
+    pub star: bool,
+    /// Whether `DISTINCT` is specified before the arguments, as in `count(DISTINCT a)`.
+    pub distinct: bool,
+    /// Whether the last argument is marked `VARIADIC`.
+    pub variadic: bool,
+    /// `ORDER BY` in the arguments or in `WITHIN GROUP`.
+    pub order_by: Vec<SortByNode>,
+    /// Whether `order_by` comes from `WITHIN GROUP (ORDER BY ...)`.
+    pub within_group: bool,
+    /// `FILTER (WHERE ...)`
+    pub filter: Option<ExprNode>,
+    /// `OVER name` or `OVER (...)`. The former is represented with `name` set.
+    pub over: Option<WindowDefNode>,
+    /// Whether the call is written in a special SQL syntax such as `EXTRACT(field FROM a)`
+    /// or `CURRENT_USER`, in which case `name` is the underlying system function.
+    pub sql_syntax: bool,
+}
+
+/// A type name (`Typename` in gram.y), such as `int`, `varchar(10)[]`, or `SETOF s.t`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeName {
+    /// The name, possibly qualified.
+    ///
+    /// As in PostgreSQL, the SQL-standard forms are resolved to the system type names,
+    /// so that `double precision` is `pg_catalog.float8` and `int` is `pg_catalog.int4`.
+    pub name: Vec<Symbol>,
+    /// Type modifiers such as the length in `varchar(10)` or the precision in `time(3)`.
+    ///
+    /// As in PostgreSQL, `char` and `bit` without a length have the length 1,
+    /// except in typed literals.
+    pub modifiers: Vec<ExprNode>,
+    /// Fields of an `INTERVAL` type, such as `DAY TO SECOND`.
+    pub interval_fields: Option<IntervalFields>,
+    /// Array dimensions, where `None` stands for an unspecified size as in `int[]`.
+    pub array_bounds: Vec<Option<i32>>,
+    pub setof: bool,
+    /// Whether the name is followed by `%TYPE`, referring to the type of a column.
+    pub pct_type: bool,
+    pub range: CodeRange,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalFields {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    YearToMonth,
+    DayToHour,
+    DayToMinute,
+    DayToSecond,
+    HourToMinute,
+    HourToSecond,
+    MinuteToSecond,
+}
+
+/// An input of SQL/JSON functions with an optional format (`json_value_expr`),
+/// such as `a FORMAT JSON`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonValueExpr {
+    pub expr: ExprNode,
+    pub format: Option<JsonFormat>,
+}
+
+/// `FORMAT JSON [ENCODING name]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonFormat {
+    pub encoding: Option<JsonEncoding>,
+    pub range: CodeRange,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonEncoding {
+    Utf8,
+    Utf16,
+    Utf32,
+}
+
+/// `RETURNING t [FORMAT JSON]` in SQL/JSON functions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonOutput {
+    pub type_name: TypeName,
+    pub format: Option<JsonFormat>,
+}
+
+/// `key : value` or `key VALUE value` in `JSON_OBJECT` (`json_name_and_value`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonKeyValue {
+    pub key: ExprNode,
+    pub value: JsonValueExpr,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonObjectConstructor {
+    pub items: Vec<JsonKeyValue>,
+    /// Whether `ABSENT ON NULL` is specified, as opposed to `NULL ON NULL` (the default).
+    pub absent_on_null: bool,
+    /// Whether `WITH UNIQUE [KEYS]` is specified.
+    pub unique_keys: bool,
+    pub output: Option<JsonOutput>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonArrayConstructor {
+    pub elements: Vec<JsonValueExpr>,
+    /// Whether `ABSENT ON NULL` (the default) is in effect, as opposed to `NULL ON NULL`.
+    pub absent_on_null: bool,
+    pub output: Option<JsonOutput>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonArrayQueryConstructor {
+    pub query: SelectNode,
+    /// `FORMAT JSON` after the query.
+    pub format: Option<JsonFormat>,
+    pub output: Option<JsonOutput>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonObjectAgg {
+    pub item: JsonKeyValue,
+    /// Whether `ABSENT ON NULL` is specified, as opposed to `NULL ON NULL` (the default).
+    pub absent_on_null: bool,
+    /// Whether `WITH UNIQUE [KEYS]` is specified.
+    pub unique_keys: bool,
+    pub output: Option<JsonOutput>,
+    pub filter: Option<ExprNode>,
+    pub over: Option<WindowDefNode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonArrayAgg {
+    pub element: JsonValueExpr,
+    pub order_by: Vec<SortByNode>,
+    /// Whether `ABSENT ON NULL` (the default) is in effect, as opposed to `NULL ON NULL`.
+    pub absent_on_null: bool,
+    pub output: Option<JsonOutput>,
+    pub filter: Option<ExprNode>,
+    pub over: Option<WindowDefNode>,
+}
+
+/// `JSON_EXISTS`, `JSON_QUERY`, or `JSON_VALUE`, which apply a path to a JSON value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonFuncExpr {
+    pub op: JsonFuncOp,
+    pub context: JsonValueExpr,
+    pub path: ExprNode,
+    /// `PASSING value AS name, ...`
+    pub passing: Vec<JsonArgument>,
+    pub output: Option<JsonOutput>,
+    /// The wrapper behavior, only given for `JSON_QUERY`.
+    pub wrapper: JsonWrapper,
+    /// The quotes behavior, only given for `JSON_QUERY`.
+    pub quotes: JsonQuotes,
+    /// `... ON EMPTY`, not given for `JSON_EXISTS`.
+    pub on_empty: Option<JsonBehavior>,
+    /// `... ON ERROR`
+    pub on_error: Option<JsonBehavior>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonFuncOp {
+    Exists,
+    Query,
+    Value,
+}
+
+/// `value AS name` in `PASSING`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonArgument {
+    pub value: JsonValueExpr,
+    pub name: Symbol,
+}
+
+/// `WITH [CONDITIONAL | UNCONDITIONAL] [ARRAY] WRAPPER` or `WITHOUT [ARRAY] WRAPPER`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonWrapper {
+    Unspecified,
+    /// `WITHOUT WRAPPER`
+    None,
+    /// `WITH CONDITIONAL WRAPPER`
+    Conditional,
+    /// `WITH [UNCONDITIONAL] WRAPPER`
+    Unconditional,
+}
+
+/// `{KEEP | OMIT} QUOTES [ON SCALAR STRING]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonQuotes {
+    Unspecified,
+    Keep,
+    Omit,
+}
+
+/// The behavior in `... ON EMPTY` or `... ON ERROR`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonBehavior {
+    pub kind: JsonBehaviorKind,
+    pub range: CodeRange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonBehaviorKind {
+    Null,
+    Error,
+    True,
+    False,
+    Unknown,
+    /// `EMPTY ARRAY`, also written as `EMPTY`
+    EmptyArray,
+    /// `EMPTY OBJECT`
+    EmptyObject,
+    /// `DEFAULT value`
+    Default(Box<ExprNode>),
+}
+
+/// The type tested by `IS JSON`, where `JSON VALUE` is the same as `JSON`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonValueType {
+    Any,
+    Object,
+    Array,
+    Scalar,
+}
+
+/// `JSON_TABLE(value, path [AS name] [PASSING ...] COLUMNS (...) [ON ERROR])` in `FROM`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonTable {
+    pub context: JsonValueExpr,
+    pub path: JsonTablePath,
+    /// `PASSING value AS name, ...`
+    pub passing: Vec<JsonArgument>,
+    pub columns: Vec<JsonTableColumn>,
+    pub on_error: Option<JsonBehavior>,
+}
+
+/// A path string in `JSON_TABLE` with an optional name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonTablePath {
+    pub path: String,
+    pub name: Option<Symbol>,
+    pub range: CodeRange,
+}
+
+/// A column definition in `JSON_TABLE`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonTableColumn {
+    pub kind: JsonTableColumnKind,
+    pub range: CodeRange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonTableColumnKind {
+    /// `name FOR ORDINALITY`
+    Ordinality { name: Symbol },
+    /// `name type [FORMAT JSON] [PATH path] [wrapper] [quotes] [behaviors]`
+    Regular {
+        name: Symbol,
+        type_name: TypeName,
+        format: Option<JsonFormat>,
+        path: Option<String>,
+        wrapper: JsonWrapper,
+        quotes: JsonQuotes,
+        on_empty: Option<JsonBehavior>,
+        on_error: Option<JsonBehavior>,
+    },
+    /// `name type EXISTS [PATH path] [ON ERROR]`
+    Exists {
+        name: Symbol,
+        type_name: TypeName,
+        path: Option<String>,
+        on_error: Option<JsonBehavior>,
+    },
+    /// `NESTED [PATH] path [AS name] COLUMNS (...)`
+    Nested {
+        path: JsonTablePath,
+        columns: Vec<JsonTableColumn>,
+    },
+}
+
+/// Operator name, such as `+`, `<->`, or `OPERATOR(pg_catalog.+)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OperatorName {
+    /// Schema qualification, only given through the `OPERATOR(...)` syntax.
+    pub schema: Vec<Symbol>,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LikeKind {
+    Like,
+    ILike,
+    SimilarTo,
 }
@@ -2,7 +2,10 @@ use std::{error::Error, fmt};
 
 use thiserror::Error;
 
-use crate::pos::CodeRange;
+use crate::{
+    pos::CodeRange,
+    render::{RenderStyle, render_diagnostic},
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CodeError {
@@ -23,12 +26,13 @@ impl fmt::Display for CodeError {
             writeln!(f, "No errors")?;
             return Ok(());
         } else if self.diagnostics.len() == 1 {
-            writeln!(f, "{}", self.diagnostics[0])?;
+            let diag = &self.diagnostics[0];
+            writeln!(f, "{}: {}", diag.severity(), diag)?;
             return Ok(());
         }
-        writeln!(f, "{} errors found:", self.diagnostics.len())?;
+        writeln!(f, "{} diagnostics found:", self.diagnostics.len())?;
         for diag in &self.diagnostics {
-            writeln!(f, "{}", diag)?;
+            writeln!(f, "{}: {}", diag.severity(), diag)?;
         }
         Ok(())
     }
@@ -36,7 +40,21 @@ impl fmt::Display for CodeError {
 
 impl Error for CodeError {}
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+impl CodeError {
+    pub fn diagnostics(&self) -> &[CodeDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Renders all diagnostics with source snippets. See [`render_diagnostic`].
+    pub fn render(&self, src: &str, style: RenderStyle) -> String {
+        self.diagnostics
+            .iter()
+            .map(|diag| render_diagnostic(src, diag, style))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub struct CodeDiagnostics {
     pub diagnostics: Vec<CodeDiagnostic>,
 }
@@ -51,8 +69,14 @@ impl CodeDiagnostics {
     }
 
     pub fn has_errors(&self) -> bool {
-        // TODO: distinguish warnings from errors
-        !self.diagnostics.is_empty()
+        self.diagnostics.iter().any(CodeDiagnostic::is_error)
+    }
+
+    /// Iterates over the diagnostics of the given severity.
+    pub fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &CodeDiagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(move |diag| diag.severity() == severity)
     }
 
     pub fn check_errors(self) -> Result<Self, CodeError> {
@@ -64,20 +88,157 @@ impl CodeDiagnostics {
     }
 }
 
-impl Default for CodeDiagnostics {
-    fn default() -> Self {
-        Self {
-            diagnostics: Vec::new(),
-        }
+/// Severity of a diagnostic, corresponding to PostgreSQL's message levels.
+///
+/// Only [`Severity::Error`] prevents the code from being processed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    Error,
+    Warning,
+    Notice,
+    Info,
+    Debug,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Error => "ERROR",
+            Severity::Warning => "WARNING",
+            Severity::Notice => "NOTICE",
+            Severity::Info => "INFO",
+            Severity::Debug => "DEBUG",
+        })
     }
 }
 
+/// The fields of an ErrorResponse or NoticeResponse message in the PostgreSQL protocol.
+///
+/// See <https://www.postgresql.org/docs/current/protocol-error-fields.html>.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ErrorFields {
+    /// `S` and `V`
+    pub severity: Severity,
+    /// `C`
+    pub sqlstate: &'static str,
+    /// `M`
+    pub message: String,
+    /// `D`
+    pub detail: Option<String>,
+    /// `H`
+    pub hint: Option<String>,
+    /// `P`, 1-based and counted in characters
+    pub position: Option<usize>,
+    /// `W`, a call stack traceback, filled in by the caller if needed
+    pub context: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
 pub enum CodeDiagnostic {
     #[error("unknown token")]
     UnknownToken { range: CodeRange },
-    #[error("unexpected end of input")]
+    #[error("syntax error at end of input")]
     UnexpectedEof { range: CodeRange },
+    #[error("syntax error at or near \"{near}\"")]
+    SyntaxError { range: CodeRange, near: String },
+    #[error("trailing junk after numeric literal")]
+    TrailingJunkAfterNumeric { range: CodeRange },
+    #[error("invalid hexadecimal integer")]
+    InvalidHexadecimalInteger { range: CodeRange },
+    #[error("invalid octal integer")]
+    InvalidOctalInteger { range: CodeRange },
+    #[error("invalid binary integer")]
+    InvalidBinaryInteger { range: CodeRange },
+    #[error("value overflows numeric format")]
+    NumericOverflow { range: CodeRange },
+    #[error("trailing junk after parameter")]
+    TrailingJunkAfterParameter { range: CodeRange },
+    #[error("parameter number too large")]
+    ParameterNumberTooLarge { range: CodeRange },
+    #[error("identifier \"{original}\" will be truncated to \"{truncated}\"")]
+    IdentifierTruncated {
+        range: CodeRange,
+        original: String,
+        truncated: String,
+    },
+    #[error("unterminated /* comment")]
+    UnterminatedBlockComment { range: CodeRange },
+    #[error("unterminated quoted string")]
+    UnterminatedString { range: CodeRange },
+    #[error("unterminated dollar-quoted string")]
+    UnterminatedDollarString { range: CodeRange },
+    #[error("unterminated bit string literal")]
+    UnterminatedBitString { range: CodeRange },
+    #[error("unterminated hexadecimal string literal")]
+    UnterminatedHexString { range: CodeRange },
+    #[error("unterminated quoted identifier")]
+    UnterminatedQuotedIdentifier { range: CodeRange },
+    #[error("zero-length delimited identifier")]
+    ZeroLengthIdentifier { range: CodeRange },
+    #[error("UESCAPE must be followed by a simple string literal")]
+    UescapeWithoutString { range: CodeRange },
+    #[error("invalid Unicode escape character")]
+    InvalidUnicodeEscapeCharacter { range: CodeRange },
+    /// Malformed `\uXXXX` or `\UXXXXXXXX` in `E'...'`.
+    #[error("invalid Unicode escape")]
+    InvalidUnicodeEscape { range: CodeRange },
+    /// Malformed `\XXXX` or `\+XXXXXX` in `U&'...'` or `U&"..."`.
+    #[error("invalid Unicode escape")]
+    InvalidUnicodeLiteralEscape { range: CodeRange },
+    #[error("invalid Unicode escape value")]
+    InvalidUnicodeEscapeValue { range: CodeRange },
+    #[error("invalid Unicode surrogate pair")]
+    InvalidUnicodeSurrogatePair { range: CodeRange },
+    #[error("invalid byte sequence for encoding \"UTF8\"")]
+    InvalidByteSequence { range: CodeRange },
+    #[error("improper qualified name (too many dotted names): {name}")]
+    ImproperQualifiedName { range: CodeRange, name: String },
+    #[error("LIMIT #,# syntax is not supported")]
+    LimitCommaNotSupported { range: CodeRange },
+    #[error("WITH TIES cannot be specified without ORDER BY clause")]
+    WithTiesWithoutOrderBy { range: CodeRange },
+    #[error("SKIP LOCKED and WITH TIES options cannot be used together")]
+    SkipLockedWithTies { range: CodeRange },
+    #[error("cannot use multiple ORDER BY clauses with WITHIN GROUP")]
+    MultipleOrderByWithWithinGroup { range: CodeRange },
+    #[error("cannot use DISTINCT with WITHIN GROUP")]
+    DistinctWithWithinGroup { range: CodeRange },
+    #[error("cannot use VARIADIC with WITHIN GROUP")]
+    VariadicWithWithinGroup { range: CodeRange },
+    #[error("frame start cannot be UNBOUNDED FOLLOWING")]
+    FrameStartUnboundedFollowing { range: CodeRange },
+    #[error("frame end cannot be UNBOUNDED PRECEDING")]
+    FrameEndUnboundedPreceding { range: CodeRange },
+    #[error("frame starting from current row cannot have preceding rows")]
+    FrameCurrentRowWithPreceding { range: CodeRange },
+    #[error("frame starting from following row cannot have preceding rows")]
+    FrameFollowingWithPreceding { range: CodeRange },
+    #[error("frame starting from following row cannot end with current row")]
+    FrameFollowingEndsWithCurrentRow { range: CodeRange },
+    #[error("type modifier cannot have parameter name")]
+    TypeModifierWithParameterName { range: CodeRange },
+    #[error("type modifier cannot have ORDER BY")]
+    TypeModifierWithOrderBy { range: CodeRange },
+    #[error("precision for type float must be at least 1 bit")]
+    FloatPrecisionTooSmall { range: CodeRange },
+    #[error("precision for type float must be less than 54 bits")]
+    FloatPrecisionTooLarge { range: CodeRange },
+    #[error("unrecognized JSON encoding: {name}")]
+    UnrecognizedJsonEncoding { range: CodeRange, name: String },
+    #[error("only string constants are supported in JSON_TABLE path specification")]
+    JsonTablePathNotString { range: CodeRange },
+    /// A row in `OVERLAPS` without exactly two fields.
+    #[error("wrong number of parameters on {side} side of OVERLAPS expression")]
+    WrongOverlapsArity {
+        range: CodeRange,
+        side: &'static str,
+    },
+    /// A clause such as `ORDER BY` given both inside and outside parentheses.
+    #[error("multiple {clause} clauses not allowed")]
+    MultipleClauses {
+        range: CodeRange,
+        clause: &'static str,
+    },
 }
 
 impl CodeDiagnostic {
@@ -85,6 +246,353 @@ impl CodeDiagnostic {
         match self {
             CodeDiagnostic::UnknownToken { range } => range,
             CodeDiagnostic::UnexpectedEof { range } => range,
+            CodeDiagnostic::SyntaxError { range, .. } => range,
+            CodeDiagnostic::TrailingJunkAfterNumeric { range } => range,
+            CodeDiagnostic::InvalidHexadecimalInteger { range } => range,
+            CodeDiagnostic::InvalidOctalInteger { range } => range,
+            CodeDiagnostic::InvalidBinaryInteger { range } => range,
+            CodeDiagnostic::NumericOverflow { range } => range,
+            CodeDiagnostic::TrailingJunkAfterParameter { range } => range,
+            CodeDiagnostic::ParameterNumberTooLarge { range } => range,
+            CodeDiagnostic::IdentifierTruncated { range, .. } => range,
+            CodeDiagnostic::UnterminatedBlockComment { range } => range,
+            CodeDiagnostic::UnterminatedString { range } => range,
+            CodeDiagnostic::UnterminatedDollarString { range } => range,
+            CodeDiagnostic::UnterminatedBitString { range } => range,
+            CodeDiagnostic::UnterminatedHexString { range } => range,
+            CodeDiagnostic::UnterminatedQuotedIdentifier { range } => range,
+            CodeDiagnostic::ZeroLengthIdentifier { range } => range,
+            CodeDiagnostic::UescapeWithoutString { range } => range,
+            CodeDiagnostic::InvalidUnicodeEscapeCharacter { range } => range,
+            CodeDiagnostic::InvalidUnicodeEscape { range } => range,
+            CodeDiagnostic::InvalidUnicodeLiteralEscape { range } => range,
+            CodeDiagnostic::InvalidUnicodeEscapeValue { range } => range,
+            CodeDiagnostic::InvalidUnicodeSurrogatePair { range } => range,
+            CodeDiagnostic::InvalidByteSequence { range } => range,
+            CodeDiagnostic::ImproperQualifiedName { range, .. } => range,
+            CodeDiagnostic::LimitCommaNotSupported { range } => range,
+            CodeDiagnostic::WithTiesWithoutOrderBy { range } => range,
+            CodeDiagnostic::SkipLockedWithTies { range } => range,
+            CodeDiagnostic::TypeModifierWithParameterName { range } => range,
+            CodeDiagnostic::TypeModifierWithOrderBy { range } => range,
+            CodeDiagnostic::FloatPrecisionTooSmall { range } => range,
+            CodeDiagnostic::FloatPrecisionTooLarge { range } => range,
+            CodeDiagnostic::UnrecognizedJsonEncoding { range, .. } => range,
+            CodeDiagnostic::JsonTablePathNotString { range } => range,
+            CodeDiagnostic::WrongOverlapsArity { range, .. } => range,
+            CodeDiagnostic::MultipleClauses { range, .. } => range,
+            CodeDiagnostic::MultipleOrderByWithWithinGroup { range } => range,
+            CodeDiagnostic::DistinctWithWithinGroup { range } => range,
+            CodeDiagnostic::VariadicWithWithinGroup { range } => range,
+            CodeDiagnostic::FrameStartUnboundedFollowing { range } => range,
+            CodeDiagnostic::FrameEndUnboundedPreceding { range } => range,
+            CodeDiagnostic::FrameCurrentRowWithPreceding { range } => range,
+            CodeDiagnostic::FrameFollowingWithPreceding { range } => range,
+            CodeDiagnostic::FrameFollowingEndsWithCurrentRow { range } => range,
+        }
+    }
+
+    /// The SQLSTATE error code.
+    ///
+    /// See <https://www.postgresql.org/docs/current/errcodes-appendix.html>.
+    pub fn sqlstate(&self) -> &'static str {
+        match self {
+            // invalid_escape_sequence
+            CodeDiagnostic::InvalidUnicodeEscape { .. } => "22025",
+            // numeric_value_out_of_range
+            CodeDiagnostic::NumericOverflow { .. } => "22003",
+            // character_not_in_repertoire
+            CodeDiagnostic::InvalidByteSequence { .. } => "22021",
+            // name_too_long
+            CodeDiagnostic::IdentifierTruncated { .. } => "42622",
+            // feature_not_supported
+            CodeDiagnostic::LimitCommaNotSupported { .. }
+            | CodeDiagnostic::SkipLockedWithTies { .. }
+            | CodeDiagnostic::JsonTablePathNotString { .. } => "0A000",
+            // invalid_parameter_value
+            CodeDiagnostic::FloatPrecisionTooSmall { .. }
+            | CodeDiagnostic::FloatPrecisionTooLarge { .. }
+            | CodeDiagnostic::UnrecognizedJsonEncoding { .. } => "22023",
+            // windowing_error
+            CodeDiagnostic::FrameStartUnboundedFollowing { .. }
+            | CodeDiagnostic::FrameEndUnboundedPreceding { .. }
+            | CodeDiagnostic::FrameCurrentRowWithPreceding { .. }
+            | CodeDiagnostic::FrameFollowingWithPreceding { .. }
+            | CodeDiagnostic::FrameFollowingEndsWithCurrentRow { .. } => "42P20",
+            // syntax_error
+            CodeDiagnostic::UnknownToken { .. }
+            | CodeDiagnostic::UnexpectedEof { .. }
+            | CodeDiagnostic::SyntaxError { .. }
+            | CodeDiagnostic::TrailingJunkAfterNumeric { .. }
+            | CodeDiagnostic::InvalidHexadecimalInteger { .. }
+            | CodeDiagnostic::InvalidOctalInteger { .. }
+            | CodeDiagnostic::InvalidBinaryInteger { .. }
+            | CodeDiagnostic::TrailingJunkAfterParameter { .. }
+            | CodeDiagnostic::ParameterNumberTooLarge { .. }
+            | CodeDiagnostic::UnterminatedBlockComment { .. }
+            | CodeDiagnostic::UnterminatedString { .. }
+            | CodeDiagnostic::UnterminatedDollarString { .. }
+            | CodeDiagnostic::UnterminatedBitString { .. }
+            | CodeDiagnostic::UnterminatedHexString { .. }
+            | CodeDiagnostic::UnterminatedQuotedIdentifier { .. }
+            | CodeDiagnostic::ZeroLengthIdentifier { .. }
+            | CodeDiagnostic::UescapeWithoutString { .. }
+            | CodeDiagnostic::InvalidUnicodeEscapeCharacter { .. }
+            | CodeDiagnostic::InvalidUnicodeLiteralEscape { .. }
+            | CodeDiagnostic::InvalidUnicodeEscapeValue { .. }
+            | CodeDiagnostic::InvalidUnicodeSurrogatePair { .. }
+            | CodeDiagnostic::ImproperQualifiedName { .. }
+            | CodeDiagnostic::WithTiesWithoutOrderBy { .. }
+            | CodeDiagnostic::MultipleOrderByWithWithinGroup { .. }
+            | CodeDiagnostic::DistinctWithWithinGroup { .. }
+            | CodeDiagnostic::VariadicWithWithinGroup { .. }
+            | CodeDiagnostic::TypeModifierWithParameterName { .. }
+            | CodeDiagnostic::TypeModifierWithOrderBy { .. }
+            | CodeDiagnostic::WrongOverlapsArity { .. }
+            | CodeDiagnostic::MultipleClauses { .. } => "42601",
+        }
+    }
+
+    /// Secondary message carrying more detail about the problem.
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            // PostgreSQL reports these without a DETAIL field.
+            CodeDiagnostic::UnknownToken { .. }
+            | CodeDiagnostic::UnexpectedEof { .. }
+            | CodeDiagnostic::SyntaxError { .. }
+            | CodeDiagnostic::TrailingJunkAfterNumeric { .. }
+            | CodeDiagnostic::InvalidHexadecimalInteger { .. }
+            | CodeDiagnostic::InvalidOctalInteger { .. }
+            | CodeDiagnostic::InvalidBinaryInteger { .. }
+            | CodeDiagnostic::NumericOverflow { .. }
+            | CodeDiagnostic::TrailingJunkAfterParameter { .. }
+            | CodeDiagnostic::ParameterNumberTooLarge { .. }
+            | CodeDiagnostic::IdentifierTruncated { .. }
+            | CodeDiagnostic::UnterminatedBlockComment { .. }
+            | CodeDiagnostic::UnterminatedString { .. }
+            | CodeDiagnostic::UnterminatedDollarString { .. }
+            | CodeDiagnostic::UnterminatedBitString { .. }
+            | CodeDiagnostic::UnterminatedHexString { .. }
+            | CodeDiagnostic::UnterminatedQuotedIdentifier { .. }
+            | CodeDiagnostic::ZeroLengthIdentifier { .. }
+            | CodeDiagnostic::UescapeWithoutString { .. }
+            | CodeDiagnostic::InvalidUnicodeEscapeCharacter { .. }
+            | CodeDiagnostic::InvalidUnicodeEscape { .. }
+            | CodeDiagnostic::InvalidUnicodeLiteralEscape { .. }
+            | CodeDiagnostic::InvalidUnicodeEscapeValue { .. }
+            | CodeDiagnostic::InvalidUnicodeSurrogatePair { .. }
+            | CodeDiagnostic::InvalidByteSequence { .. }
+            | CodeDiagnostic::ImproperQualifiedName { .. }
+            | CodeDiagnostic::LimitCommaNotSupported { .. }
+            | CodeDiagnostic::WithTiesWithoutOrderBy { .. }
+            | CodeDiagnostic::SkipLockedWithTies { .. }
+            | CodeDiagnostic::MultipleOrderByWithWithinGroup { .. }
+            | CodeDiagnostic::DistinctWithWithinGroup { .. }
+            | CodeDiagnostic::VariadicWithWithinGroup { .. }
+            | CodeDiagnostic::FrameStartUnboundedFollowing { .. }
+            | CodeDiagnostic::FrameEndUnboundedPreceding { .. }
+            | CodeDiagnostic::FrameCurrentRowWithPreceding { .. }
+            | CodeDiagnostic::FrameFollowingWithPreceding { .. }
+            | CodeDiagnostic::FrameFollowingEndsWithCurrentRow { .. }
+            | CodeDiagnostic::TypeModifierWithParameterName { .. }
+            | CodeDiagnostic::TypeModifierWithOrderBy { .. }
+            | CodeDiagnostic::FloatPrecisionTooSmall { .. }
+            | CodeDiagnostic::FloatPrecisionTooLarge { .. }
+            | CodeDiagnostic::UnrecognizedJsonEncoding { .. }
+            | CodeDiagnostic::JsonTablePathNotString { .. }
+            | CodeDiagnostic::WrongOverlapsArity { .. }
+            | CodeDiagnostic::MultipleClauses { .. } => None,
         }
     }
+
+    /// Suggestion about how to fix the problem.
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            CodeDiagnostic::InvalidUnicodeEscape { .. } => {
+                Some("Unicode escapes must be \\uXXXX or \\UXXXXXXXX.".to_string())
+            }
+            CodeDiagnostic::InvalidUnicodeLiteralEscape { .. } => {
+                Some("Unicode escapes must be \\XXXX or \\+XXXXXX.".to_string())
+            }
+            CodeDiagnostic::LimitCommaNotSupported { .. } => {
+                Some("Use separate LIMIT and OFFSET clauses.".to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// PostgreSQL's error position: 1-based index in characters into the source.
+    pub fn position(&self, src: &str) -> usize {
+        self.range().position(src)
+    }
+
+    /// Builds the fields of an ErrorResponse (or NoticeResponse) message.
+    pub fn to_error_fields(&self, src: &str) -> ErrorFields {
+        ErrorFields {
+            severity: self.severity(),
+            sqlstate: self.sqlstate(),
+            message: self.to_string(),
+            detail: self.detail(),
+            hint: self.hint(),
+            position: Some(self.position(src)),
+            context: None,
+        }
+    }
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            CodeDiagnostic::IdentifierTruncated { .. } => Severity::Notice,
+            CodeDiagnostic::UnknownToken { .. }
+            | CodeDiagnostic::UnexpectedEof { .. }
+            | CodeDiagnostic::SyntaxError { .. }
+            | CodeDiagnostic::TrailingJunkAfterNumeric { .. }
+            | CodeDiagnostic::InvalidHexadecimalInteger { .. }
+            | CodeDiagnostic::InvalidOctalInteger { .. }
+            | CodeDiagnostic::InvalidBinaryInteger { .. }
+            | CodeDiagnostic::NumericOverflow { .. }
+            | CodeDiagnostic::TrailingJunkAfterParameter { .. }
+            | CodeDiagnostic::ParameterNumberTooLarge { .. }
+            | CodeDiagnostic::UnterminatedBlockComment { .. }
+            | CodeDiagnostic::UnterminatedString { .. }
+            | CodeDiagnostic::UnterminatedDollarString { .. }
+            | CodeDiagnostic::UnterminatedBitString { .. }
+            | CodeDiagnostic::UnterminatedHexString { .. }
+            | CodeDiagnostic::UnterminatedQuotedIdentifier { .. }
+            | CodeDiagnostic::ZeroLengthIdentifier { .. }
+            | CodeDiagnostic::UescapeWithoutString { .. }
+            | CodeDiagnostic::InvalidUnicodeEscapeCharacter { .. }
+            | CodeDiagnostic::InvalidUnicodeEscape { .. }
+            | CodeDiagnostic::InvalidUnicodeLiteralEscape { .. }
+            | CodeDiagnostic::InvalidUnicodeEscapeValue { .. }
+            | CodeDiagnostic::InvalidUnicodeSurrogatePair { .. }
+            | CodeDiagnostic::InvalidByteSequence { .. }
+            | CodeDiagnostic::ImproperQualifiedName { .. }
+            | CodeDiagnostic::LimitCommaNotSupported { .. }
+            | CodeDiagnostic::WithTiesWithoutOrderBy { .. }
+            | CodeDiagnostic::SkipLockedWithTies { .. }
+            | CodeDiagnostic::MultipleOrderByWithWithinGroup { .. }
+            | CodeDiagnostic::DistinctWithWithinGroup { .. }
+            | CodeDiagnostic::VariadicWithWithinGroup { .. }
+            | CodeDiagnostic::FrameStartUnboundedFollowing { .. }
+            | CodeDiagnostic::FrameEndUnboundedPreceding { .. }
+            | CodeDiagnostic::FrameCurrentRowWithPreceding { .. }
+            | CodeDiagnostic::FrameFollowingWithPreceding { .. }
+            | CodeDiagnostic::FrameFollowingEndsWithCurrentRow { .. }
+            | CodeDiagnostic::TypeModifierWithParameterName { .. }
+            | CodeDiagnostic::TypeModifierWithOrderBy { .. }
+            | CodeDiagnostic::FloatPrecisionTooSmall { .. }
+            | CodeDiagnostic::FloatPrecisionTooLarge { .. }
+            | CodeDiagnostic::UnrecognizedJsonEncoding { .. }
+            | CodeDiagnostic::JsonTablePathNotString { .. }
+            | CodeDiagnostic::WrongOverlapsArity { .. }
+            | CodeDiagnostic::MultipleClauses { .. } => Severity::Error,
+        }
+    }
+
+    /// Whether the diagnostic prevents the code from being processed.
+    /// Others, like notices, are informational.
+    pub fn is_error(&self) -> bool {
+        self.severity() == Severity::Error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notice() -> CodeDiagnostic {
+        CodeDiagnostic::IdentifierTruncated {
+            range: CodeRange { start: 0, end: 70 },
+            original: "a".repeat(70),
+            truncated: "a".repeat(63),
+        }
+    }
+
+    fn error() -> CodeDiagnostic {
+        CodeDiagnostic::UnknownToken {
+            range: CodeRange { start: 0, end: 1 },
+        }
+    }
+
+    #[test]
+    fn test_check_errors_ignores_notices() {
+        let mut diags = CodeDiagnostics::new();
+        diags.add(notice());
+        let diags = diags.check_errors().unwrap();
+        assert_eq!(diags.diagnostics, vec![notice()]);
+    }
+
+    #[test]
+    fn test_check_errors_fails_on_errors() {
+        let mut diags = CodeDiagnostics::new();
+        diags.add(notice());
+        diags.add(error());
+        let err = diags.check_errors().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "2 diagnostics found:\nNOTICE: {}\nERROR: unknown token\n",
+                notice()
+            )
+        );
+    }
+
+    #[test]
+    fn test_sqlstate() {
+        assert_eq!(error().sqlstate(), "42601");
+        assert_eq!(notice().sqlstate(), "42622");
+        assert_eq!(
+            CodeDiagnostic::NumericOverflow {
+                range: CodeRange { start: 0, end: 1 }
+            }
+            .sqlstate(),
+            "22003"
+        );
+        assert_eq!(
+            CodeDiagnostic::LimitCommaNotSupported {
+                range: CodeRange { start: 0, end: 1 }
+            }
+            .sqlstate(),
+            "0A000"
+        );
+    }
+
+    #[test]
+    fn test_to_error_fields() {
+        let src = "select 'é', E'\\u12'";
+        let diag = CodeDiagnostic::InvalidUnicodeEscape {
+            range: CodeRange {
+                start: src.find('\\').unwrap(),
+                end: src.len() - 1,
+            },
+        };
+        assert_eq!(
+            diag.to_error_fields(src),
+            ErrorFields {
+                severity: Severity::Error,
+                sqlstate: "22025",
+                message: "invalid Unicode escape".to_string(),
+                detail: None,
+                hint: Some("Unicode escapes must be \\uXXXX or \\UXXXXXXXX.".to_string()),
+                position: Some(15),
+                context: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_with_severity() {
+        let mut diags = CodeDiagnostics::new();
+        diags.add(notice());
+        diags.add(error());
+        assert_eq!(
+            diags.with_severity(Severity::Notice).collect::<Vec<_>>(),
+            vec![&notice()]
+        );
+        assert_eq!(
+            diags.with_severity(Severity::Error).collect::<Vec<_>>(),
+            vec![&error()]
+        );
+        assert_eq!(diags.with_severity(Severity::Warning).count(), 0);
+    }
 }
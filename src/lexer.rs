@@ -40,19 +40,37 @@ pub(crate) fn lex_with_diags(src: &str, diags: &mut CodeDiagnostics) -> Vec<Toke
     tokens
 }
 
-#[derive(Debug)]
+/// The maximum length of identifiers plus one, as in PostgreSQL's default build.
+const NAMEDATALEN: usize = 64;
+
+#[derive(Debug, Clone)]
 pub(crate) struct Lexer<'a> {
     src: &'a str,
     pos: usize,
 }
 
 macro_rules! byte_pattern {
+    (newline) => {
+        b'\n' | b'\r'
+    };
+    (horiz_space) => {
+        b' ' | b'\t' | b'\x0B' | b'\x0C'
+    };
+    (space) => {
+        byte_pattern!(horiz_space) | byte_pattern!(newline)
+    };
     (digit) => {
         b'0'..=b'9'
     };
     (ident_start) => {
         b'A'..=b'Z' | b'a'..=b'z' | b'_' | 0x80..=0xFF
     };
+    (dollar_tag_start) => {
+        byte_pattern!(ident_start)
+    };
+    (dollar_tag_continue) => {
+        byte_pattern!(dollar_tag_start) | byte_pattern!(digit)
+    };
     (ident_continue) => {
         byte_pattern!(ident_start) | byte_pattern!(digit) | b'$'
     };
@@ -67,6 +85,26 @@ macro_rules! byte_pattern {
     };
 }
 
+/// Lowercase `UESCAPE`, which is looked for after `U&'...'` and `U&"..."`.
+const KEYWORD_UESCAPE: &[u8] = b"uescape";
+
+/// The flavor of a quoted string literal, determined by its prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StringStyle {
+    /// `'foo'`
+    Standard,
+    /// `E'foo'`
+    Escape,
+    /// `U&'foo'`
+    Unicode,
+    /// `N'foo'`
+    National,
+    /// `B'0101'`
+    Bit,
+    /// `X'1F'`
+    Hex,
+}
+
 impl<'a> Lexer<'a> {
     pub(crate) fn new(src: &'a str) -> Self {
         Self { src, pos: 0 }
@@ -74,7 +112,7 @@ impl<'a> Lexer<'a> {
 
     pub(crate) fn next_token(&mut self, diags: &mut CodeDiagnostics) -> Token {
         let start_before_ws = self.pos;
-        self.skip_whitespace();
+        self.skip_whitespace(diags);
 
         if self.pos >= self.src.len() {
             return Token {
@@ -86,7 +124,28 @@ impl<'a> Lexer<'a> {
         let start = self.pos;
 
         match self.src.as_bytes()[self.pos] {
+            b'\'' => self.next_string_token(start, StringStyle::Standard, diags),
+            b'"' => self.next_quoted_identifier_token(start, diags),
+            b'U' | b'u' if self.src[self.pos + 1..].starts_with("&'") => {
+                self.next_string_token(start, StringStyle::Unicode, diags)
+            }
+            b'U' | b'u' if self.src[self.pos + 1..].starts_with("&\"") => {
+                self.next_quoted_identifier_token(start, diags)
+            }
+            b'E' | b'e' if self.src[self.pos + 1..].starts_with('\'') => {
+                self.next_string_token(start, StringStyle::Escape, diags)
+            }
+            b'N' | b'n' if self.src[self.pos + 1..].starts_with('\'') => {
+                self.next_string_token(start, StringStyle::National, diags)
+            }
+            b'B' | b'b' if self.src[self.pos + 1..].starts_with('\'') => {
+                self.next_string_token(start, StringStyle::Bit, diags)
+            }
+            b'X' | b'x' if self.src[self.pos + 1..].starts_with('\'') => {
+                self.next_string_token(start, StringStyle::Hex, diags)
+            }
             byte_pattern!(ident_start) => self.next_identifier_token(start, diags),
+            b'$' => self.next_dollar_token(start, diags),
             byte_pattern!(digit) => self.next_numeric_token(start, diags),
             b'(' => {
                 self.pos += 1;
@@ -130,6 +189,14 @@ impl<'a> Lexer<'a> {
                     range: self.range_from(start),
                 }
             }
+            b'.' if self
+                .src
+                .as_bytes()
+                .get(self.pos + 1)
+                .is_some_and(u8::is_ascii_digit) =>
+            {
+                self.next_numeric_token(start, diags)
+            }
             b'.' => {
                 self.pos += 1;
                 if self.pos < self.src.len() && self.src.as_bytes()[self.pos] == b'.' {
@@ -193,7 +260,7 @@ impl<'a> Lexer<'a> {
         }
     }
 
-    fn next_identifier_token(&mut self, start: usize, _diags: &mut CodeDiagnostics) -> Token {
+    fn next_identifier_token(&mut self, start: usize, diags: &mut CodeDiagnostics) -> Token {
         while self.pos < self.src.len()
             && matches!(self.src.as_bytes()[self.pos], byte_pattern!(ident_continue))
         {
@@ -201,8 +268,9 @@ impl<'a> Lexer<'a> {
         }
         let identifier = &self.src[start..self.pos];
         let identifier = identifier.to_ascii_lowercase();
-        let identifier = Symbol::from(identifier);
         let range = self.range_from(start);
+        let identifier = Self::truncate_identifier(identifier, range, diags);
+        let identifier = Symbol::from(identifier);
         Token {
             kind: TokenKind::Identifier {
                 name: identifier,
@@ -212,28 +280,674 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    /// Truncates the identifier to `NAMEDATALEN - 1` bytes on a character boundary,
+    /// as PostgreSQL does.
+    fn truncate_identifier(
+        mut identifier: String,
+        range: CodeRange,
+        diags: &mut CodeDiagnostics,
+    ) -> String {
+        if identifier.len() < NAMEDATALEN {
+            return identifier;
+        }
+        let mut len = NAMEDATALEN - 1;
+        while !identifier.is_char_boundary(len) {
+            len -= 1;
+        }
+        let original = identifier.clone();
+        identifier.truncate(len);
+        diags.add(CodeDiagnostic::IdentifierTruncated {
+            range,
+            original,
+            truncated: identifier.clone(),
+        });
+        identifier
+    }
+
     fn next_numeric_token(&mut self, start: usize, diags: &mut CodeDiagnostics) -> Token {
+        let radix = match self.src.as_bytes()[start..] {
+            [b'0', b'x' | b'X', ..] => 16,
+            [b'0', b'o' | b'O', ..] => 8,
+            [b'0', b'b' | b'B', ..] => 2,
+            _ => 10,
+        };
+        if radix != 10 {
+            return self.next_non_decimal_integer_token(start, radix, diags);
+        }
+
+        self.skip_digits(10, false);
+        let mut is_integer = true;
+        // `1..10` is `1`, `..`, and `10`
+        if self.src[self.pos..].starts_with('.') && !self.src[self.pos..].starts_with("..") {
+            self.pos += 1;
+            self.skip_digits(10, false);
+            is_integer = false;
+        }
+        let mantissa_end = self.pos;
+        let mut exponent_range = None;
+        if let [b'e' | b'E', rest @ ..] = &self.src.as_bytes()[self.pos..] {
+            match rest {
+                [b'0'..=b'9', ..] | [b'+' | b'-', b'0'..=b'9', ..] => {
+                    let exponent_start = self.pos + 1;
+                    self.pos += if rest[0].is_ascii_digit() { 1 } else { 2 };
+                    self.skip_digits(10, false);
+                    exponent_range = Some(exponent_start..self.pos);
+                    is_integer = false;
+                }
+                [b'+' | b'-', ..] => {
+                    // Something like `1e+`
+                    self.pos += 2;
+                    let range = self.range_from(start);
+                    diags.add(CodeDiagnostic::TrailingJunkAfterNumeric { range });
+                    return Token {
+                        kind: TokenKind::Unknown,
+                        range,
+                    };
+                }
+                _ => {}
+            }
+        }
+        if let Some(token) = self.check_trailing_junk(start, diags) {
+            return token;
+        }
+
+        let mantissa_src = &self.src[start..mantissa_end];
+        let digits = mantissa_src
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(char::from)
+            .collect::<String>();
+        let mantissa = digits.parse::<BigInt>().unwrap();
+        if is_integer {
+            return Token {
+                kind: TokenKind::Integer(mantissa),
+                range: self.range_from(start),
+            };
+        }
+        let fraction_len = mantissa_src.split_once('.').map_or(0, |(_, fraction)| {
+            fraction.bytes().filter(u8::is_ascii_digit).count()
+        });
+        let scale = match exponent_range {
+            Some(exponent_range) => Self::remove_underscores(&self.src[exponent_range])
+                .parse::<i64>()
+                .ok()
+                .and_then(|exponent| (fraction_len as i64).checked_sub(exponent)),
+            None => Some(fraction_len as i64),
+        };
+        let range = self.range_from(start);
+        let Some(scale) = scale else {
+            diags.add(CodeDiagnostic::NumericOverflow { range });
+            return Token {
+                kind: TokenKind::Unknown,
+                range,
+            };
+        };
+        Token {
+            kind: TokenKind::Numeric { mantissa, scale },
+            range,
+        }
+    }
+
+    /// Lexes `0x1F`, `0o17`, or `0b101`.
+    fn next_non_decimal_integer_token(
+        &mut self,
+        start: usize,
+        radix: u32,
+        diags: &mut CodeDiagnostics,
+    ) -> Token {
+        // Skip `0x` etc.
+        self.pos += 2;
+        let digits_start = self.pos;
+        self.skip_digits(radix, true);
+        if self.pos == digits_start {
+            // `0x` and `0x_` are invalid hexadecimal integers,
+            // whereas `0xyz` is `0` followed by trailing junk.
+            self.pos = start + 1;
+            self.skip_identifier_chars();
+            let junk = &self.src[start + 1..self.pos];
+            let range = self.range_from(start);
+            if junk.len() == 1 || (junk.len() == 2 && junk.ends_with('_')) {
+                diags.add(match radix {
+                    16 => CodeDiagnostic::InvalidHexadecimalInteger { range },
+                    8 => CodeDiagnostic::InvalidOctalInteger { range },
+                    _ => CodeDiagnostic::InvalidBinaryInteger { range },
+                });
+            } else {
+                diags.add(CodeDiagnostic::TrailingJunkAfterNumeric { range });
+            }
+            return Token {
+                kind: TokenKind::Unknown,
+                range,
+            };
+        }
+        if let Some(token) = self.check_trailing_junk(start, diags) {
+            return token;
+        }
+        let digits = Self::remove_underscores(&self.src[digits_start..self.pos]);
+        let value = BigInt::parse_bytes(digits.as_bytes(), radix).unwrap();
+        Token {
+            kind: TokenKind::Integer(value),
+            range: self.range_from(start),
+        }
+    }
+
+    /// Skips digits in the given radix, allowing single underscores between digits
+    /// (or also before the first digit if `leading_underscore` is true).
+    fn skip_digits(&mut self, radix: u32, leading_underscore: bool) {
+        let is_digit = |b: Option<&u8>| b.is_some_and(|&b| (b as char).is_digit(radix));
+        let mut first = true;
+        loop {
+            let bytes = self.src.as_bytes();
+            if is_digit(bytes.get(self.pos)) {
+                self.pos += 1;
+            } else if bytes.get(self.pos) == Some(&b'_')
+                && (!first || leading_underscore)
+                && is_digit(bytes.get(self.pos + 1))
+            {
+                self.pos += 2;
+            } else {
+                break;
+            }
+            first = false;
+        }
+    }
+
+    fn skip_identifier_chars(&mut self) {
         while self.pos < self.src.len()
             && matches!(self.src.as_bytes()[self.pos], byte_pattern!(ident_continue))
         {
             self.pos += 1;
         }
-        let s = &self.src[start..self.pos];
-        if Self::is_decimal_integer(s) {
-            // TODO: check against invalid underscore occurrences
-            let value = Self::remove_underscores(s).parse::<BigInt>().unwrap();
-            Token {
-                kind: TokenKind::Integer(value),
+    }
+
+    /// Reports an identifier immediately following a numeric literal, such as `123abc`.
+    ///
+    /// This also covers misplaced underscores like `1__000` or `1_`.
+    fn check_trailing_junk(&mut self, start: usize, diags: &mut CodeDiagnostics) -> Option<Token> {
+        if !self
+            .src
+            .as_bytes()
+            .get(self.pos)
+            .is_some_and(|b| matches!(b, byte_pattern!(ident_start)))
+        {
+            return None;
+        }
+        self.skip_identifier_chars();
+        let range = self.range_from(start);
+        diags.add(CodeDiagnostic::TrailingJunkAfterNumeric { range });
+        Some(Token {
+            kind: TokenKind::Unknown,
+            range,
+        })
+    }
+
+    fn next_string_token(
+        &mut self,
+        start: usize,
+        style: StringStyle,
+        diags: &mut CodeDiagnostics,
+    ) -> Token {
+        // Skip the prefix
+        self.pos += match style {
+            StringStyle::Standard => 0,
+            StringStyle::Unicode => 2,
+            _ => 1,
+        };
+        let mut value = Vec::new();
+        let mut valid = true;
+        loop {
+            // Skip the opening quote
+            self.pos += 1;
+            if !self.read_string_segment(style, &mut value, &mut valid, diags) {
+                let range = self.range_from(start);
+                diags.add(match style {
+                    StringStyle::Bit => CodeDiagnostic::UnterminatedBitString { range },
+                    StringStyle::Hex => CodeDiagnostic::UnterminatedHexString { range },
+                    _ => CodeDiagnostic::UnterminatedString { range },
+                });
+                return Token {
+                    kind: TokenKind::Unknown,
+                    range,
+                };
+            }
+            match self.string_continuation() {
+                Some(quote_pos) => self.pos = quote_pos,
+                None => break,
+            }
+        }
+        if !valid {
+            return Token {
+                kind: TokenKind::Unknown,
+                range: self.range_from(start),
+            };
+        }
+        // Only escape sequences can produce invalid UTF-8 or NUL bytes.
+        let value = match String::from_utf8(value) {
+            Ok(value) if !value.contains('\0') => value,
+            _ => {
+                let range = self.range_from(start);
+                diags.add(CodeDiagnostic::InvalidByteSequence { range });
+                return Token {
+                    kind: TokenKind::Unknown,
+                    range,
+                };
+            }
+        };
+        let value = if style == StringStyle::Unicode {
+            match self.process_unicode_escapes(start, &value, diags) {
+                Some(value) => value,
+                None => {
+                    return Token {
+                        kind: TokenKind::Unknown,
+                        range: self.range_from(start),
+                    };
+                }
+            }
+        } else {
+            value
+        };
+        let range = self.range_from(start);
+        let kind = match style {
+            StringStyle::Standard | StringStyle::Escape | StringStyle::Unicode => {
+                TokenKind::String(value)
+            }
+            StringStyle::National => TokenKind::NationalString(value),
+            StringStyle::Bit => TokenKind::BitString(value),
+            StringStyle::Hex => TokenKind::HexString(value),
+        };
+        Token { kind, range }
+    }
+
+    /// Reads the body of a quoted string up to and including the closing quote.
+    ///
+    /// Returns false if the input ends before the closing quote.
+    fn read_string_segment(
+        &mut self,
+        style: StringStyle,
+        value: &mut Vec<u8>,
+        valid: &mut bool,
+        diags: &mut CodeDiagnostics,
+    ) -> bool {
+        // Bit strings do not recognize `''`.
+        let allow_doubling = !matches!(style, StringStyle::Bit | StringStyle::Hex);
+        while self.pos < self.src.len() {
+            match self.src.as_bytes()[self.pos] {
+                b'\'' if allow_doubling && self.src[self.pos + 1..].starts_with('\'') => {
+                    value.push(b'\'');
+                    self.pos += 2;
+                }
+                b'\'' => {
+                    self.pos += 1;
+                    return true;
+                }
+                b'\\' if style == StringStyle::Escape => {
+                    self.read_escape_sequence(value, valid, diags);
+                }
+                b => {
+                    value.push(b);
+                    self.pos += 1;
+                }
+            }
+        }
+        false
+    }
+
+    /// Reads a backslash escape sequence in an `E'...'` string.
+    fn read_escape_sequence(
+        &mut self,
+        value: &mut Vec<u8>,
+        valid: &mut bool,
+        diags: &mut CodeDiagnostics,
+    ) {
+        let start = self.pos;
+        // Skip the backslash
+        self.pos += 1;
+        let Some(&b) = self.src.as_bytes().get(self.pos) else {
+            // Reported as an unterminated string
+            return;
+        };
+        match b {
+            b'b' => value.push(b'\x08'),
+            b'f' => value.push(b'\x0C'),
+            b'n' => value.push(b'\n'),
+            b'r' => value.push(b'\r'),
+            b't' => value.push(b'\t'),
+            b'0'..=b'7' => {
+                let (code, _) = self.take_digits(8, 3);
+                // Out-of-range values like `\777` are truncated, as PostgreSQL does.
+                value.push(code as u8);
+                return;
+            }
+            b'x' if self
+                .src
+                .as_bytes()
+                .get(self.pos + 1)
+                .is_some_and(u8::is_ascii_hexdigit) =>
+            {
+                self.pos += 1;
+                let (code, _) = self.take_digits(16, 2);
+                value.push(code as u8);
+                return;
+            }
+            b'u' | b'U' => {
+                self.pos = start;
+                match self.read_unicode_escape_sequence(diags) {
+                    Some(ch) => value.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes()),
+                    None => *valid = false,
+                }
+                return;
+            }
+            // Any other character stands for itself, including `\\` and `\'`.
+            _ => value.push(b),
+        }
+        self.pos += 1;
+    }
+
+    /// Reads `\uXXXX` or `\UXXXXXXXX`, combining UTF-16 surrogate pairs.
+    fn read_unicode_escape_sequence(&mut self, diags: &mut CodeDiagnostics) -> Option<char> {
+        let start = self.pos;
+        let first = self.read_unicode_escape_value(diags)?;
+        let code = if is_utf16_surrogate_first(first) {
+            let second = if self.src[self.pos..].starts_with("\\u")
+                || self.src[self.pos..].starts_with("\\U")
+            {
+                self.read_unicode_escape_value(diags)?
+            } else {
+                0
+            };
+            if !is_utf16_surrogate_second(second) {
+                diags.add(CodeDiagnostic::InvalidUnicodeSurrogatePair {
+                    range: self.range_from(start),
+                });
+                return None;
+            }
+            surrogate_pair_to_codepoint(first, second)
+        } else if is_utf16_surrogate_second(first) {
+            diags.add(CodeDiagnostic::InvalidUnicodeSurrogatePair {
                 range: self.range_from(start),
+            });
+            return None;
+        } else {
+            first
+        };
+        match char::from_u32(code) {
+            Some(ch) if code != 0 => Some(ch),
+            _ => {
+                diags.add(CodeDiagnostic::InvalidUnicodeEscapeValue {
+                    range: self.range_from(start),
+                });
+                None
             }
+        }
+    }
+
+    /// Reads the hexadecimal value of a single `\uXXXX` or `\UXXXXXXXX`.
+    fn read_unicode_escape_value(&mut self, diags: &mut CodeDiagnostics) -> Option<u32> {
+        let start = self.pos;
+        let len = if self.src.as_bytes()[self.pos + 1] == b'u' {
+            4
         } else {
+            8
+        };
+        // Skip `\u` or `\U`
+        self.pos += 2;
+        let (code, count) = self.take_digits(16, len);
+        if count < len {
+            diags.add(CodeDiagnostic::InvalidUnicodeEscape {
+                range: self.range_from(start),
+            });
+            return None;
+        }
+        Some(code)
+    }
+
+    /// Consumes up to `max` digits in the given radix and returns their value and count.
+    fn take_digits(&mut self, radix: u32, max: usize) -> (u32, usize) {
+        let mut code = 0;
+        let mut count = 0;
+        while count < max
+            && let Some(digit) = self
+                .src
+                .as_bytes()
+                .get(self.pos)
+                .and_then(|&b| (b as char).to_digit(radix))
+        {
+            code = code * radix + digit;
+            count += 1;
+            self.pos += 1;
+        }
+        (code, count)
+    }
+
+    /// Finds the opening quote of a continuation segment, if any.
+    ///
+    /// Adjacent string literals are concatenated only if
+    /// the whitespace between them contains at least one newline.
+    fn string_continuation(&self) -> Option<usize> {
+        let bytes = self.src.as_bytes();
+        let mut pos = self.pos;
+        let mut saw_newline = false;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                byte_pattern!(newline) => {
+                    saw_newline = true;
+                    pos += 1;
+                }
+                byte_pattern!(horiz_space) => {
+                    pos += 1;
+                }
+                b'-' if bytes[pos..].starts_with(b"--") => {
+                    while pos < bytes.len() && !matches!(bytes[pos], byte_pattern!(newline)) {
+                        pos += 1;
+                    }
+                }
+                b'\'' if saw_newline => return Some(pos),
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    fn next_quoted_identifier_token(&mut self, start: usize, diags: &mut CodeDiagnostics) -> Token {
+        let unicode = self.src.as_bytes()[start] != b'"';
+        // Skip the prefix (if any) and the opening quote
+        self.pos += if unicode { 3 } else { 1 };
+        let mut name = String::new();
+        loop {
+            let Some(len) = self.src[self.pos..].find('"') else {
+                self.pos = self.src.len();
+                let range = self.range_from(start);
+                diags.add(CodeDiagnostic::UnterminatedQuotedIdentifier { range });
+                return Token {
+                    kind: TokenKind::Unknown,
+                    range,
+                };
+            };
+            name.push_str(&self.src[self.pos..self.pos + len]);
+            self.pos += len + 1;
+            if self.src[self.pos..].starts_with('"') {
+                name.push('"');
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        if name.is_empty() {
+            let range = self.range_from(start);
+            diags.add(CodeDiagnostic::ZeroLengthIdentifier { range });
+            return Token {
+                kind: TokenKind::Unknown,
+                range,
+            };
+        }
+        if unicode {
+            match self.process_unicode_escapes(start, &name, diags) {
+                Some(unescaped) => name = unescaped,
+                None => {
+                    return Token {
+                        kind: TokenKind::Unknown,
+                        range: self.range_from(start),
+                    };
+                }
+            }
+        }
+        let range = self.range_from(start);
+        let name = Self::truncate_identifier(name, range, diags);
+        Token {
+            kind: TokenKind::Identifier {
+                name: Symbol::from(name),
+                quoted: true,
+            },
+            range,
+        }
+    }
+
+    /// Reads the optional `UESCAPE 'c'` clause after `U&'...'` or `U&"..."`
+    /// and processes Unicode escapes in the literal body.
+    ///
+    /// Returns None if an error has been reported.
+    fn process_unicode_escapes(
+        &mut self,
+        start: usize,
+        body: &str,
+        diags: &mut CodeDiagnostics,
+    ) -> Option<String> {
+        let escape = self.read_uescape(diags)?;
+        decode_unicode_escapes(body, escape, self.range_from(start))
+            .map_err(|diag| diags.add(diag))
+            .ok()
+    }
+
+    /// Reads `UESCAPE 'c'` if present, returning the escape character (`\\` by default).
+    fn read_uescape(&mut self, diags: &mut CodeDiagnostics) -> Option<char> {
+        // Look ahead for the keyword without reporting anything; an unterminated comment
+        // is reported when it is lexed for real.
+        let start = self.pos;
+        self.skip_whitespace(&mut CodeDiagnostics::new());
+        let rest = &self.src.as_bytes()[self.pos..];
+        let is_uescape = rest
+            .get(..KEYWORD_UESCAPE.len())
+            .is_some_and(|word| word.eq_ignore_ascii_case(KEYWORD_UESCAPE))
+            && !matches!(
+                rest.get(KEYWORD_UESCAPE.len()),
+                Some(byte_pattern!(ident_continue))
+            );
+        if !is_uescape {
+            self.pos = start;
+            return Some('\\');
+        }
+        self.pos += KEYWORD_UESCAPE.len();
+
+        let tok = self.next_token(diags);
+        let (TokenKind::String(escape) | TokenKind::DollarString(escape)) = tok.kind else {
+            diags.add(CodeDiagnostic::UescapeWithoutString { range: tok.range });
+            return None;
+        };
+        let escape_char = escape.chars().next().filter(|&c| {
+            escape.len() == 1
+                && !c.is_ascii_hexdigit()
+                && !matches!(c as u8, b'+' | b'\'' | b'"' | byte_pattern!(space))
+        });
+        if escape_char.is_none() {
+            diags.add(CodeDiagnostic::InvalidUnicodeEscapeCharacter { range: tok.range });
+        }
+        escape_char
+    }
+
+    fn next_dollar_token(&mut self, start: usize, diags: &mut CodeDiagnostics) -> Token {
+        // Note that `$` after an identifier character is part of the identifier,
+        // so `foo$$bar$$` never reaches here.
+        if self
+            .src
+            .as_bytes()
+            .get(self.pos + 1)
+            .is_some_and(u8::is_ascii_digit)
+        {
+            return self.next_param_token(start, diags);
+        }
+        let Some(delim_len) = self.dollar_quote_delimiter_len() else {
+            self.pos += 1;
             let range = self.range_from(start);
             diags.add(CodeDiagnostic::UnknownToken { range });
             return Token {
                 kind: TokenKind::Unknown,
                 range,
             };
+        };
+        let delim = &self.src[start..start + delim_len];
+        self.pos += delim_len;
+        let Some(len) = self.src[self.pos..].find(delim) else {
+            self.pos = self.src.len();
+            let range = self.range_from(start);
+            diags.add(CodeDiagnostic::UnterminatedDollarString { range });
+            return Token {
+                kind: TokenKind::Unknown,
+                range,
+            };
+        };
+        let value = self.src[self.pos..self.pos + len].to_string();
+        self.pos += len + delim_len;
+        Token {
+            kind: TokenKind::DollarString(value),
+            range: self.range_from(start),
+        }
+    }
+
+    /// Lexes a positional parameter like `$1`.
+    fn next_param_token(&mut self, start: usize, diags: &mut CodeDiagnostics) -> Token {
+        // Skip `$`
+        self.pos += 1;
+        let digits_start = self.pos;
+        self.skip_digits(10, false);
+        let digits_end = self.pos;
+        if self
+            .src
+            .as_bytes()
+            .get(self.pos)
+            .is_some_and(|b| matches!(b, byte_pattern!(ident_start)))
+        {
+            self.skip_identifier_chars();
+            let range = self.range_from(start);
+            diags.add(CodeDiagnostic::TrailingJunkAfterParameter { range });
+            return Token {
+                kind: TokenKind::Unknown,
+                range,
+            };
+        }
+        let range = self.range_from(start);
+        // PostgreSQL limits parameter numbers to the range of int4.
+        match Self::remove_underscores(&self.src[digits_start..digits_end]).parse::<u32>() {
+            Ok(index) if index <= i32::MAX as u32 => Token {
+                kind: TokenKind::Param(index),
+                range,
+            },
+            _ => {
+                diags.add(CodeDiagnostic::ParameterNumberTooLarge { range });
+                Token {
+                    kind: TokenKind::Unknown,
+                    range,
+                }
+            }
+        }
+    }
+
+    /// Returns the length of the `$tag$` delimiter at the current position, if any.
+    fn dollar_quote_delimiter_len(&self) -> Option<usize> {
+        let bytes = &self.src.as_bytes()[self.pos..];
+        let mut len = 1;
+        if bytes
+            .get(len)
+            .is_some_and(|b| matches!(b, byte_pattern!(dollar_tag_start)))
+        {
+            len += 1;
+            while bytes
+                .get(len)
+                .is_some_and(|b| matches!(b, byte_pattern!(dollar_tag_continue)))
+            {
+                len += 1;
+            }
         }
+        (bytes.get(len) == Some(&b'$')).then_some(len + 1)
     }
 
     fn next_operator_token(&mut self, start: usize, _diags: &mut CodeDiagnostics) -> Token {
@@ -251,8 +965,8 @@ impl<'a> Lexer<'a> {
             }
         }
         if self.pos == start {
-            // TODO: implement comment parsing and turn this check into `unreachable!()`
-            unimplemented!("comment handling");
+            // Comments are consumed by skip_whitespace
+            unreachable!("operator starting with a comment");
         }
         let sym = &self.src[start..self.pos];
 
@@ -288,10 +1002,6 @@ impl<'a> Lexer<'a> {
         }
     }
 
-    fn is_decimal_integer(s: &str) -> bool {
-        s.bytes().all(|b| b.is_ascii_digit() || b == b'_')
-    }
-
     fn remove_underscores(s: &str) -> Cow<'_, str> {
         if s.contains('_') {
             let filtered: String = s.chars().filter(|&c| c != '_').collect();
@@ -301,17 +1011,58 @@ impl<'a> Lexer<'a> {
         }
     }
 
-    fn skip_whitespace(&mut self) {
+    fn skip_whitespace(&mut self, diags: &mut CodeDiagnostics) {
+        while self.pos < self.src.len() {
+            match self.src.as_bytes()[self.pos] {
+                byte_pattern!(space) => {
+                    self.pos += 1;
+                }
+                b'-' if self.src[self.pos..].starts_with("--") => {
+                    self.skip_line_comment();
+                }
+                b'/' if self.src[self.pos..].starts_with("/*") => {
+                    self.skip_block_comment(diags);
+                }
+                _ => break,
+            }
+        }
+    }
+
+    /// Skips `-- ...` up to (but not including) the next newline.
+    fn skip_line_comment(&mut self) {
+        self.pos += 2;
         while self.pos < self.src.len()
-            && self.src[self.pos..]
-                .chars()
-                .next()
-                .map_or(false, |c| c.is_whitespace())
+            && !matches!(self.src.as_bytes()[self.pos], byte_pattern!(newline))
         {
             self.pos += 1;
         }
     }
 
+    /// Skips `/* ... */`. Unlike the SQL standard, block comments nest in PostgreSQL.
+    fn skip_block_comment(&mut self, diags: &mut CodeDiagnostics) {
+        let start = self.pos;
+        self.pos += 2;
+        let mut depth = 1;
+        while self.pos < self.src.len() {
+            let rest = &self.src.as_bytes()[self.pos..];
+            if rest.starts_with(b"/*") {
+                self.pos += 2;
+                depth += 1;
+            } else if rest.starts_with(b"*/") {
+                self.pos += 2;
+                depth -= 1;
+                if depth == 0 {
+                    return;
+                }
+            } else {
+                self.pos += 1;
+            }
+        }
+        diags.add(CodeDiagnostic::UnterminatedBlockComment {
+            range: self.range_from(start),
+        });
+    }
+
     fn range_from(&self, start: usize) -> CodeRange {
         CodeRange {
             start,
@@ -320,9 +1071,86 @@ impl<'a> Lexer<'a> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::{
+/// Decodes `\\XXXX` and `\\+XXXXXX` escapes in the body of `U&'...'` or `U&"..."`,
+/// where `\\` is the escape character given by `UESCAPE`.
+fn decode_unicode_escapes(
+    body: &str,
+    escape: char,
+    range: CodeRange,
+) -> Result<String, CodeDiagnostic> {
+    let mut result = String::new();
+    let mut pair_first = None;
+    let mut rest = body;
+    while let Some(c) = rest.chars().next() {
+        if c != escape {
+            if pair_first.is_some() {
+                return Err(CodeDiagnostic::InvalidUnicodeSurrogatePair { range });
+            }
+            result.push(c);
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+        let after = &rest[c.len_utf8()..];
+        if after.starts_with(escape) {
+            if pair_first.is_some() {
+                return Err(CodeDiagnostic::InvalidUnicodeSurrogatePair { range });
+            }
+            result.push(escape);
+            rest = &after[escape.len_utf8()..];
+            continue;
+        }
+        let (digits, len) = if let Some(after_plus) = after.strip_prefix('+') {
+            (after_plus.get(..6), 7)
+        } else {
+            (after.get(..4), 4)
+        };
+        let Some(code) = digits
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+        else {
+            return Err(CodeDiagnostic::InvalidUnicodeLiteralEscape { range });
+        };
+        rest = &after[len..];
+        if code == 0 || code > 0x10FFFF {
+            return Err(CodeDiagnostic::InvalidUnicodeEscapeValue { range });
+        }
+        let code = match pair_first.take() {
+            Some(first) if is_utf16_surrogate_second(code) => {
+                surrogate_pair_to_codepoint(first, code)
+            }
+            Some(_) => return Err(CodeDiagnostic::InvalidUnicodeSurrogatePair { range }),
+            None if is_utf16_surrogate_first(code) => {
+                pair_first = Some(code);
+                continue;
+            }
+            None if is_utf16_surrogate_second(code) => {
+                return Err(CodeDiagnostic::InvalidUnicodeSurrogatePair { range });
+            }
+            None => code,
+        };
+        result.push(char::from_u32(code).unwrap());
+    }
+    if pair_first.is_some() {
+        return Err(CodeDiagnostic::InvalidUnicodeSurrogatePair { range });
+    }
+    Ok(result)
+}
+
+fn is_utf16_surrogate_first(c: u32) -> bool {
+    (0xD800..=0xDBFF).contains(&c)
+}
+
+fn is_utf16_surrogate_second(c: u32) -> bool {
+    (0xDC00..=0xDFFF).contains(&c)
+}
+
+fn surrogate_pair_to_codepoint(first: u32, second: u32) -> u32 {
+    ((first & 0x3FF) << 10) + 0x10000 + (second & 0x3FF)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
         pos::{CodeRange, pos},
         token::TokenKind,
     };
@@ -370,6 +1198,117 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lex_line_comment() {
+        let src = "foo -- comment\nbar";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from("foo"),
+                        quoted: false
+                    },
+                    pos(src, "foo", 0)
+                ),
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from("bar"),
+                        quoted: false
+                    },
+                    pos(src, "bar", 0)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_line_comment_at_eof() {
+        let src = "-- comment";
+        assert_eq!(lex(src).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_lex_block_comment() {
+        let src = "foo/* comment */bar";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from("foo"),
+                        quoted: false
+                    },
+                    pos(src, "foo", 0)
+                ),
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from("bar"),
+                        quoted: false
+                    },
+                    pos(src, "bar", 0)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_block_comment_nested() {
+        let src = "/* outer /* inner */ still comment */ foo";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::Identifier {
+                    name: Symbol::from("foo"),
+                    quoted: false
+                },
+                pos(src, "foo", 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_block_comment_unterminated() {
+        let src = "foo /* outer /* inner */";
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::Identifier {
+                    name: Symbol::from("foo"),
+                    quoted: false
+                },
+                pos(src, "foo", 0)
+            )]
+        );
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::UnterminatedBlockComment {
+                range: CodeRange {
+                    start: 4,
+                    end: src.len()
+                }
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_user_op_breaks_before_comment() {
+        let src = "+-- comment\n*/* comment */";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                tok(TokenKind::Plus, pos(src, "+", 0)),
+                tok(TokenKind::Asterisk, pos(src, "*", 0)),
+            ]
+        );
+    }
+
     #[test]
     fn test_lex_identifier_simple() {
         let src = "foo";
@@ -485,44 +1424,1032 @@ mod tests {
     }
 
     #[test]
-    fn test_lex_integer_simple() {
-        let src = "12345";
+    fn test_lex_identifier_max_length() {
+        let src = "a".repeat(63);
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(&src, &mut diags);
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::Identifier {
+                    name: Symbol::from(&*src),
+                    quoted: false
+                },
+                pos(&src, &src, 0)
+            )]
+        );
+        assert_eq!(diags.diagnostics, vec![]);
+    }
+
+    #[test]
+    fn test_lex_identifier_truncated() {
+        let src = "A".repeat(70);
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(&src, &mut diags);
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::Identifier {
+                    name: Symbol::from("a".repeat(63)),
+                    quoted: false
+                },
+                pos(&src, &src, 0)
+            )]
+        );
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::IdentifierTruncated {
+                range: pos(&src, &src, 0),
+                original: "a".repeat(70),
+                truncated: "a".repeat(63),
+            }]
+        );
+        assert!(!diags.has_errors());
+    }
+
+    #[test]
+    fn test_lex_identifier_truncated_at_char_boundary() {
+        // 62 bytes + 2-byte character: the character does not fit in 63 bytes.
+        let name = format!("{}é", "a".repeat(62));
+        let src = format!("\"{name}\"");
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(&src, &mut diags);
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::Identifier {
+                    name: Symbol::from("a".repeat(62)),
+                    quoted: true
+                },
+                pos(&src, &src, 0)
+            )]
+        );
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::IdentifierTruncated {
+                range: pos(&src, &src, 0),
+                original: name,
+                truncated: "a".repeat(62),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_quoted_identifier() {
+        let src = r#""FoO bar""#;
         let tokens = lex(src).unwrap();
         assert_eq!(
             tokens,
             vec![tok(
-                TokenKind::Integer(BigInt::from(12345)),
-                pos(src, "12345", 0)
+                TokenKind::Identifier {
+                    name: Symbol::from("FoO bar"),
+                    quoted: true
+                },
+                pos(src, src, 0)
             )]
         );
     }
 
     #[test]
-    fn test_lex_integer_decimal_leading_zeros() {
-        let src = "00012345";
+    fn test_lex_quoted_identifier_doubled_quote() {
+        let src = r#""a""b""#;
         let tokens = lex(src).unwrap();
         assert_eq!(
             tokens,
             vec![tok(
-                TokenKind::Integer(BigInt::from(12345)),
-                pos(src, "00012345", 0)
+                TokenKind::Identifier {
+                    name: Symbol::from(r#"a"b"#),
+                    quoted: true
+                },
+                pos(src, src, 0)
             )]
         );
     }
 
     #[test]
-    fn test_lex_integer_underscores() {
-        let src = "12_345_678";
+    fn test_lex_quoted_identifier_zero_length() {
+        let src = r#""""#;
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(tokens, vec![tok(TokenKind::Unknown, pos(src, src, 0))]);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::ZeroLengthIdentifier {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_quoted_identifier_unterminated() {
+        let src = r#""foo"#;
+        let mut diags = CodeDiagnostics::new();
+        lex_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::UnterminatedQuotedIdentifier {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_unicode_identifier() {
+        let src = r#"U&"d\0061t\+000061""#;
         let tokens = lex(src).unwrap();
         assert_eq!(
             tokens,
             vec![tok(
-                TokenKind::Integer(BigInt::from(12345678)),
-                pos(src, "12_345_678", 0)
+                TokenKind::Identifier {
+                    name: Symbol::from("data"),
+                    quoted: true
+                },
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_unicode_identifier_uescape() {
+        let src = r#"u&"d!0061t!+000061!!\" /* c */ UESCAPE '!' x"#;
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from(r"data!\"),
+                        quoted: true
+                    },
+                    pos(src, r#"u&"d!0061t!+000061!!\" /* c */ UESCAPE '!'"#, 0)
+                ),
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from("x"),
+                        quoted: false
+                    },
+                    pos(src, "x", 0)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_unicode_string() {
+        let src = r"U&'\0441\D83D\DE00'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(TokenKind::String("с😀".to_string()), pos(src, src, 0))]
+        );
+    }
+
+    #[test]
+    fn test_lex_unicode_string_uescape() {
+        let src = r"U&'a#0062''\' UESCAPE '#'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::String(r"ab'\".to_string()),
+                pos(src, src, 0)
             )]
         );
     }
 
+    #[test]
+    fn test_lex_unicode_string_uescape_lookahead() {
+        let src = "U&'a' -- c\n /* c /* d */ */ uEscape'!' U&'b' uescape_ U&'c' /* e";
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(
+            tokens,
+            vec![
+                tok(
+                    TokenKind::String("a".to_string()),
+                    pos(src, "U&'a' -- c\n /* c /* d */ */ uEscape'!'", 0)
+                ),
+                tok(TokenKind::String("b".to_string()), pos(src, "U&'b'", 0)),
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from("uescape_"),
+                        quoted: false
+                    },
+                    pos(src, "uescape_", 0)
+                ),
+                tok(TokenKind::String("c".to_string()), pos(src, "U&'c'", 0)),
+            ]
+        );
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::UnterminatedBlockComment {
+                range: pos(src, "/* e", 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_unicode_string_invalid_escape() {
+        let src = r"U&'\00g1'";
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(tokens, vec![tok(TokenKind::Unknown, pos(src, src, 0))]);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::InvalidUnicodeLiteralEscape {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_unicode_string_invalid_escape_value() {
+        let src = r"U&'\+110000'";
+        let mut diags = CodeDiagnostics::new();
+        lex_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::InvalidUnicodeEscapeValue {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_unicode_string_invalid_surrogate_pair() {
+        let src = r"U&'\D83Dx'";
+        let mut diags = CodeDiagnostics::new();
+        lex_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::InvalidUnicodeSurrogatePair {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_unicode_string_invalid_escape_character() {
+        let src = "U&'foo' UESCAPE 'a'";
+        let mut diags = CodeDiagnostics::new();
+        lex_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::InvalidUnicodeEscapeCharacter {
+                range: pos(src, "'a'", 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_unicode_string_uescape_without_string() {
+        let src = "U&'foo' UESCAPE bar";
+        let mut diags = CodeDiagnostics::new();
+        lex_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::UescapeWithoutString {
+                range: pos(src, "bar", 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_u_ampersand_without_quote() {
+        let src = "u&v";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from("u"),
+                        quoted: false
+                    },
+                    pos(src, "u", 0)
+                ),
+                tok(TokenKind::UserOp("&".to_string()), pos(src, "&", 0)),
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from("v"),
+                        quoted: false
+                    },
+                    pos(src, "v", 0)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_integer_simple() {
+        let src = "12345";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::Integer(BigInt::from(12345)),
+                pos(src, "12345", 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_integer_decimal_leading_zeros() {
+        let src = "00012345";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::Integer(BigInt::from(12345)),
+                pos(src, "00012345", 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_integer_underscores() {
+        let src = "12_345_678";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::Integer(BigInt::from(12345678)),
+                pos(src, "12_345_678", 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_integer_misplaced_underscores() {
+        for src in ["1__000", "1_", "1_000_"] {
+            let mut diags = CodeDiagnostics::new();
+            let tokens = lex_with_diags(src, &mut diags);
+            assert_eq!(tokens, vec![tok(TokenKind::Unknown, pos(src, src, 0))]);
+            assert_eq!(
+                diags.diagnostics,
+                vec![CodeDiagnostic::TrailingJunkAfterNumeric {
+                    range: pos(src, src, 0)
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn test_lex_integer_trailing_junk() {
+        let src = "123abc";
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(tokens, vec![tok(TokenKind::Unknown, pos(src, src, 0))]);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::TrailingJunkAfterNumeric {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_integer_hexadecimal() {
+        let src = "0x1F 0X_dead_BEEF";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                tok(TokenKind::Integer(BigInt::from(0x1F)), pos(src, "0x1F", 0)),
+                tok(
+                    TokenKind::Integer(BigInt::from(0xDEADBEEFu32)),
+                    pos(src, "0X_dead_BEEF", 0)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_integer_octal() {
+        let src = "0o17";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::Integer(BigInt::from(0o17)),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_integer_binary() {
+        let src = "0b1_01";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::Integer(BigInt::from(0b101)),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_integer_invalid_non_decimal() {
+        let cases = [
+            (
+                "0x",
+                CodeDiagnostic::InvalidHexadecimalInteger {
+                    range: pos("0x", "0x", 0),
+                },
+            ),
+            (
+                "0o_",
+                CodeDiagnostic::InvalidOctalInteger {
+                    range: pos("0o_", "0o_", 0),
+                },
+            ),
+            (
+                "0b2",
+                CodeDiagnostic::TrailingJunkAfterNumeric {
+                    range: pos("0b2", "0b2", 0),
+                },
+            ),
+            (
+                "0xg",
+                CodeDiagnostic::TrailingJunkAfterNumeric {
+                    range: pos("0xg", "0xg", 0),
+                },
+            ),
+            (
+                "0x1g",
+                CodeDiagnostic::TrailingJunkAfterNumeric {
+                    range: pos("0x1g", "0x1g", 0),
+                },
+            ),
+        ];
+        for (src, diag) in cases {
+            let mut diags = CodeDiagnostics::new();
+            lex_with_diags(src, &mut diags);
+            assert_eq!(diags.diagnostics, vec![diag], "{src}");
+        }
+    }
+
+    #[test]
+    fn test_lex_numeric_decimal_point() {
+        let src = "1.50 .5 1.";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                tok(
+                    TokenKind::Numeric {
+                        mantissa: BigInt::from(150),
+                        scale: 2
+                    },
+                    pos(src, "1.50", 0)
+                ),
+                tok(
+                    TokenKind::Numeric {
+                        mantissa: BigInt::from(5),
+                        scale: 1
+                    },
+                    pos(src, ".5", 1)
+                ),
+                tok(
+                    TokenKind::Numeric {
+                        mantissa: BigInt::from(1),
+                        scale: 0
+                    },
+                    pos(src, "1.", 1)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_numeric_exponent() {
+        let src = "1e10 1.5E-3 2_0e+0_1";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                tok(
+                    TokenKind::Numeric {
+                        mantissa: BigInt::from(1),
+                        scale: -10
+                    },
+                    pos(src, "1e10", 0)
+                ),
+                tok(
+                    TokenKind::Numeric {
+                        mantissa: BigInt::from(15),
+                        scale: 4
+                    },
+                    pos(src, "1.5E-3", 0)
+                ),
+                tok(
+                    TokenKind::Numeric {
+                        mantissa: BigInt::from(20),
+                        scale: -1
+                    },
+                    pos(src, "2_0e+0_1", 0)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_numeric_exponent_incomplete() {
+        for src in ["1e", "1e+", "1.5e-x"] {
+            let mut diags = CodeDiagnostics::new();
+            lex_with_diags(src, &mut diags);
+            assert!(
+                matches!(
+                    diags.diagnostics[..],
+                    [CodeDiagnostic::TrailingJunkAfterNumeric { .. }]
+                ),
+                "{src}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_lex_numeric_exponent_overflow() {
+        let src = "1e99999999999999999999";
+        let mut diags = CodeDiagnostics::new();
+        lex_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::NumericOverflow {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_integer_before_dot_dot() {
+        let src = "1..2";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                tok(TokenKind::Integer(BigInt::from(1)), pos(src, "1", 0)),
+                tok(TokenKind::DotDot, pos(src, "..", 0)),
+                tok(TokenKind::Integer(BigInt::from(2)), pos(src, "2", 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_string_simple() {
+        let src = "'foo'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::String("foo".to_string()),
+                pos(src, "'foo'", 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_string_empty() {
+        let src = "''";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(TokenKind::String("".to_string()), pos(src, "''", 0))]
+        );
+    }
+
+    #[test]
+    fn test_lex_string_doubled_quote() {
+        let src = "'it''s'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::String("it's".to_string()),
+                pos(src, "'it''s'", 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_string_backslash_is_literal() {
+        let src = r"'a\nb'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::String(r"a\nb".to_string()),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_string_continuation() {
+        let src = "'foo' -- comment\n  'bar'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::String("foobar".to_string()),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_string_no_continuation_without_newline() {
+        let src = "'foo' 'bar'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                tok(TokenKind::String("foo".to_string()), pos(src, "'foo'", 0)),
+                tok(TokenKind::String("bar".to_string()), pos(src, "'bar'", 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_string_unterminated() {
+        let src = "'foo";
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(tokens, vec![tok(TokenKind::Unknown, pos(src, src, 0))]);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::UnterminatedString {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_escape_string_simple_escapes() {
+        let src = r"E'\b\f\n\r\t\\\'\q'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::String("\x08\x0C\n\r\t\\'q".to_string()),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_escape_string_octal_and_hex() {
+        let src = r"e'\101\60x\x41\x4a\xg'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::String("A0xAJxg".to_string()),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_escape_string_unicode() {
+        let src = r"E'\u00e9\U0001F600\ud83d\ude00'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::String("é😀😀".to_string()),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_escape_string_continuation_keeps_escapes() {
+        let src = "E'foo\\n'\n'\\t'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::String("foo\n\t".to_string()),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_escape_string_invalid_unicode_escape() {
+        let src = r"E'\u12'";
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(tokens, vec![tok(TokenKind::Unknown, pos(src, src, 0))]);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::InvalidUnicodeEscape {
+                range: pos(src, r"\u12", 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_escape_string_invalid_unicode_escape_value() {
+        let src = r"E'\U00110000'";
+        let mut diags = CodeDiagnostics::new();
+        lex_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::InvalidUnicodeEscapeValue {
+                range: pos(src, r"\U00110000", 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_escape_string_invalid_surrogate_pair() {
+        let src = r"E'\ud83dx'";
+        let mut diags = CodeDiagnostics::new();
+        lex_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::InvalidUnicodeSurrogatePair {
+                range: pos(src, r"\ud83d", 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_escape_string_invalid_byte_sequence() {
+        let src = r"E'\xff'";
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(tokens, vec![tok(TokenKind::Unknown, pos(src, src, 0))]);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::InvalidByteSequence {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_escape_string_nul_byte() {
+        let src = r"E'\0'";
+        let mut diags = CodeDiagnostics::new();
+        lex_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::InvalidByteSequence {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_dollar_string_empty_tag() {
+        let src = "$$it's \\ $$";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::DollarString("it's \\ ".to_string()),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_dollar_string_with_tag() {
+        let src = "$fn$ select $$x$$; $f$ $fn$";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::DollarString(" select $$x$$; $f$ ".to_string()),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_dollar_string_tag_is_case_sensitive() {
+        let src = "$a$ $A$ $a$";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::DollarString(" $A$ ".to_string()),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_dollar_string_after_identifier() {
+        let src = "foo$$bar$$";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::Identifier {
+                    name: Symbol::from("foo$$bar$$"),
+                    quoted: false
+                },
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_dollar_string_unterminated() {
+        let src = "$tag$ foo $tag";
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(tokens, vec![tok(TokenKind::Unknown, pos(src, src, 0))]);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::UnterminatedDollarString {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_dollar_without_delimiter() {
+        let src = "$foo";
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(
+            tokens,
+            vec![
+                tok(TokenKind::Unknown, pos(src, "$", 0)),
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from("foo"),
+                        quoted: false
+                    },
+                    pos(src, "foo", 0)
+                ),
+            ]
+        );
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::UnknownToken {
+                range: pos(src, "$", 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_param() {
+        let src = "$1 $1_0 $0";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                tok(TokenKind::Param(1), pos(src, "$1", 0)),
+                tok(TokenKind::Param(10), pos(src, "$1_0", 0)),
+                tok(TokenKind::Param(0), pos(src, "$0", 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_param_followed_by_dollar() {
+        let src = "$1$";
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(
+            tokens,
+            vec![
+                tok(TokenKind::Param(1), pos(src, "$1", 0)),
+                tok(TokenKind::Unknown, pos(src, "$", 1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lex_param_too_large() {
+        let src = "$2147483648";
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(tokens, vec![tok(TokenKind::Unknown, pos(src, src, 0))]);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::ParameterNumberTooLarge {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_param_max() {
+        let src = "$2147483647";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(TokenKind::Param(2147483647), pos(src, src, 0))]
+        );
+    }
+
+    #[test]
+    fn test_lex_param_trailing_junk() {
+        let src = "$1abc";
+        let mut diags = CodeDiagnostics::new();
+        let tokens = lex_with_diags(src, &mut diags);
+        assert_eq!(tokens, vec![tok(TokenKind::Unknown, pos(src, src, 0))]);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::TrailingJunkAfterParameter {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_national_string() {
+        let src = "N'foo'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::NationalString("foo".to_string()),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_bit_string() {
+        let src = "B'0101'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::BitString("0101".to_string()),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_hex_string() {
+        let src = "x'1F'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![tok(
+                TokenKind::HexString("1F".to_string()),
+                pos(src, src, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lex_bit_string_unterminated() {
+        let src = "b'01";
+        let mut diags = CodeDiagnostics::new();
+        lex_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::UnterminatedBitString {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_hex_string_unterminated() {
+        let src = "X'1F";
+        let mut diags = CodeDiagnostics::new();
+        lex_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::UnterminatedHexString {
+                range: pos(src, src, 0)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lex_identifier_ending_with_prefix_letter() {
+        let src = "abe'foo'";
+        let tokens = lex(src).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from("abe"),
+                        quoted: false
+                    },
+                    pos(src, "abe", 0)
+                ),
+                tok(TokenKind::String("foo".to_string()), pos(src, "'foo'", 0)),
+            ]
+        );
+    }
+
     #[test]
     fn test_lex_lparen() {
         let src = "(";
@@ -1,14 +1,17 @@
-pub use crate::diag::{CodeDiagnostic, CodeDiagnostics, CodeError};
+pub use crate::diag::{CodeDiagnostic, CodeDiagnostics, CodeError, ErrorFields, Severity};
 pub use crate::parser::{
     parse_stmt, parse_stmt_with_diags, parse_stmtmulti, parse_stmtmulti_with_diags,
 };
 pub use crate::pos::CodeRange;
-pub use crate::symbols::Symbol;
+pub use crate::render::{RenderStyle, render_diagnostic};
+pub use crate::symbols::{KeywordCategory, Symbol};
 
 pub mod ast;
 mod diag;
 mod lexer;
 mod parser;
 mod pos;
+mod render;
 mod symbols;
 mod token;
+mod token_filter;
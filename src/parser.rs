@@ -1,13 +1,28 @@
 // https://github.com/postgres/postgres/blob/REL_18_1/src/backend/parser/gram.y
 
+mod expr;
+mod from;
+mod func;
+mod insert;
+mod json;
+mod select;
+#[cfg(test)]
+mod sexp;
+mod types;
+mod with;
+
 use crate::{
     Symbol,
-    ast::{ExprKind, ExprNode, StmtKind, StmtMultiNode, StmtNode},
+    ast::{StmtKind, StmtMultiNode, StmtNode},
     diag::{CodeDiagnostic, CodeDiagnostics, CodeError},
-    lexer::Lexer,
+    pos::CodeRange,
     token::{Token, TokenKind},
+    token_filter::TokenFilter,
 };
 
+use select::starts_select;
+use with::starts_with_clause;
+
 pub fn parse_stmtmulti(src: &str) -> Result<StmtMultiNode, CodeError> {
     let mut diags = CodeDiagnostics::new();
     let stmt = parse_stmtmulti_with_diags(src, &mut diags);
@@ -15,6 +30,11 @@ pub fn parse_stmtmulti(src: &str) -> Result<StmtMultiNode, CodeError> {
     Ok(stmt)
 }
 
+/// Parses a script of `;`-separated statements.
+///
+/// Syntax errors do not stop parsing: the erroneous statement is replaced with
+/// [`StmtKind::Error`] and parsing resumes after the next `;`,
+/// so that all errors in the script are reported at once.
 pub fn parse_stmtmulti_with_diags(src: &str, diags: &mut CodeDiagnostics) -> StmtMultiNode {
     let mut parser = Parser::new(src);
     parser.parse_stmtmulti_toplevel(diags)
@@ -32,25 +52,31 @@ pub fn parse_stmt_with_diags(src: &str, diags: &mut CodeDiagnostics) -> StmtNode
     parser.parse_stmt_toplevel(diags)
 }
 
+/// The result of parsing a syntactic element.
+///
+/// On success, it holds the node and the token following it.
+/// On failure, the syntax error has already been reported,
+/// and the offending token is returned so that the caller can resynchronize.
+type ParseResult<T> = Result<(T, Token), Token>;
+
 #[derive(Debug)]
 struct Parser<'a> {
-    lexer: Lexer<'a>,
+    src: &'a str,
+    tokens: TokenFilter<'a>,
 }
 
 impl<'a> Parser<'a> {
     fn new(src: &'a str) -> Self {
         Self {
-            lexer: Lexer::new(src),
+            src,
+            tokens: TokenFilter::new(src),
         }
     }
 
     fn parse_stmtmulti_toplevel(&mut self, diags: &mut CodeDiagnostics) -> StmtMultiNode {
-        let tok0 = self.lexer.next_token(diags);
+        let tok0 = self.tokens.next_token(diags);
         let (stmtmulti, tok1) = self.parse_stmtmulti(tok0, diags);
-        if tok1.kind != TokenKind::Eof {
-            // TODO: handle errors gracefully
-            panic!("unexpected token after statement list: {:?}", tok1);
-        }
+        debug_assert_eq!(tok1.kind, TokenKind::Eof);
         stmtmulti
     }
 
@@ -61,19 +87,40 @@ impl<'a> Parser<'a> {
     ) -> (StmtMultiNode, Token) {
         let mut stmts = Vec::new();
         loop {
-            let (stmt, tok1) = self.parse_stmt(tok0, diags);
-            if tok1.kind == TokenKind::Semicolon {
+            let start = tok0.range.start;
+            let (stmt, tok1) = match self.parse_stmt_terminated(tok0, diags) {
+                Ok(result) => result,
+                Err(tok) => {
+                    let tok1 = self.skip_to_stmt_end(tok, diags);
+                    let stmt = StmtNode {
+                        kind: StmtKind::Error,
+                        range: CodeRange {
+                            start,
+                            end: tok1.range.start,
+                        },
+                    };
+                    (stmt, tok1)
+                }
+            };
+            // Empty statements are omitted, as PostgreSQL does.
+            if stmt.kind != StmtKind::Empty {
                 // TODO: record semicolon in stmt
                 stmts.push(stmt);
-                tok0 = self.lexer.next_token(diags);
-                continue;
-            } else if tok1.kind == TokenKind::Eof {
-                stmts.push(stmt);
+            }
+            if tok1.kind == TokenKind::Semicolon {
+                tok0 = self.tokens.next_token(diags);
+            } else {
+                if stmts.is_empty() {
+                    stmts.push(StmtNode {
+                        kind: StmtKind::Empty,
+                        range: CodeRange {
+                            start: tok1.range.start,
+                            end: tok1.range.start,
+                        },
+                    });
+                }
                 tok0 = tok1;
                 break;
-            } else {
-                // TODO: handle errors gracefully
-                panic!("unexpected token after statement: {:?}", tok1);
             }
         }
         let stmtmulti = StmtMultiNode { stmts };
@@ -81,15 +128,43 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_stmt_toplevel(&mut self, diags: &mut CodeDiagnostics) -> StmtNode {
-        let tok0 = self.lexer.next_token(diags);
-        let (stmt, tok1) = self.parse_stmt(tok0, diags);
-        if tok1.kind != TokenKind::Eof {
-            diags.add(CodeDiagnostic::UnexpectedEof { range: tok1.range });
+        let tok0 = self.tokens.next_token(diags);
+        let start = tok0.range.start;
+        let result = self.parse_stmt(tok0, diags).and_then(|(stmt, tok1)| {
+            if tok1.kind == TokenKind::Eof {
+                Ok((stmt, tok1))
+            } else {
+                self.syntax_error(&tok1, diags);
+                Err(tok1)
+            }
+        });
+        match result {
+            Ok((stmt, _)) => stmt,
+            Err(tok) => StmtNode {
+                kind: StmtKind::Error,
+                range: CodeRange {
+                    start,
+                    end: tok.range.end,
+                },
+            },
+        }
+    }
+
+    /// Parses a statement that must be followed by `;` or the end of input.
+    fn parse_stmt_terminated(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<StmtNode> {
+        let (stmt, tok1) = self.parse_stmt(tok0, diags)?;
+        if !matches!(tok1.kind, TokenKind::Semicolon | TokenKind::Eof) {
+            self.syntax_error(&tok1, diags);
+            return Err(tok1);
         }
-        stmt
+        Ok((stmt, tok1))
     }
 
-    fn parse_stmt(&mut self, tok0: Token, diags: &mut CodeDiagnostics) -> (StmtNode, Token) {
+    fn parse_stmt(&mut self, tok0: Token, diags: &mut CodeDiagnostics) -> ParseResult<StmtNode> {
         // TODO: incomplete list of statement syntaxes
         match tok0.kind {
             // TODO: handle keyword contexts correctly, such as:
@@ -97,50 +172,261 @@ impl<'a> Parser<'a> {
             // - statement/expression context
             // - function/type context
             // - implicit renaming context (e.g. `SELECT 1 x`)
-            TokenKind::Identifier {
-                name: Symbol::KEYWORD_select,
-                quoted: false,
-            } => {
-                let tok1 = self.lexer.next_token(diags);
-                let (expr, tok2) = self.parse_expr(tok1, diags);
+            _ if starts_select(&tok0)
+                || tok0.kind == TokenKind::LParen
+                || tok0.is_keyword(&Symbol::KEYWORD_insert) =>
+            {
+                self.parse_preparable_stmt(tok0, diags)
+            }
+            TokenKind::Semicolon | TokenKind::Eof => {
                 let stmt = StmtNode {
-                    kind: StmtKind::Select {
-                        select_list: vec![expr],
+                    kind: StmtKind::Empty,
+                    range: CodeRange {
+                        start: tok0.range.start,
+                        end: tok0.range.start,
                     },
-                    range: tok0.range,
                 };
-                (stmt, tok2)
+                Ok((stmt, tok0))
+            }
+            _ => {
+                self.syntax_error(&tok0, diags);
+                Err(tok0)
             }
-            // TODO: handle errors gracefully
-            _ => unimplemented!(),
         }
     }
 
-    fn parse_expr(&mut self, tok0: Token, diags: &mut CodeDiagnostics) -> (ExprNode, Token) {
-        // TODO: incomplete list of expression syntaxes
+    /// Parses a statement that can be the body of a common table expression (`PreparableStmt`).
+    fn parse_preparable_stmt(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<StmtNode> {
+        // TODO: UPDATE, DELETE, and MERGE
+        let start = tok0.range.start;
+        // The `WITH` clause is parsed here since it can precede any of the statements.
+        let (with, tok1) = if starts_with_clause(&tok0) {
+            let (with, tok1) = self.parse_with_clause(tok0, diags)?;
+            (Some(with), tok1)
+        } else {
+            (None, tok0)
+        };
+        if tok1.is_keyword(&Symbol::KEYWORD_insert) {
+            let (insert, tok2) = self.parse_insert(with, tok1, diags)?;
+            let stmt = StmtNode {
+                kind: StmtKind::Insert(Box::new(insert)),
+                range: CodeRange {
+                    start,
+                    end: self.tokens.prev_end(),
+                },
+            };
+            return Ok((stmt, tok2));
+        }
+        if !(starts_select(&tok1) || tok1.kind == TokenKind::LParen) {
+            self.syntax_error(&tok1, diags);
+            return Err(tok1);
+        }
+        let (first, tok2) = self.parse_select_primary(tok1, diags)?;
+        let (select, tok3) = self.parse_select_continued(start, with, first, tok2, diags)?;
+        let stmt = StmtNode {
+            range: select.range,
+            kind: StmtKind::Select(Box::new(select)),
+        };
+        Ok((stmt, tok3))
+    }
+
+    /// Reports a syntax error at the token, unless it has already been reported by the lexer.
+    fn syntax_error(&self, tok: &Token, diags: &mut CodeDiagnostics) {
+        match tok.kind {
+            TokenKind::Unknown => {}
+            TokenKind::Eof => diags.add(CodeDiagnostic::UnexpectedEof {
+                range: CodeRange {
+                    start: tok.range.end,
+                    end: tok.range.end,
+                },
+            }),
+            _ => diags.add(CodeDiagnostic::SyntaxError {
+                range: tok.range,
+                near: self.src[tok.range.start..tok.range.end].to_string(),
+            }),
+        }
+    }
+
+    /// Checks that the token is the given keyword, and returns the next token.
+    fn expect_keyword(
+        &mut self,
+        tok0: Token,
+        keyword: Symbol,
+        diags: &mut CodeDiagnostics,
+    ) -> Result<Token, Token> {
+        if !tok0.is_keyword(&keyword) {
+            self.syntax_error(&tok0, diags);
+            return Err(tok0);
+        }
+        Ok(self.tokens.next_token(diags))
+    }
+
+    /// Checks that the token is of the given kind, and returns the next token.
+    fn expect_token(
+        &mut self,
+        tok0: Token,
+        kind: TokenKind,
+        diags: &mut CodeDiagnostics,
+    ) -> Result<Token, Token> {
+        if tok0.kind != kind {
+            self.syntax_error(&tok0, diags);
+            return Err(tok0);
+        }
+        Ok(self.tokens.next_token(diags))
+    }
+
+    /// Parses one or more items separated by commas.
+    fn parse_comma_list<T>(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+        mut parse_item: impl FnMut(&mut Self, Token, &mut CodeDiagnostics) -> ParseResult<T>,
+    ) -> ParseResult<Vec<T>> {
+        let mut items = Vec::new();
+        let (item, mut tok1) = parse_item(self, tok0, diags)?;
+        items.push(item);
+        while tok1.kind == TokenKind::Comma {
+            let tok2 = self.tokens.next_token(diags);
+            let (item, tok3) = parse_item(self, tok2, diags)?;
+            items.push(item);
+            tok1 = tok3;
+        }
+        Ok((items, tok1))
+    }
+
+    /// Parses a `ColId`: an identifier or a keyword that can be used as a column name.
+    fn parse_col_id(&mut self, tok0: Token, diags: &mut CodeDiagnostics) -> ParseResult<Symbol> {
         match tok0.kind {
-            TokenKind::Integer(value) => {
-                let expr = ExprNode {
-                    kind: ExprKind::IntegerLiteral {
-                        value: value.try_into().unwrap(),
-                    },
-                    range: tok0.range,
-                };
-                let tok1 = self.lexer.next_token(diags);
-                (expr, tok1)
+            TokenKind::Identifier { name, quoted } if quoted || name.is_col_id() => {
+                Ok((name, self.tokens.next_token(diags)))
+            }
+            _ => {
+                self.syntax_error(&tok0, diags);
+                Err(tok0)
             }
-            // TODO: handle errors gracefully
-            _ => unimplemented!(),
         }
     }
+
+    /// Parses a `ColLabel`: an identifier or any keyword.
+    fn parse_col_label(&mut self, tok0: Token, diags: &mut CodeDiagnostics) -> ParseResult<Symbol> {
+        match tok0.kind {
+            TokenKind::Identifier { name, .. } => Ok((name, self.tokens.next_token(diags))),
+            _ => {
+                self.syntax_error(&tok0, diags);
+                Err(tok0)
+            }
+        }
+    }
+
+    /// Parses a `qualified_name` such as `catalog.schema.table`.
+    fn parse_qualified_name(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<Symbol>> {
+        let start = tok0.range.start;
+        let (names, tok1) = self.parse_any_name(tok0, diags)?;
+        let range = CodeRange {
+            start,
+            end: self.tokens.prev_end(),
+        };
+        check_qualified_name(&names, range, diags);
+        Ok((names, tok1))
+    }
+
+    /// Parses an `any_name`, a dotted name of any length such as `pg_catalog."C"`.
+    fn parse_any_name(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<Symbol>> {
+        let (name, mut tok1) = self.parse_col_id(tok0, diags)?;
+        let mut names = vec![name];
+        while tok1.kind == TokenKind::Dot {
+            let tok2 = self.tokens.next_token(diags);
+            let (name, tok3) = self.parse_col_label(tok2, diags)?;
+            names.push(name);
+            tok1 = tok3;
+        }
+        Ok((names, tok1))
+    }
+
+    /// Skips tokens up to the next `;` or the end of input for error recovery.
+    fn skip_to_stmt_end(&mut self, mut tok0: Token, diags: &mut CodeDiagnostics) -> Token {
+        while !matches!(tok0.kind, TokenKind::Semicolon | TokenKind::Eof) {
+            tok0 = self.tokens.next_token(diags);
+        }
+        tok0
+    }
+}
+
+/// Reports a qualified name with too many dotted parts.
+fn check_qualified_name(names: &[Symbol], range: CodeRange, diags: &mut CodeDiagnostics) {
+    if names.len() > 3 {
+        diags.add(CodeDiagnostic::ImproperQualifiedName {
+            range,
+            name: names
+                .iter()
+                .map(|name| &**name)
+                .collect::<Vec<_>>()
+                .join("."),
+        });
+    }
+}
+
+/// The range from the start of `first` to the end of `last`.
+fn join(first: CodeRange, last: CodeRange) -> CodeRange {
+    CodeRange {
+        start: first.start,
+        end: last.end,
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::pos::pos;
+    use crate::{
+        ast::{ExprKind, ExprNode, SelectBody, SelectNode, SimpleSelect, TargetNode},
+        pos::pos,
+    };
 
     use super::*;
 
+    /// A `SELECT` statement with a single unnamed target.
+    fn select_stmt(expr: ExprNode, range: CodeRange) -> StmtNode {
+        let target = TargetNode {
+            range: expr.range,
+            expr,
+            alias: None,
+        };
+        let body = SelectBody::Simple(Box::new(SimpleSelect {
+            distinct: None,
+            targets: vec![target],
+            from: Vec::new(),
+            where_clause: None,
+            group_by_distinct: false,
+            group_by: Vec::new(),
+            having: None,
+            window: Vec::new(),
+        }));
+        StmtNode {
+            kind: StmtKind::Select(Box::new(SelectNode {
+                with: None,
+                body,
+                order_by: Vec::new(),
+                limit: None,
+                offset: None,
+                with_ties: false,
+                locking: Vec::new(),
+                range,
+            })),
+            range,
+        }
+    }
+
     #[test]
     fn test_parse_stmtmulti_single() {
         let src = "select 1";
@@ -148,15 +434,13 @@ mod tests {
         assert_eq!(
             stmtmulti,
             StmtMultiNode {
-                stmts: vec![StmtNode {
-                    kind: StmtKind::Select {
-                        select_list: vec![ExprNode {
-                            kind: ExprKind::IntegerLiteral { value: 1 },
-                            range: pos(src, "1", 0),
-                        }],
+                stmts: vec![select_stmt(
+                    ExprNode {
+                        kind: ExprKind::IntegerLiteral { value: 1 },
+                        range: pos(src, "1", 0),
                     },
-                    range: pos(src, "select", 0),
-                }],
+                    pos(src, "select 1", 0),
+                )],
             }
         );
     }
@@ -169,24 +453,20 @@ mod tests {
             stmtmulti,
             StmtMultiNode {
                 stmts: vec![
-                    StmtNode {
-                        kind: StmtKind::Select {
-                            select_list: vec![ExprNode {
-                                kind: ExprKind::IntegerLiteral { value: 1 },
-                                range: pos(src, "1", 0),
-                            }],
+                    select_stmt(
+                        ExprNode {
+                            kind: ExprKind::IntegerLiteral { value: 1 },
+                            range: pos(src, "1", 0),
                         },
-                        range: pos(src, "select", 0),
-                    },
-                    StmtNode {
-                        kind: StmtKind::Select {
-                            select_list: vec![ExprNode {
-                                kind: ExprKind::IntegerLiteral { value: 2 },
-                                range: pos(src, "2", 0),
-                            }],
+                        pos(src, "select 1", 0),
+                    ),
+                    select_stmt(
+                        ExprNode {
+                            kind: ExprKind::IntegerLiteral { value: 2 },
+                            range: pos(src, "2", 0),
                         },
-                        range: pos(src, "select", 1),
-                    },
+                        pos(src, "select 2", 0),
+                    ),
                 ],
             }
         );
@@ -198,15 +478,172 @@ mod tests {
         let stmt = parse_stmt(src).unwrap();
         assert_eq!(
             stmt,
-            StmtNode {
-                kind: StmtKind::Select {
-                    select_list: vec![ExprNode {
-                        kind: ExprKind::IntegerLiteral { value: 42 },
-                        range: pos(src, "42", 0),
-                    }],
+            select_stmt(
+                ExprNode {
+                    kind: ExprKind::IntegerLiteral { value: 42 },
+                    range: pos(src, "42", 0),
                 },
-                range: pos(src, "select", 0),
+                pos(src, "select 42", 0),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_select_param() {
+        let src = "select $1";
+        let stmt = parse_stmt(src).unwrap();
+        assert_eq!(
+            stmt,
+            select_stmt(
+                ExprNode {
+                    kind: ExprKind::Param { index: 1 },
+                    range: pos(src, "$1", 0),
+                },
+                pos(src, "select $1", 0),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_stmtmulti_empty() {
+        let src = "  ";
+        let stmtmulti = parse_stmtmulti(src).unwrap();
+        assert_eq!(
+            stmtmulti,
+            StmtMultiNode {
+                stmts: vec![StmtNode {
+                    kind: StmtKind::Empty,
+                    range: CodeRange { start: 0, end: 0 },
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_stmtmulti_empty_statements_omitted() {
+        let src = ";select 1;;";
+        let stmtmulti = parse_stmtmulti(src).unwrap();
+        assert_eq!(
+            stmtmulti,
+            StmtMultiNode {
+                stmts: vec![select_stmt(
+                    ExprNode {
+                        kind: ExprKind::IntegerLiteral { value: 1 },
+                        range: pos(src, "1", 0),
+                    },
+                    pos(src, "select 1", 0),
+                )],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_stmtmulti_recovers_from_errors() {
+        let src = "select ); select 1; select 2 3; foo";
+        let mut diags = CodeDiagnostics::new();
+        let stmtmulti = parse_stmtmulti_with_diags(src, &mut diags);
+        assert_eq!(
+            stmtmulti,
+            StmtMultiNode {
+                stmts: vec![
+                    StmtNode {
+                        kind: StmtKind::Error,
+                        range: pos(src, "select )", 0),
+                    },
+                    select_stmt(
+                        ExprNode {
+                            kind: ExprKind::IntegerLiteral { value: 1 },
+                            range: pos(src, "1", 0),
+                        },
+                        pos(src, "select 1", 0),
+                    ),
+                    StmtNode {
+                        kind: StmtKind::Error,
+                        range: pos(src, "select 2 3", 0),
+                    },
+                    StmtNode {
+                        kind: StmtKind::Error,
+                        range: pos(src, "foo", 0),
+                    },
+                ],
             }
         );
+        assert_eq!(
+            diags.diagnostics,
+            vec![
+                CodeDiagnostic::SyntaxError {
+                    range: pos(src, ")", 0),
+                    near: ")".to_string(),
+                },
+                CodeDiagnostic::SyntaxError {
+                    range: pos(src, "3", 0),
+                    near: "3".to_string(),
+                },
+                CodeDiagnostic::SyntaxError {
+                    range: pos(src, "foo", 0),
+                    near: "foo".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_stmtmulti_lexer_errors_not_duplicated() {
+        let src = "select 'foo";
+        let mut diags = CodeDiagnostics::new();
+        parse_stmtmulti_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::UnterminatedString {
+                range: pos(src, "'foo", 0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_stmt_unexpected_eof() {
+        let src = "select 1 + ";
+        let mut diags = CodeDiagnostics::new();
+        let stmt = parse_stmt_with_diags(src, &mut diags);
+        assert_eq!(stmt.kind, StmtKind::Error);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::UnexpectedEof {
+                range: CodeRange { start: 11, end: 11 },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_stmt_trailing_tokens() {
+        let src = "select 1; select 2";
+        let mut diags = CodeDiagnostics::new();
+        parse_stmt_with_diags(src, &mut diags);
+        assert_eq!(
+            diags.diagnostics,
+            vec![CodeDiagnostic::SyntaxError {
+                range: pos(src, ";", 0),
+                near: ";".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_select_large_integer() {
+        let src = "select 99999999999999999999";
+        let stmt = parse_stmt(src).unwrap();
+        assert_eq!(
+            stmt,
+            select_stmt(
+                ExprNode {
+                    kind: ExprKind::NumericLiteral {
+                        mantissa: "99999999999999999999".parse().unwrap(),
+                        scale: 0,
+                    },
+                    range: pos(src, "99999999999999999999", 0),
+                },
+                pos(src, src, 0),
+            )
+        );
     }
 }
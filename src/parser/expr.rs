@@ -0,0 +1,1931 @@
+// Expressions: `a_expr`, `b_expr`, and `c_expr` in gram.y.
+
+use crate::{
+    Symbol,
+    ast::{
+        CaseWhen, ExprKind, ExprNode, IndirectionItem, JsonValueType, LikeKind, OperatorName,
+        SelectNode, TypeName,
+    },
+    diag::{CodeDiagnostic, CodeDiagnostics},
+    pos::CodeRange,
+    token::{Token, TokenKind},
+};
+
+use super::{
+    ParseResult, Parser,
+    func::{sql_func_call, starts_unicode_normal_form},
+    join,
+    json::starts_json_func,
+    select::{continues_select, starts_select},
+    types::{
+        continues_special_type_const, is_sconst, starts_special_type_name, system_type_name,
+        type_cast,
+    },
+};
+
+/// Operator precedence levels from the loosest to the tightest,
+/// following the precedence declarations in gram.y.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Prec {
+    Lowest,
+    Or,
+    And,
+    Not,
+    /// `IS`, `ISNULL`, and `NOTNULL` (non-associative)
+    Is,
+    /// `<`, `>`, `=`, `<=`, `>=`, and `<>` (non-associative)
+    Comparison,
+    /// `BETWEEN`, `IN`, `LIKE`, `ILIKE`, `SIMILAR`, and `NOT_LA` (non-associative)
+    Like,
+    /// `ESCAPE`, just above `LIKE` (non-associative)
+    Escape,
+    /// User-defined operators and `OPERATOR(...)`
+    Op,
+    /// `+` and `-`
+    Additive,
+    /// `*`, `/`, and `%`
+    Multiplicative,
+    /// `^`
+    Exponent,
+    /// `AT TIME ZONE` and `AT LOCAL`
+    At,
+    /// `COLLATE`
+    Collate,
+    /// Unary `+` and `-`
+    Unary,
+    /// `::`
+    Typecast,
+}
+
+impl Prec {
+    /// The level just above this one, which the right operand of
+    /// a left-associative or non-associative operator must bind tighter than.
+    fn next(self) -> Self {
+        match self {
+            Prec::Lowest => Prec::Or,
+            Prec::Or => Prec::And,
+            Prec::And => Prec::Not,
+            Prec::Not => Prec::Is,
+            Prec::Is => Prec::Comparison,
+            Prec::Comparison => Prec::Like,
+            Prec::Like => Prec::Escape,
+            Prec::Escape => Prec::Op,
+            Prec::Op => Prec::Additive,
+            Prec::Additive => Prec::Multiplicative,
+            Prec::Multiplicative => Prec::Exponent,
+            Prec::Exponent => Prec::At,
+            Prec::At => Prec::Collate,
+            Prec::Collate => Prec::Unary,
+            Prec::Unary | Prec::Typecast => Prec::Typecast,
+        }
+    }
+
+    fn is_nonassoc(self) -> bool {
+        matches!(
+            self,
+            Prec::Is | Prec::Comparison | Prec::Like | Prec::Escape
+        )
+    }
+}
+
+impl Parser<'_> {
+    /// Parses a general expression (`a_expr`).
+    pub(super) fn parse_expr(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        self.parse_expr_prec(tok0, Prec::Lowest, false, diags)
+    }
+
+    /// Parses a restricted expression (`b_expr`), which excludes the keyword operators
+    /// such as `AND` and `IS NULL` so that it can be used where `AND` has a special meaning,
+    /// as in the lower bound of `BETWEEN`.
+    pub(super) fn parse_b_expr(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        self.parse_expr_prec(tok0, Prec::Lowest, true, diags)
+    }
+
+    /// Parses a comma-separated list of expressions (`expr_list`).
+    pub(super) fn parse_expr_list(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<ExprNode>> {
+        self.parse_comma_list(tok0, diags, Self::parse_expr)
+    }
+
+    /// Parses an expression consisting of operators binding at least as tight as `min_prec`.
+    fn parse_expr_prec(
+        &mut self,
+        tok0: Token,
+        min_prec: Prec,
+        restricted: bool,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let (expr, tok1) = self.parse_prefix_expr(tok0, restricted, diags)?;
+        self.parse_infix_exprs(expr, tok1, min_prec, restricted, diags)
+    }
+
+    /// Parses the rest of a general expression (`a_expr`) after its first operand,
+    /// which has been parsed by [`Parser::parse_prefix_expr`].
+    pub(super) fn parse_expr_continued(
+        &mut self,
+        left: ExprNode,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        self.parse_infix_exprs(left, tok0, Prec::Lowest, false, diags)
+    }
+
+    /// Parses infix and postfix operators binding at least as tight as `min_prec`
+    /// after the first operand.
+    fn parse_infix_exprs(
+        &mut self,
+        mut expr: ExprNode,
+        mut tok0: Token,
+        min_prec: Prec,
+        restricted: bool,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        // Non-associative operators of the same level cannot be chained, as in `a < b < c`.
+        // Postfix forms like `a IS NULL IS NULL` are fine, as nothing is left to associate.
+        let mut nonassoc_prec = None;
+        while let Some(prec) = infix_prec(&tok0, restricted) {
+            if prec < min_prec {
+                break;
+            }
+            // `SIMILAR` without `TO` is a column label, or separates the arguments of `SUBSTRING`.
+            if tok0.is_keyword(&Symbol::KEYWORD_similar)
+                && !self
+                    .tokens
+                    .peek_token(diags)
+                    .is_keyword(&Symbol::KEYWORD_to)
+            {
+                break;
+            }
+            if nonassoc_prec == Some(prec) {
+                self.syntax_error(&tok0, diags);
+                return Err(tok0);
+            }
+            (expr, tok0) = self.parse_infix_expr(expr, tok0, prec, restricted, diags)?;
+            nonassoc_prec = (prec.is_nonassoc() && ends_with_operand(&expr)).then_some(prec);
+        }
+        Ok((expr, tok0))
+    }
+
+    /// Parses a primary expression or a prefix operator application.
+    pub(super) fn parse_prefix_expr(
+        &mut self,
+        tok0: Token,
+        restricted: bool,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        match &tok0.kind {
+            TokenKind::Plus | TokenKind::Minus => {
+                let op = OperatorName::from(if tok0.kind == TokenKind::Plus {
+                    "+"
+                } else {
+                    "-"
+                });
+                let tok1 = self.tokens.next_token(diags);
+                let (operand, tok2) = self.parse_expr_prec(tok1, Prec::Unary, restricted, diags)?;
+                Ok((unary_op(op, tok0.range, operand), tok2))
+            }
+            TokenKind::UserOp(name) => {
+                let op = OperatorName::from(name.as_str());
+                let tok1 = self.tokens.next_token(diags);
+                let (operand, tok2) =
+                    self.parse_expr_prec(tok1, Prec::Op.next(), restricted, diags)?;
+                Ok((unary_op(op, tok0.range, operand), tok2))
+            }
+            TokenKind::Identifier {
+                name: Symbol::KEYWORD_operator,
+                quoted: false,
+            } => {
+                let tok1 = self.tokens.next_token(diags);
+                if tok1.kind != TokenKind::LParen {
+                    return self.parse_columnref(tok0, tok1, diags);
+                }
+                let (op, tok2) = self.parse_qual_op_body(tok1, diags)?;
+                let (operand, tok3) =
+                    self.parse_expr_prec(tok2, Prec::Op.next(), restricted, diags)?;
+                Ok((unary_op(op, tok0.range, operand), tok3))
+            }
+            TokenKind::Identifier {
+                name: Symbol::KEYWORD_not,
+                quoted: false,
+            }
+            | TokenKind::NotLa
+                if !restricted =>
+            {
+                let tok1 = self.tokens.next_token(diags);
+                let (operand, tok2) = self.parse_expr_prec(tok1, Prec::Not, false, diags)?;
+                let expr = ExprNode {
+                    range: join(tok0.range, operand.range),
+                    kind: ExprKind::Not {
+                        operand: Box::new(operand),
+                    },
+                };
+                Ok((expr, tok2))
+            }
+            // `DEFAULT` is accepted anywhere in `a_expr`, though only valid in `VALUES` and `SET`.
+            TokenKind::Identifier {
+                name: Symbol::KEYWORD_default,
+                quoted: false,
+            } if !restricted => {
+                let expr = ExprNode {
+                    kind: ExprKind::Default,
+                    range: tok0.range,
+                };
+                Ok((expr, self.tokens.next_token(diags)))
+            }
+            _ => self.parse_primary_expr(tok0, diags),
+        }
+    }
+
+    fn parse_infix_expr(
+        &mut self,
+        left: ExprNode,
+        tok0: Token,
+        prec: Prec,
+        restricted: bool,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let Some(keyword) = tok0.keyword().cloned() else {
+            if tok0.kind == TokenKind::ColonColon {
+                let tok1 = self.tokens.next_token(diags);
+                let (type_name, tok2) = self.parse_type_name(tok1, diags)?;
+                let range = join(left.range, type_name.range);
+                return Ok((type_cast(left, type_name, range), tok2));
+            }
+            if tok0.kind == TokenKind::NotLa {
+                let tok1 = self.tokens.next_token(diags);
+                return self.parse_negatable_infix_expr(left, tok1, true, diags);
+            }
+            let op = symbol_op(&tok0.kind).expect("not an operator token");
+            let tok1 = self.tokens.next_token(diags);
+            if !restricted && starts_quantifier(&tok1) {
+                return self.parse_quantified_expr(op, left, tok1, diags);
+            }
+            let (right, tok2) = self.parse_expr_prec(tok1, prec.next(), restricted, diags)?;
+            return Ok((binary_op(op, left, right), tok2));
+        };
+        match keyword {
+            Symbol::KEYWORD_operator => {
+                let tok1 = self.tokens.next_token(diags);
+                let (op, tok2) = self.parse_qual_op_body(tok1, diags)?;
+                if !restricted && starts_quantifier(&tok2) {
+                    return self.parse_quantified_expr(op, left, tok2, diags);
+                }
+                let (right, tok3) = self.parse_expr_prec(tok2, prec.next(), restricted, diags)?;
+                Ok((binary_op(op, left, right), tok3))
+            }
+            Symbol::KEYWORD_and | Symbol::KEYWORD_or => {
+                let tok1 = self.tokens.next_token(diags);
+                let (right, tok2) = self.parse_expr_prec(tok1, prec.next(), false, diags)?;
+                let range = join(left.range, right.range);
+                let (left, right) = (Box::new(left), Box::new(right));
+                let kind = if keyword == Symbol::KEYWORD_and {
+                    ExprKind::And { left, right }
+                } else {
+                    ExprKind::Or { left, right }
+                };
+                Ok((ExprNode { kind, range }, tok2))
+            }
+            Symbol::KEYWORD_is => self.parse_is_expr(left, restricted, diags),
+            Symbol::KEYWORD_collate => {
+                let tok1 = self.tokens.next_token(diags);
+                let (collation, tok2) = self.parse_any_name(tok1, diags)?;
+                let expr = ExprNode {
+                    range: CodeRange {
+                        start: left.range.start,
+                        end: self.tokens.prev_end(),
+                    },
+                    kind: ExprKind::Collate {
+                        operand: Box::new(left),
+                        collation,
+                    },
+                };
+                Ok((expr, tok2))
+            }
+            Symbol::KEYWORD_isnull | Symbol::KEYWORD_notnull => {
+                let expr = ExprNode {
+                    range: join(left.range, tok0.range),
+                    kind: ExprKind::IsNull {
+                        operand: Box::new(left),
+                        negated: keyword == Symbol::KEYWORD_notnull,
+                    },
+                };
+                Ok((expr, self.tokens.next_token(diags)))
+            }
+            Symbol::KEYWORD_at => {
+                let tok1 = self.tokens.next_token(diags);
+                if tok1.is_keyword(&Symbol::KEYWORD_local) {
+                    let expr = ExprNode {
+                        range: join(left.range, tok1.range),
+                        kind: ExprKind::AtTimeZone {
+                            operand: Box::new(left),
+                            zone: None,
+                        },
+                    };
+                    return Ok((expr, self.tokens.next_token(diags)));
+                }
+                let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_time, diags)?;
+                let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_zone, diags)?;
+                let (zone, tok4) = self.parse_expr_prec(tok3, prec.next(), false, diags)?;
+                let expr = ExprNode {
+                    range: join(left.range, zone.range),
+                    kind: ExprKind::AtTimeZone {
+                        operand: Box::new(left),
+                        zone: Some(Box::new(zone)),
+                    },
+                };
+                Ok((expr, tok4))
+            }
+            _ => self.parse_negatable_infix_expr(left, tok0, false, diags),
+        }
+    }
+
+    /// Parses the `IS ...` postfix forms after `IS`.
+    fn parse_is_expr(
+        &mut self,
+        left: ExprNode,
+        restricted: bool,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let start = left.range.start;
+        let mut tok1 = self.tokens.next_token(diags);
+        let negated = tok1.is_keyword(&Symbol::KEYWORD_not);
+        if negated {
+            tok1 = self.tokens.next_token(diags);
+        }
+        let operand = Box::new(left);
+        let kind = match tok1.keyword() {
+            Some(&Symbol::KEYWORD_distinct) => {
+                let tok2 = self.tokens.next_token(diags);
+                let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_from, diags)?;
+                let (right, tok4) =
+                    self.parse_expr_prec(tok3, Prec::Is.next(), restricted, diags)?;
+                let expr = ExprNode {
+                    range: join(operand.range, right.range),
+                    kind: ExprKind::IsDistinctFrom {
+                        left: operand,
+                        right: Box::new(right),
+                        negated,
+                    },
+                };
+                return Ok((expr, tok4));
+            }
+            Some(&Symbol::KEYWORD_null) if !restricted => ExprKind::IsNull { operand, negated },
+            Some(&Symbol::KEYWORD_json) if !restricted => {
+                let tok2 = self.tokens.next_token(diags);
+                let (item_type, tok3) = match tok2.keyword() {
+                    Some(&Symbol::KEYWORD_value) => {
+                        (JsonValueType::Any, self.tokens.next_token(diags))
+                    }
+                    Some(&Symbol::KEYWORD_object) => {
+                        (JsonValueType::Object, self.tokens.next_token(diags))
+                    }
+                    Some(&Symbol::KEYWORD_array) => {
+                        (JsonValueType::Array, self.tokens.next_token(diags))
+                    }
+                    Some(&Symbol::KEYWORD_scalar) => {
+                        (JsonValueType::Scalar, self.tokens.next_token(diags))
+                    }
+                    _ => (JsonValueType::Any, tok2),
+                };
+                let (unique_keys, tok4) = self.parse_opt_json_unique_keys(tok3, diags)?;
+                let expr = ExprNode {
+                    kind: ExprKind::IsJson {
+                        operand,
+                        item_type,
+                        unique_keys,
+                        negated,
+                    },
+                    range: CodeRange {
+                        start,
+                        end: self.tokens.prev_end(),
+                    },
+                };
+                return Ok((expr, tok4));
+            }
+            // `IS [NOT] [form] NORMALIZED` calls `is_normalized`.
+            Some(&Symbol::KEYWORD_normalized) if !restricted => {
+                return Ok(self.is_normalized(vec![*operand], negated, diags));
+            }
+            _ if !restricted && starts_unicode_normal_form(&tok1) => {
+                let (form, tok2) = self.parse_unicode_normal_form(tok1, diags)?;
+                if !tok2.is_keyword(&Symbol::KEYWORD_normalized) {
+                    self.syntax_error(&tok2, diags);
+                    return Err(tok2);
+                }
+                return Ok(self.is_normalized(vec![*operand, form], negated, diags));
+            }
+            Some(&(Symbol::KEYWORD_true | Symbol::KEYWORD_false | Symbol::KEYWORD_unknown))
+                if !restricted =>
+            {
+                let value = match tok1.keyword() {
+                    Some(&Symbol::KEYWORD_true) => Some(true),
+                    Some(&Symbol::KEYWORD_false) => Some(false),
+                    _ => None,
+                };
+                ExprKind::IsBoolean {
+                    operand,
+                    value,
+                    negated,
+                }
+            }
+            _ => {
+                self.syntax_error(&tok1, diags);
+                return Err(tok1);
+            }
+        };
+        let range = CodeRange {
+            start,
+            end: tok1.range.end,
+        };
+        Ok((ExprNode { kind, range }, self.tokens.next_token(diags)))
+    }
+
+    /// Builds `IS [NOT] NORMALIZED` ending at the current `NORMALIZED` keyword,
+    /// and returns the following token.
+    fn is_normalized(
+        &mut self,
+        args: Vec<ExprNode>,
+        negated: bool,
+        diags: &mut CodeDiagnostics,
+    ) -> (ExprNode, Token) {
+        let tok0 = self.tokens.next_token(diags);
+        let range = CodeRange {
+            start: args[0].range.start,
+            end: self.tokens.prev_end(),
+        };
+        let mut expr = ExprNode {
+            kind: sql_func_call("is_normalized", args),
+            range,
+        };
+        if negated {
+            expr = ExprNode {
+                kind: ExprKind::Not {
+                    operand: Box::new(expr),
+                },
+                range,
+            };
+        }
+        (expr, tok0)
+    }
+
+    /// Parses `BETWEEN`, `IN`, `LIKE`, `ILIKE`, and `SIMILAR TO`, which can be preceded by `NOT`.
+    fn parse_negatable_infix_expr(
+        &mut self,
+        left: ExprNode,
+        tok0: Token,
+        negated: bool,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let operand = Box::new(left);
+        match tok0.keyword() {
+            Some(&Symbol::KEYWORD_between) => {
+                let mut tok1 = self.tokens.next_token(diags);
+                let symmetric = tok1.is_keyword(&Symbol::KEYWORD_symmetric);
+                if symmetric || tok1.is_keyword(&Symbol::KEYWORD_asymmetric) {
+                    tok1 = self.tokens.next_token(diags);
+                }
+                let (low, tok2) = self.parse_b_expr(tok1, diags)?;
+                let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_and, diags)?;
+                let (high, tok4) = self.parse_expr_prec(tok3, Prec::Like.next(), false, diags)?;
+                let expr = ExprNode {
+                    range: join(operand.range, high.range),
+                    kind: ExprKind::Between {
+                        operand,
+                        low: Box::new(low),
+                        high: Box::new(high),
+                        negated,
+                        symmetric,
+                    },
+                };
+                Ok((expr, tok4))
+            }
+            Some(&Symbol::KEYWORD_in) => {
+                let tok1 = self.tokens.next_token(diags);
+                let ((contents, range), tok2) = self.parse_paren_contents(tok1, true, diags)?;
+                let range = join(operand.range, range);
+                let kind = match contents {
+                    ParenContents::Query(query) => ExprKind::InSubquery {
+                        operand,
+                        query,
+                        negated,
+                    },
+                    ParenContents::Exprs(list) => ExprKind::InList {
+                        operand,
+                        list,
+                        negated,
+                    },
+                };
+                Ok((ExprNode { kind, range }, tok2))
+            }
+            Some(&(Symbol::KEYWORD_like | Symbol::KEYWORD_ilike | Symbol::KEYWORD_similar)) => {
+                let mut tok1 = self.tokens.next_token(diags);
+                let kind = match tok0.keyword() {
+                    Some(&Symbol::KEYWORD_like) => LikeKind::Like,
+                    Some(&Symbol::KEYWORD_ilike) => LikeKind::ILike,
+                    _ => {
+                        tok1 = self.expect_keyword(tok1, Symbol::KEYWORD_to, diags)?;
+                        LikeKind::SimilarTo
+                    }
+                };
+                if kind != LikeKind::SimilarTo && starts_quantifier(&tok1) {
+                    let op = match (kind, negated) {
+                        (LikeKind::Like, false) => "~~",
+                        (LikeKind::Like, true) => "!~~",
+                        (_, false) => "~~*",
+                        (_, true) => "!~~*",
+                    };
+                    return self.parse_quantified_expr(
+                        OperatorName::from(op),
+                        *operand,
+                        tok1,
+                        diags,
+                    );
+                }
+                let (pattern, mut tok2) =
+                    self.parse_expr_prec(tok1, Prec::Like.next(), false, diags)?;
+                let mut escape = None;
+                if tok2.is_keyword(&Symbol::KEYWORD_escape) {
+                    let tok3 = self.tokens.next_token(diags);
+                    let (expr, tok4) =
+                        self.parse_expr_prec(tok3, Prec::Like.next(), false, diags)?;
+                    escape = Some(Box::new(expr));
+                    tok2 = tok4;
+                }
+                let end = escape.as_ref().map_or(pattern.range, |e| e.range);
+                let expr = ExprNode {
+                    range: join(operand.range, end),
+                    kind: ExprKind::Like {
+                        kind,
+                        operand,
+                        pattern: Box::new(pattern),
+                        escape,
+                        negated,
+                    },
+                };
+                Ok((expr, tok2))
+            }
+            _ => {
+                self.syntax_error(&tok0, diags);
+                Err(tok0)
+            }
+        }
+    }
+
+    /// Parses `( any_operator )` following `OPERATOR`.
+    pub(super) fn parse_qual_op_body(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<OperatorName> {
+        let mut tok1 = self.expect_token(tok0, TokenKind::LParen, diags)?;
+        let mut schema = Vec::new();
+        loop {
+            if let TokenKind::Identifier { name, quoted } = &tok1.kind
+                && (*quoted || name.is_col_id())
+            {
+                schema.push(name.clone());
+                let tok2 = self.tokens.next_token(diags);
+                tok1 = self.expect_token(tok2, TokenKind::Dot, diags)?;
+                continue;
+            }
+            let Some(op) = symbol_op(&tok1.kind) else {
+                self.syntax_error(&tok1, diags);
+                return Err(tok1);
+            };
+            let tok2 = self.tokens.next_token(diags);
+            let tok3 = self.expect_token(tok2, TokenKind::RParen, diags)?;
+            let op = OperatorName {
+                schema,
+                name: op.name,
+            };
+            return Ok((op, tok3));
+        }
+    }
+
+    /// Parses `CAST(a AS t)`.
+    pub(super) fn parse_cast(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let start = tok0.range.start;
+        let tok1 = self.expect_keyword(tok0, Symbol::KEYWORD_cast, diags)?;
+        let tok2 = self.expect_token(tok1, TokenKind::LParen, diags)?;
+        let (operand, tok3) = self.parse_expr(tok2, diags)?;
+        let tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_as, diags)?;
+        let (type_name, tok5) = self.parse_type_name(tok4, diags)?;
+        let tok6 = self.expect_token(tok5, TokenKind::RParen, diags)?;
+        let range = CodeRange {
+            start,
+            end: self.tokens.prev_end(),
+        };
+        Ok((type_cast(operand, type_name, range), tok6))
+    }
+
+    /// Parses a primary expression (`c_expr`), such as a literal or a column reference.
+    fn parse_primary_expr(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        match tok0.kind {
+            TokenKind::Param(index) => {
+                let expr = ExprNode {
+                    kind: ExprKind::Param { index },
+                    range: tok0.range,
+                };
+                let tok1 = self.tokens.next_token(diags);
+                self.parse_opt_indirection(expr, tok1, diags)
+            }
+            TokenKind::Identifier {
+                name: Symbol::KEYWORD_cast,
+                quoted: false,
+            } => self.parse_cast(tok0, diags),
+            TokenKind::Identifier {
+                name: Symbol::KEYWORD_array,
+                quoted: false,
+            } => {
+                let tok1 = self.tokens.next_token(diags);
+                if tok1.kind == TokenKind::LParen {
+                    let (query, tok2) = self.parse_select_with_parens(tok1, diags)?;
+                    let expr = ExprNode {
+                        range: join(tok0.range, query.range),
+                        kind: ExprKind::ArraySubquery {
+                            query: Box::new(query),
+                        },
+                    };
+                    return Ok((expr, tok2));
+                }
+                let (array, tok2) = self.parse_array_expr(tok1, diags)?;
+                let expr = ExprNode {
+                    kind: array.kind,
+                    range: join(tok0.range, array.range),
+                };
+                Ok((expr, tok2))
+            }
+            TokenKind::Identifier {
+                name: Symbol::KEYWORD_case,
+                quoted: false,
+            } => self.parse_case_expr(tok0, diags),
+            // `EXISTS` is a column name unless followed by `(`.
+            TokenKind::Identifier {
+                name: Symbol::KEYWORD_exists,
+                quoted: false,
+            } if self.tokens.peek_token(diags).kind == TokenKind::LParen => {
+                let tok1 = self.tokens.next_token(diags);
+                let (query, tok2) = self.parse_select_with_parens(tok1, diags)?;
+                let expr = ExprNode {
+                    range: join(tok0.range, query.range),
+                    kind: ExprKind::Exists {
+                        query: Box::new(query),
+                    },
+                };
+                Ok((expr, tok2))
+            }
+            // Keywords like `COALESCE` are mostly column names unless followed by `(`.
+            _ if self.starts_func_expr_common_subexpr(&tok0, diags) => {
+                self.parse_func_expr_common_subexpr(tok0, diags)
+            }
+            // Keywords like `JSON_OBJECT` are column names unless followed by `(`.
+            _ if starts_json_func(&tok0)
+                && self.tokens.peek_token(diags).kind == TokenKind::LParen =>
+            {
+                self.parse_json_func(tok0, diags)
+            }
+            // `ROW` is a column name unless followed by `(`.
+            TokenKind::Identifier {
+                name: Symbol::KEYWORD_row,
+                quoted: false,
+            } if self.tokens.peek_token(diags).kind == TokenKind::LParen => {
+                let (row, tok1) = self.parse_row(tok0, diags)?;
+                self.parse_opt_overlaps(row, tok1, diags)
+            }
+            // Type names like `TIME` are column names unless followed by a literal or
+            // the rest of the type name.
+            _ if starts_special_type_name(&tok0)
+                && continues_special_type_const(self.tokens.peek_token(diags)) =>
+            {
+                self.parse_special_type_const(tok0, diags)
+            }
+            TokenKind::Identifier { ref name, quoted }
+                if quoted || name.is_col_id() || name.is_type_function_name() =>
+            {
+                let tok1 = self.tokens.next_token(diags);
+                self.parse_columnref(tok0, tok1, diags)
+            }
+            TokenKind::LParen => {
+                let ((contents, range), tok1) = self.parse_paren_contents(tok0, true, diags)?;
+                let expr = match contents {
+                    ParenContents::Query(query) => ExprNode {
+                        kind: ExprKind::ScalarSubquery { query },
+                        range,
+                    },
+                    // Parentheses only affect the range.
+                    ParenContents::Exprs(mut exprs) if exprs.len() == 1 => ExprNode {
+                        kind: exprs.pop().unwrap().kind,
+                        range,
+                    },
+                    ParenContents::Exprs(fields) => {
+                        let row = ExprNode {
+                            kind: ExprKind::Row {
+                                fields,
+                                explicit: false,
+                            },
+                            range,
+                        };
+                        return self.parse_opt_overlaps(row, tok1, diags);
+                    }
+                };
+                self.parse_opt_indirection(expr, tok1, diags)
+            }
+            _ => self.parse_const(tok0, diags),
+        }
+    }
+
+    /// Parses `ROW(fields)`, or `(fields)` with at least two fields (`row`).
+    fn parse_row(&mut self, tok0: Token, diags: &mut CodeDiagnostics) -> ParseResult<ExprNode> {
+        let start = tok0.range.start;
+        let explicit = tok0.is_keyword(&Symbol::KEYWORD_row);
+        let tok1 = if explicit {
+            self.tokens.next_token(diags)
+        } else {
+            tok0
+        };
+        let tok2 = self.expect_token(tok1, TokenKind::LParen, diags)?;
+        let (fields, tok3) = if explicit && tok2.kind == TokenKind::RParen {
+            (Vec::new(), tok2)
+        } else {
+            self.parse_expr_list(tok2, diags)?
+        };
+        if !explicit && fields.len() < 2 {
+            self.syntax_error(&tok3, diags);
+            return Err(tok3);
+        }
+        let tok4 = self.expect_token(tok3, TokenKind::RParen, diags)?;
+        let expr = ExprNode {
+            kind: ExprKind::Row { fields, explicit },
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((expr, tok4))
+    }
+
+    /// Parses `OVERLAPS row` after a row, if any, which calls `overlaps` with the fields
+    /// of both rows.
+    fn parse_opt_overlaps(
+        &mut self,
+        left: ExprNode,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        if !tok0.is_keyword(&Symbol::KEYWORD_overlaps) {
+            return Ok((left, tok0));
+        }
+        let tok1 = self.tokens.next_token(diags);
+        let (right, tok2) = self.parse_row(tok1, diags)?;
+        let range = join(left.range, right.range);
+        let mut args = Vec::new();
+        for (row, side) in [(left, "left"), (right, "right")] {
+            let ExprKind::Row { fields, .. } = row.kind else {
+                unreachable!("not a row");
+            };
+            if fields.len() != 2 {
+                diags.add(CodeDiagnostic::WrongOverlapsArity {
+                    range: row.range,
+                    side,
+                });
+            }
+            args.extend(fields);
+        }
+        let expr = ExprNode {
+            kind: sql_func_call("overlaps", args),
+            range,
+        };
+        Ok((expr, tok2))
+    }
+
+    /// Parses `[elements]` after `ARRAY` or nested in it (`array_expr`).
+    fn parse_array_expr(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let start = tok0.range.start;
+        let tok1 = self.expect_token(tok0, TokenKind::LBracket, diags)?;
+        let (elements, tok2) = match tok1.kind {
+            TokenKind::RBracket => (Vec::new(), tok1),
+            TokenKind::LBracket => self.parse_comma_list(tok1, diags, Self::parse_array_expr)?,
+            _ => self.parse_expr_list(tok1, diags)?,
+        };
+        let tok3 = self.expect_token(tok2, TokenKind::RBracket, diags)?;
+        let expr = ExprNode {
+            kind: ExprKind::Array { elements },
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((expr, tok3))
+    }
+
+    /// Parses a parenthesized query or list of expressions, along with the range including
+    /// the parentheses. The list must have exactly one expression unless `allow_list` is true.
+    ///
+    /// As in PostgreSQL, a parenthesized scalar subquery is still a query, so `((SELECT 1))` and
+    /// `((SELECT 1) UNION SELECT 2)` are queries rather than expressions.
+    fn parse_paren_contents(
+        &mut self,
+        tok0: Token,
+        allow_list: bool,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<(ParenContents, CodeRange)> {
+        let start = tok0.range.start;
+        let tok1 = self.expect_token(tok0, TokenKind::LParen, diags)?;
+        let (contents, tok2) = if starts_select(&tok1) {
+            let (query, tok2) = self.parse_select(tok1, diags)?;
+            (ParenContents::Query(Box::new(query)), tok2)
+        } else {
+            let (first, tok2) = self.parse_expr(tok1, diags)?;
+            if is_bare_subquery(&first)
+                && (tok2.kind == TokenKind::RParen || continues_select(&tok2))
+            {
+                let ExprKind::ScalarSubquery { query } = first.kind else {
+                    unreachable!("not a subquery");
+                };
+                let (query, tok3) =
+                    self.parse_select_continued(query.range.start, None, *query, tok2, diags)?;
+                (ParenContents::Query(Box::new(query)), tok3)
+            } else if allow_list && tok2.kind == TokenKind::Comma {
+                let tok3 = self.tokens.next_token(diags);
+                let (rest, tok4) = self.parse_expr_list(tok3, diags)?;
+                let mut exprs = vec![first];
+                exprs.extend(rest);
+                (ParenContents::Exprs(exprs), tok4)
+            } else {
+                (ParenContents::Exprs(vec![first]), tok2)
+            }
+        };
+        let tok3 = self.expect_token(tok2, TokenKind::RParen, diags)?;
+        let range = CodeRange {
+            start,
+            end: self.tokens.prev_end(),
+        };
+        let contents = match contents {
+            // Parentheses only affect the range.
+            ParenContents::Query(query) => {
+                ParenContents::Query(Box::new(SelectNode { range, ..*query }))
+            }
+            contents => contents,
+        };
+        Ok(((contents, range), tok3))
+    }
+
+    /// Parses `ANY (...)`, `SOME (...)`, or `ALL (...)` after an operator.
+    fn parse_quantified_expr(
+        &mut self,
+        op: OperatorName,
+        left: ExprNode,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let all = tok0.is_keyword(&Symbol::KEYWORD_all);
+        let tok1 = self.tokens.next_token(diags);
+        let ((contents, range), tok2) = self.parse_paren_contents(tok1, false, diags)?;
+        let expr_range = join(left.range, range);
+        let operand = Box::new(left);
+        let kind = match contents {
+            ParenContents::Query(query) => ExprKind::QuantifiedSubquery {
+                op,
+                all,
+                operand,
+                query,
+            },
+            ParenContents::Exprs(mut exprs) => ExprKind::QuantifiedArray {
+                op,
+                all,
+                operand,
+                // Parentheses only affect the range.
+                array: Box::new(ExprNode {
+                    kind: exprs.pop().unwrap().kind,
+                    range,
+                }),
+            },
+        };
+        let expr = ExprNode {
+            kind,
+            range: expr_range,
+        };
+        Ok((expr, tok2))
+    }
+
+    /// Parses `CASE ... END`.
+    fn parse_case_expr(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let tok1 = self.tokens.next_token(diags);
+        let (operand, tok2) = if tok1.is_keyword(&Symbol::KEYWORD_when) {
+            (None, tok1)
+        } else {
+            let (operand, tok2) = self.parse_expr(tok1, diags)?;
+            (Some(Box::new(operand)), tok2)
+        };
+        if !tok2.is_keyword(&Symbol::KEYWORD_when) {
+            self.syntax_error(&tok2, diags);
+            return Err(tok2);
+        }
+        let mut whens = Vec::new();
+        let mut tok3 = tok2;
+        while tok3.is_keyword(&Symbol::KEYWORD_when) {
+            let start = tok3.range.start;
+            let tok4 = self.tokens.next_token(diags);
+            let (condition, tok5) = self.parse_expr(tok4, diags)?;
+            let tok6 = self.expect_keyword(tok5, Symbol::KEYWORD_then, diags)?;
+            let (result, tok7) = self.parse_expr(tok6, diags)?;
+            whens.push(CaseWhen {
+                range: CodeRange {
+                    start,
+                    end: result.range.end,
+                },
+                condition,
+                result,
+            });
+            tok3 = tok7;
+        }
+        let (default, tok4) = if tok3.is_keyword(&Symbol::KEYWORD_else) {
+            let tok4 = self.tokens.next_token(diags);
+            let (default, tok5) = self.parse_expr(tok4, diags)?;
+            (Some(Box::new(default)), tok5)
+        } else {
+            (None, tok3)
+        };
+        let tok5 = self.expect_keyword(tok4, Symbol::KEYWORD_end, diags)?;
+        let expr = ExprNode {
+            kind: ExprKind::Case {
+                operand,
+                whens,
+                default,
+            },
+            range: CodeRange {
+                start: tok0.range.start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((expr, tok5))
+    }
+
+    /// Parses subscripts and field selections after an expression if any (`opt_indirection`).
+    fn parse_opt_indirection(
+        &mut self,
+        operand: ExprNode,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let (items, tok1) = self.parse_opt_indirection_items(tok0, diags)?;
+        if items.is_empty() {
+            return Ok((operand, tok1));
+        }
+        let expr = ExprNode {
+            range: CodeRange {
+                start: operand.range.start,
+                end: self.tokens.prev_end(),
+            },
+            kind: ExprKind::Indirection {
+                operand: Box::new(operand),
+                items,
+            },
+        };
+        Ok((expr, tok1))
+    }
+
+    /// Parses subscripts and field selections, if any.
+    pub(super) fn parse_opt_indirection_items(
+        &mut self,
+        mut tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<IndirectionItem>> {
+        let mut items = Vec::new();
+        loop {
+            match tok0.kind {
+                TokenKind::Dot => {
+                    let tok1 = self.tokens.next_token(diags);
+                    match tok1.kind {
+                        // Any keyword can be used after a dot (`attr_name`).
+                        TokenKind::Identifier { name, .. } => {
+                            items.push(IndirectionItem::Field(name))
+                        }
+                        TokenKind::Asterisk => {
+                            items.push(IndirectionItem::Star);
+                            tok0 = self.tokens.next_token(diags);
+                            break;
+                        }
+                        _ => {
+                            self.syntax_error(&tok1, diags);
+                            return Err(tok1);
+                        }
+                    }
+                    tok0 = self.tokens.next_token(diags);
+                }
+                TokenKind::LBracket => {
+                    let tok1 = self.tokens.next_token(diags);
+                    let (lower, tok2) = self.parse_opt_slice_bound(tok1, diags)?;
+                    if tok2.kind == TokenKind::Colon {
+                        let tok3 = self.tokens.next_token(diags);
+                        let (upper, tok4) = self.parse_opt_slice_bound(tok3, diags)?;
+                        items.push(IndirectionItem::Slice { lower, upper });
+                        tok0 = self.expect_token(tok4, TokenKind::RBracket, diags)?;
+                    } else {
+                        let Some(index) = lower else {
+                            self.syntax_error(&tok2, diags);
+                            return Err(tok2);
+                        };
+                        items.push(IndirectionItem::Subscript(index));
+                        tok0 = self.expect_token(tok2, TokenKind::RBracket, diags)?;
+                    }
+                }
+                _ => break,
+            }
+        }
+        Ok((items, tok0))
+    }
+
+    /// Parses a bound of a slice, which is omitted before `:` or `]` (`opt_slice_bound`).
+    fn parse_opt_slice_bound(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Option<Box<ExprNode>>> {
+        if matches!(tok0.kind, TokenKind::Colon | TokenKind::RBracket) {
+            return Ok((None, tok0));
+        }
+        let (expr, tok1) = self.parse_expr(tok0, diags)?;
+        Ok((Some(Box::new(expr)), tok1))
+    }
+
+    /// Parses a literal constant (`AexprConst`), except the typed literals of generic types.
+    pub(super) fn parse_const(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        if starts_special_type_name(&tok0) {
+            return self.parse_special_type_const(tok0, diags);
+        }
+        let kind = match tok0.kind {
+            TokenKind::Integer(value) => match i64::try_from(&value) {
+                Ok(value) => ExprKind::IntegerLiteral { value },
+                // Too large integers are treated as numeric, as PostgreSQL does.
+                Err(_) => ExprKind::NumericLiteral {
+                    mantissa: value,
+                    scale: 0,
+                },
+            },
+            TokenKind::Numeric { mantissa, scale } => ExprKind::NumericLiteral { mantissa, scale },
+            TokenKind::String(value) | TokenKind::DollarString(value) => {
+                ExprKind::StringLiteral { value }
+            }
+            // `N'foo'` is a shorthand for `NCHAR 'foo'`.
+            TokenKind::NationalString(value) => {
+                let literal = ExprNode {
+                    kind: ExprKind::StringLiteral { value },
+                    range: tok0.range,
+                };
+                let type_name = TypeName {
+                    name: system_type_name("bpchar"),
+                    modifiers: Vec::new(),
+                    interval_fields: None,
+                    array_bounds: Vec::new(),
+                    setof: false,
+                    pct_type: false,
+                    range: tok0.range,
+                };
+                let expr = type_cast(literal, type_name, tok0.range);
+                return Ok((expr, self.tokens.next_token(diags)));
+            }
+            TokenKind::BitString(value) => ExprKind::BitStringLiteral { value, hex: false },
+            TokenKind::HexString(value) => ExprKind::BitStringLiteral { value, hex: true },
+            TokenKind::Identifier {
+                name: Symbol::KEYWORD_true | Symbol::KEYWORD_false,
+                quoted: false,
+            } => ExprKind::BooleanLiteral {
+                value: tok0.is_keyword(&Symbol::KEYWORD_true),
+            },
+            TokenKind::Identifier {
+                name: Symbol::KEYWORD_null,
+                quoted: false,
+            } => ExprKind::NullLiteral,
+            _ => {
+                self.syntax_error(&tok0, diags);
+                return Err(tok0);
+            }
+        };
+        let expr = ExprNode {
+            kind,
+            range: tok0.range,
+        };
+        Ok((expr, self.tokens.next_token(diags)))
+    }
+
+    /// Parses the rest of a column reference (`columnref`) or a function call
+    /// (`func_application`) after its first name.
+    fn parse_columnref(
+        &mut self,
+        tok0: Token,
+        mut tok1: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let TokenKind::Identifier { name, quoted } = tok0.kind else {
+            unreachable!("columnref must start with an identifier");
+        };
+        // Keywords like `LEFT` can only be used as function or type names.
+        if !(quoted || name.is_col_id()) && tok1.kind != TokenKind::LParen && !is_sconst(&tok1) {
+            self.syntax_error(&tok1, diags);
+            return Err(tok1);
+        }
+        let mut fields = vec![name];
+        let mut range = tok0.range;
+        while tok1.kind == TokenKind::Dot {
+            let tok2 = self.tokens.next_token(diags);
+            match tok2.kind {
+                // Any keyword can be used after a dot (`attr_name`).
+                TokenKind::Identifier { name, .. } => {
+                    fields.push(name);
+                    range = join(range, tok2.range);
+                }
+                TokenKind::Asterisk => {
+                    let expr = ExprNode {
+                        kind: ExprKind::ColumnRef { fields, star: true },
+                        range: join(range, tok2.range),
+                    };
+                    return Ok((expr, self.tokens.next_token(diags)));
+                }
+                _ => {
+                    self.syntax_error(&tok2, diags);
+                    return Err(tok2);
+                }
+            }
+            tok1 = self.tokens.next_token(diags);
+        }
+        // Column name keywords like `INT` cannot be unqualified function or type names.
+        if quoted || fields.len() > 1 || fields[0].is_type_function_name() {
+            if tok1.kind == TokenKind::LParen {
+                return self.parse_func_expr(fields, range, tok1, diags);
+            }
+            if is_sconst(&tok1) {
+                return self.parse_generic_type_const(fields, range, tok1, diags);
+            }
+        }
+        let expr = ExprNode {
+            kind: ExprKind::ColumnRef {
+                fields,
+                star: false,
+            },
+            range,
+        };
+        if tok1.kind == TokenKind::LBracket {
+            return self.parse_opt_indirection(expr, tok1, diags);
+        }
+        Ok((expr, tok1))
+    }
+}
+
+/// The contents of parentheses in an expression.
+enum ParenContents {
+    Query(Box<SelectNode>),
+    Exprs(Vec<ExprNode>),
+}
+
+impl From<&str> for OperatorName {
+    fn from(name: &str) -> Self {
+        OperatorName {
+            schema: Vec::new(),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// The precedence of the token as an infix or postfix operator, if it is one.
+fn infix_prec(tok: &Token, restricted: bool) -> Option<Prec> {
+    let prec = match &tok.kind {
+        TokenKind::Lt
+        | TokenKind::Gt
+        | TokenKind::Eq
+        | TokenKind::Le
+        | TokenKind::Ge
+        | TokenKind::Neq => Prec::Comparison,
+        TokenKind::Plus | TokenKind::Minus => Prec::Additive,
+        TokenKind::Asterisk | TokenKind::Slash | TokenKind::Percent => Prec::Multiplicative,
+        TokenKind::Caret => Prec::Exponent,
+        TokenKind::UserOp(_) => Prec::Op,
+        TokenKind::ColonColon => Prec::Typecast,
+        TokenKind::NotLa if !restricted => Prec::Like,
+        TokenKind::Identifier {
+            name,
+            quoted: false,
+        } => match *name {
+            Symbol::KEYWORD_operator => Prec::Op,
+            // `IS DISTINCT FROM` is allowed in `b_expr`.
+            Symbol::KEYWORD_is => Prec::Is,
+            _ if restricted => return None,
+            Symbol::KEYWORD_or => Prec::Or,
+            Symbol::KEYWORD_and => Prec::And,
+            Symbol::KEYWORD_isnull | Symbol::KEYWORD_notnull => Prec::Is,
+            Symbol::KEYWORD_between
+            | Symbol::KEYWORD_in
+            | Symbol::KEYWORD_like
+            | Symbol::KEYWORD_ilike
+            | Symbol::KEYWORD_similar => Prec::Like,
+            Symbol::KEYWORD_at => Prec::At,
+            Symbol::KEYWORD_collate => Prec::Collate,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(prec)
+}
+
+/// Whether the expression is a parenthesized query without anything else, such as `(SELECT 1)`.
+pub(super) fn is_bare_subquery(expr: &ExprNode) -> bool {
+    matches!(&expr.kind, ExprKind::ScalarSubquery { query } if query.range == expr.range)
+}
+
+/// Whether the token is `ANY`, `SOME`, or `ALL` after an operator (`sub_type`).
+fn starts_quantifier(tok: &Token) -> bool {
+    matches!(
+        tok.keyword(),
+        Some(&(Symbol::KEYWORD_any | Symbol::KEYWORD_some | Symbol::KEYWORD_all))
+    )
+}
+
+/// The operator name of an operator token (`all_Op` in gram.y).
+pub(super) fn symbol_op(kind: &TokenKind) -> Option<OperatorName> {
+    let name = match kind {
+        TokenKind::Plus => "+",
+        TokenKind::Minus => "-",
+        TokenKind::Asterisk => "*",
+        TokenKind::Slash => "/",
+        TokenKind::Percent => "%",
+        TokenKind::Caret => "^",
+        TokenKind::Lt => "<",
+        TokenKind::Gt => ">",
+        TokenKind::Eq => "=",
+        TokenKind::Le => "<=",
+        TokenKind::Ge => ">=",
+        TokenKind::Neq => "<>",
+        TokenKind::UserOp(name) => name,
+        _ => return None,
+    };
+    Some(OperatorName::from(name))
+}
+
+fn unary_op(op: OperatorName, op_range: CodeRange, operand: ExprNode) -> ExprNode {
+    ExprNode {
+        range: join(op_range, operand.range),
+        kind: ExprKind::UnaryOp {
+            op,
+            operand: Box::new(operand),
+        },
+    }
+}
+
+fn binary_op(op: OperatorName, left: ExprNode, right: ExprNode) -> ExprNode {
+    ExprNode {
+        range: join(left.range, right.range),
+        kind: ExprKind::BinaryOp {
+            op,
+            left: Box::new(left),
+            right: Box::new(right),
+        },
+    }
+}
+
+/// Whether an operator application ends with an operand, unlike postfix forms such as `a IS NULL`
+/// and parenthesized ones such as `a IN (b)` and `a = ANY (b)`.
+fn ends_with_operand(expr: &ExprNode) -> bool {
+    !matches!(
+        expr.kind,
+        ExprKind::IsNull { .. }
+            | ExprKind::IsBoolean { .. }
+            | ExprKind::IsJson { .. }
+            | ExprKind::InList { .. }
+            | ExprKind::InSubquery { .. }
+            | ExprKind::QuantifiedArray { .. }
+            | ExprKind::QuantifiedSubquery { .. }
+            // `a IS [NOT] NORMALIZED`
+            | ExprKind::FuncCall(_)
+            | ExprKind::Not { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parser::sexp::{parse_expr_errors, parse_expr_sexp, parse_expr_src, syntax_error},
+        pos::pos,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_literals() {
+        assert_eq!(parse_expr_sexp("'foo'"), "'foo'");
+        assert_eq!(parse_expr_sexp("$$foo$$"), "'foo'");
+        assert_eq!(parse_expr_sexp("B'0101'"), "b'0101'");
+        assert_eq!(parse_expr_sexp("X'1F'"), "x'1F'");
+        assert_eq!(parse_expr_sexp("1.5"), "15e-1");
+        assert_eq!(parse_expr_sexp("true"), "true");
+        assert_eq!(parse_expr_sexp("FALSE"), "false");
+        assert_eq!(parse_expr_sexp("null"), "null");
+    }
+
+    #[test]
+    fn test_columnref() {
+        assert_eq!(parse_expr_sexp("a"), "a");
+        assert_eq!(parse_expr_sexp("\"Select\""), "Select");
+        assert_eq!(parse_expr_sexp("s.t.a"), "s.t.a");
+        assert_eq!(parse_expr_sexp("t.*"), "t.*");
+        // Any keyword can follow a dot.
+        assert_eq!(parse_expr_sexp("t.select"), "t.select");
+        // Unreserved and column name keywords can be column names.
+        assert_eq!(parse_expr_sexp("name + time"), "(+ name time)");
+    }
+
+    #[test]
+    fn test_columnref_reserved() {
+        let src = "1 + select";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "select", 0));
+    }
+
+    #[test]
+    fn test_arithmetic_precedence() {
+        assert_eq!(parse_expr_sexp("1 + 2 * 3 - 4"), "(- (+ 1 (* 2 3)) 4)");
+        assert_eq!(parse_expr_sexp("1 / 2 % 3"), "(% (/ 1 2) 3)");
+        assert_eq!(parse_expr_sexp("2 ^ 3 ^ 2"), "(^ (^ 2 3) 2)");
+        assert_eq!(parse_expr_sexp("2 * 3 ^ 2"), "(* 2 (^ 3 2))");
+    }
+
+    #[test]
+    fn test_unary_operators() {
+        assert_eq!(parse_expr_sexp("-a * b"), "(* (- a) b)");
+        assert_eq!(parse_expr_sexp("- - a"), "(- (- a))");
+        // Unary minus binds tighter than `^`, as in PostgreSQL.
+        assert_eq!(parse_expr_sexp("-2 ^ 2"), "(^ (- 2) 2)");
+        assert_eq!(parse_expr_sexp("+a"), "(+ a)");
+    }
+
+    #[test]
+    fn test_user_operators() {
+        assert_eq!(parse_expr_sexp("a <-> b + c"), "(<-> a (+ b c))");
+        assert_eq!(parse_expr_sexp("a || b || c"), "(|| (|| a b) c)");
+        assert_eq!(parse_expr_sexp("a || b = c"), "(= (|| a b) c)");
+        assert_eq!(parse_expr_sexp("@ a + b"), "(@ (+ a b))");
+        assert_eq!(parse_expr_sexp("@ a @ b"), "(@ (@ a) b)");
+    }
+
+    #[test]
+    fn test_qualified_operator() {
+        assert_eq!(
+            parse_expr_sexp("a operator(pg_catalog.+) b"),
+            "(pg_catalog.+ a b)"
+        );
+        assert_eq!(parse_expr_sexp("a OPERATOR(<->) b * c"), "(<-> a (* b c))");
+        assert_eq!(parse_expr_sexp("operator(-) a"), "(- a)");
+        // `operator` not followed by `(` is a column name.
+        assert_eq!(parse_expr_sexp("operator + 1"), "(+ operator 1)");
+    }
+
+    #[test]
+    fn test_qualified_operator_invalid() {
+        let src = "a operator(foo) b";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, ")", 0));
+    }
+
+    #[test]
+    fn test_boolean_operators() {
+        assert_eq!(
+            parse_expr_sexp("a = b and c <> d or not e"),
+            "(or (and (= a b) (<> c d)) (not e))"
+        );
+        assert_eq!(parse_expr_sexp("a or b and c"), "(or a (and b c))");
+        assert_eq!(parse_expr_sexp("not a = b"), "(not (= a b))");
+        assert_eq!(parse_expr_sexp("not not a"), "(not (not a))");
+        assert_eq!(parse_expr_sexp("a != b"), "(<> a b)");
+    }
+
+    #[test]
+    fn test_comparison_nonassoc() {
+        let src = "a < b < c";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "<", 1));
+        // The quantified form ends with parentheses, so nothing is left to associate.
+        assert_eq!(parse_expr_sexp("a = any(b) = c"), "(= (any = a b) c)");
+        assert_eq!(parse_expr_sexp("a in (b) in (c)"), "(in (in a (b)) (c))");
+        let src = "a = b + 1 >= c";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, ">=", 0));
+    }
+
+    #[test]
+    fn test_is() {
+        assert_eq!(parse_expr_sexp("a is null"), "(is-null a)");
+        assert_eq!(parse_expr_sexp("a IS NOT NULL"), "(is-not-null a)");
+        assert_eq!(parse_expr_sexp("a isnull"), "(is-null a)");
+        assert_eq!(parse_expr_sexp("a notnull"), "(is-not-null a)");
+        assert_eq!(parse_expr_sexp("a is true"), "(is-true a)");
+        assert_eq!(parse_expr_sexp("a is not false"), "(is-not-false a)");
+        assert_eq!(parse_expr_sexp("a is unknown"), "(is-unknown a)");
+        assert_eq!(parse_expr_sexp("a = b is null"), "(is-null (= a b))");
+        assert_eq!(parse_expr_sexp("a is null and b"), "(and (is-null a) b)");
+        assert_eq!(parse_expr_sexp("a is null = b"), "(= (is-null a) b)");
+    }
+
+    #[test]
+    fn test_is_nonassoc() {
+        // Postfix operators can be chained, as there is no right operand to associate.
+        assert_eq!(
+            parse_expr_sexp("a is null is null"),
+            "(is-null (is-null a))"
+        );
+        assert_eq!(parse_expr_sexp("a isnull is null"), "(is-null (is-null a))");
+        assert_eq!(
+            parse_expr_sexp("a is true is not null"),
+            "(is-not-null (is-true a))"
+        );
+        assert_eq!(
+            parse_expr_sexp("a is normalized is false"),
+            "(is-false (sql-call pg_catalog.is_normalized a))"
+        );
+        assert_eq!(
+            parse_expr_sexp("a is null is distinct from b"),
+            "(is-distinct-from (is-null a) b)"
+        );
+        let src = "a is distinct from b is null";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "is", 2));
+    }
+
+    #[test]
+    fn test_is_distinct_from() {
+        assert_eq!(
+            parse_expr_sexp("a is distinct from b"),
+            "(is-distinct-from a b)"
+        );
+        assert_eq!(
+            parse_expr_sexp("a is not distinct from b = c"),
+            "(is-not-distinct-from a (= b c))"
+        );
+    }
+
+    #[test]
+    fn test_between() {
+        assert_eq!(parse_expr_sexp("a between 1 and 2"), "(between a 1 2)");
+        assert_eq!(
+            parse_expr_sexp("a between 1 and 2 and b"),
+            "(and (between a 1 2) b)"
+        );
+        assert_eq!(
+            parse_expr_sexp("a not between symmetric b + 1 and c * 2"),
+            "(not-between-symmetric a (+ b 1) (* c 2))"
+        );
+        assert_eq!(
+            parse_expr_sexp("a between asymmetric b and c"),
+            "(between a b c)"
+        );
+        assert_eq!(
+            parse_expr_sexp("a = b between c and d"),
+            "(= a (between b c d))"
+        );
+        assert_eq!(
+            parse_expr_sexp("a between b is distinct from c and d"),
+            "(between a (is-distinct-from b c) d)"
+        );
+    }
+
+    #[test]
+    fn test_between_restricted_lower_bound() {
+        let src = "a between b is null and c";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "null", 0));
+        let src = "a between not b and c";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "not", 0));
+    }
+
+    #[test]
+    fn test_in() {
+        assert_eq!(parse_expr_sexp("a in (1, 2)"), "(in a (1 2))");
+        assert_eq!(parse_expr_sexp("a not in (b + 1)"), "(not-in a ((+ b 1)))");
+        assert_eq!(
+            parse_expr_sexp("a + 1 in (b) = c"),
+            "(= (in (+ a 1) (b)) c)"
+        );
+        let src = "a in ()";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, ")", 0));
+    }
+
+    #[test]
+    fn test_like() {
+        assert_eq!(parse_expr_sexp("a like 'x%'"), "(like a 'x%')");
+        assert_eq!(parse_expr_sexp("a not ilike b"), "(not-ilike a b)");
+        assert_eq!(
+            parse_expr_sexp("a like 'x!%' escape '!'"),
+            "(like a 'x!%' '!')"
+        );
+        assert_eq!(
+            parse_expr_sexp("a not similar to 'x' escape 'y'"),
+            "(not-similar-to a 'x' 'y')"
+        );
+        assert_eq!(
+            parse_expr_sexp("a || b like c || d"),
+            "(like (|| a b) (|| c d))"
+        );
+        assert_eq!(parse_expr_sexp("a like b = c"), "(= (like a b) c)");
+    }
+
+    #[test]
+    fn test_like_nonassoc() {
+        let src = "a like b like c";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "like", 1));
+    }
+
+    #[test]
+    fn test_at_time_zone() {
+        assert_eq!(
+            parse_expr_sexp("a at time zone 'UTC'"),
+            "(at-time-zone a 'UTC')"
+        );
+        assert_eq!(parse_expr_sexp("a at local"), "(at-local a)");
+        assert_eq!(
+            parse_expr_sexp("a + b at time zone z"),
+            "(+ a (at-time-zone b z))"
+        );
+        assert_eq!(
+            parse_expr_sexp("a at time zone b at time zone c"),
+            "(at-time-zone (at-time-zone a b) c)"
+        );
+    }
+
+    #[test]
+    fn test_collate() {
+        assert_eq!(parse_expr_sexp("a collate \"C\""), "(collate a C)");
+        assert_eq!(
+            parse_expr_sexp("a || b collate pg_catalog.\"default\" = c"),
+            "(= (|| a (collate b pg_catalog.default)) c)"
+        );
+        assert_eq!(parse_expr_sexp("-a collate \"C\""), "(collate (- a) C)");
+        assert_eq!(
+            parse_expr_sexp("a::text collate \"C\" collate \"D\""),
+            "(collate (collate (cast a text) C) D)"
+        );
+        let src = "a collate \"C\" || b";
+        let expr = parse_expr_src(src, &mut CodeDiagnostics::new()).unwrap();
+        let ExprKind::BinaryOp { left, .. } = expr.kind else {
+            panic!("not a binary operation");
+        };
+        assert_eq!(left.range, pos(src, "a collate \"C\"", 0));
+    }
+
+    #[test]
+    fn test_is_normalized() {
+        assert_eq!(
+            parse_expr_sexp("a is normalized"),
+            "(sql-call pg_catalog.is_normalized a)"
+        );
+        assert_eq!(
+            parse_expr_sexp("a is not nfd normalized and b"),
+            "(and (not (sql-call pg_catalog.is_normalized a 'NFD')) b)"
+        );
+        let src = "a is nfc or b";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "or", 0));
+        let src = "a is normalized";
+        let expr = parse_expr_src(src, &mut CodeDiagnostics::new()).unwrap();
+        assert_eq!(expr.range, pos(src, src, 0));
+    }
+
+    #[test]
+    fn test_is_json() {
+        assert_eq!(parse_expr_sexp("a is json"), "(is-json a)");
+        assert_eq!(
+            parse_expr_sexp("a is not json object with unique keys and b"),
+            "(and (is-not-json-object a unique) b)"
+        );
+        assert_eq!(
+            parse_expr_sexp("a is json value without unique"),
+            "(is-json a)"
+        );
+        assert_eq!(parse_expr_sexp("a is json scalar"), "(is-json-scalar a)");
+        let src = "a is json array with unique";
+        let expr = parse_expr_src(src, &mut CodeDiagnostics::new()).unwrap();
+        assert_eq!(expr.range, pos(src, src, 0));
+        let src = "a is json with keys";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "keys", 0));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        assert_eq!(
+            parse_expr_sexp("(a, b) overlaps row(c, d) and e"),
+            "(and (sql-call pg_catalog.overlaps a b c d) e)"
+        );
+        let src = "(a, b, c) overlaps row(d)";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![
+                CodeDiagnostic::WrongOverlapsArity {
+                    range: pos(src, "(a, b, c)", 0),
+                    side: "left",
+                },
+                CodeDiagnostic::WrongOverlapsArity {
+                    range: pos(src, "row(d)", 0),
+                    side: "right",
+                },
+            ]
+        );
+        // An implicit row needs at least two fields.
+        let src = "(a, b) overlaps (c)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, ")", 1));
+    }
+
+    #[test]
+    fn test_parenthesized() {
+        assert_eq!(parse_expr_sexp("(1 + 2) * 3"), "(* (+ 1 2) 3)");
+        assert_eq!(parse_expr_sexp("(a < b) < c"), "(< (< a b) c)");
+    }
+
+    #[test]
+    fn test_array() {
+        assert_eq!(parse_expr_sexp("array[1, a + 2]"), "(array 1 (+ a 2))");
+        assert_eq!(parse_expr_sexp("array[]"), "(array)");
+        assert_eq!(
+            parse_expr_sexp("ARRAY[[1, 2], [3, 4]]"),
+            "(array (array 1 2) (array 3 4))"
+        );
+        assert_eq!(
+            parse_expr_sexp("array(select a from t)"),
+            "(array-subquery (select (targets a) (from t)))"
+        );
+        let src = "array[[1], 2]";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "2", 0));
+        // Subscripts need parentheses.
+        let src = "array[1][1]";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "[", 1));
+    }
+
+    #[test]
+    fn test_row() {
+        assert_eq!(parse_expr_sexp("row(1, a)"), "(row 1 a)");
+        assert_eq!(parse_expr_sexp("row()"), "(row)");
+        assert_eq!(parse_expr_sexp("(1, a + b)"), "(implicit-row 1 (+ a b))");
+        assert_eq!(
+            parse_expr_sexp("(a, b) = (1, 2)"),
+            "(= (implicit-row a b) (implicit-row 1 2))"
+        );
+        // `ROW` is a column name unless followed by `(`.
+        assert_eq!(parse_expr_sexp("row + 1"), "(+ row 1)");
+    }
+
+    #[test]
+    fn test_subscripts() {
+        assert_eq!(parse_expr_sexp("a[1]"), "(indirection a [1])");
+        assert_eq!(
+            parse_expr_sexp("t.a[i + 1][2]"),
+            "(indirection t.a [(+ i 1)] [2])"
+        );
+        assert_eq!(parse_expr_sexp("a[1:2]"), "(indirection a [1:2])");
+        assert_eq!(
+            parse_expr_sexp("a[:2][1:][:]"),
+            "(indirection a [:2] [1:] [:])"
+        );
+        assert_eq!(parse_expr_sexp("$1[1]"), "(indirection $1 [1])");
+        assert_eq!(parse_expr_sexp("-a[1]"), "(- (indirection a [1]))");
+        let src = "a[]";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "]", 0));
+    }
+
+    #[test]
+    fn test_field_selection() {
+        assert_eq!(parse_expr_sexp("(a).b"), "(indirection a .b)");
+        assert_eq!(parse_expr_sexp("(f(x)).*"), "(indirection (call f x) .*)");
+        assert_eq!(parse_expr_sexp("(a).b[1].c"), "(indirection a .b [1] .c)");
+        assert_eq!(parse_expr_sexp("a[1].b.*"), "(indirection a [1] .b .*)");
+        assert_eq!(parse_expr_sexp("$1.f"), "(indirection $1 .f)");
+        let src = "(a).*.b";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, ".", 1));
+    }
+
+    #[test]
+    fn test_indirection_range() {
+        let src = "1 + (a).b[1]";
+        let expr = parse_expr_src(src, &mut CodeDiagnostics::new()).unwrap();
+        let ExprKind::BinaryOp { right, .. } = expr.kind else {
+            panic!("not a binary operation: {expr:?}");
+        };
+        assert_eq!(right.range, pos(src, "(a).b[1]", 0));
+        let ExprKind::Indirection { operand, .. } = right.kind else {
+            panic!("not an indirection: {right:?}");
+        };
+        assert_eq!(operand.range, pos(src, "(a)", 0));
+    }
+
+    #[test]
+    fn test_scalar_subquery() {
+        assert_eq!(
+            parse_expr_sexp("(select 1)"),
+            "(subquery (select (targets 1)))"
+        );
+        assert_eq!(
+            parse_expr_sexp("(select a from t) + 1"),
+            "(+ (subquery (select (targets a) (from t))) 1)"
+        );
+        assert_eq!(
+            parse_expr_sexp("((select 1) union select 2)"),
+            "(subquery (union (select (targets 1)) (select (targets 2))))"
+        );
+        assert_eq!(
+            parse_expr_sexp("(select t)[1].a"),
+            "(indirection (subquery (select (targets t))) [1] .a)"
+        );
+        assert_eq!(
+            parse_expr_sexp("((select 1), 2)"),
+            "(implicit-row (subquery (select (targets 1))) 2)"
+        );
+        let src = "((select 1) + 1 union select 2)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "union", 0));
+    }
+
+    #[test]
+    fn test_scalar_subquery_range() {
+        let src = "1 + ((select 1))";
+        let expr = parse_expr_src(src, &mut CodeDiagnostics::new()).unwrap();
+        let ExprKind::BinaryOp { right, .. } = expr.kind else {
+            panic!("not a binary operation: {expr:?}");
+        };
+        assert_eq!(right.range, pos(src, "((select 1))", 0));
+        let ExprKind::ScalarSubquery { query } = right.kind else {
+            panic!("not a subquery: {right:?}");
+        };
+        assert_eq!(query.range, pos(src, "((select 1))", 0));
+    }
+
+    #[test]
+    fn test_exists() {
+        assert_eq!(
+            parse_expr_sexp("not exists (select from t)"),
+            "(not (exists (select (targets) (from t))))"
+        );
+        // `EXISTS` is a column name unless followed by `(`.
+        assert_eq!(parse_expr_sexp("exists + 1"), "(+ exists 1)");
+        let src = "exists (1)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "1", 0));
+    }
+
+    #[test]
+    fn test_in_subquery() {
+        assert_eq!(
+            parse_expr_sexp("a in (select b from t)"),
+            "(in-subquery a (select (targets b) (from t)))"
+        );
+        assert_eq!(
+            parse_expr_sexp("(a, b) not in (values (1, 2))"),
+            "(not-in-subquery (implicit-row a b) (values (1 2)))"
+        );
+        assert_eq!(
+            parse_expr_sexp("a in ((select 1))"),
+            "(in-subquery a (select (targets 1)))"
+        );
+        assert_eq!(
+            parse_expr_sexp("a in ((select 1), 2)"),
+            "(in a ((subquery (select (targets 1))) 2))"
+        );
+    }
+
+    #[test]
+    fn test_quantified() {
+        assert_eq!(
+            parse_expr_sexp("a = any (select b from t)"),
+            "(any-subquery = a (select (targets b) (from t)))"
+        );
+        assert_eq!(
+            parse_expr_sexp("a < all (array[1, 2])"),
+            "(all < a (array 1 2))"
+        );
+        assert_eq!(parse_expr_sexp("a <> some (b)"), "(any <> a b)");
+        assert_eq!(
+            parse_expr_sexp("a operator(pg_catalog.=) any (b)"),
+            "(any pg_catalog.= a b)"
+        );
+        assert_eq!(parse_expr_sexp("a not like all (b)"), "(all !~~ a b)");
+        assert_eq!(parse_expr_sexp("a ilike any (b)"), "(any ~~* a b)");
+        assert_eq!(parse_expr_sexp("a = any (b) and c"), "(and (any = a b) c)");
+        // A parenthesized subquery is still a subquery.
+        assert_eq!(
+            parse_expr_sexp("a = any ((select b))"),
+            "(any-subquery = a (select (targets b)))"
+        );
+        let src = "a = any (b, c)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, ",", 0));
+        let src = "a similar to any (b)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "any", 0));
+    }
+
+    #[test]
+    fn test_case() {
+        assert_eq!(
+            parse_expr_sexp("case when a then 1 when b then 2 else 3 end"),
+            "(case (when a 1) (when b 2) (else 3))"
+        );
+        assert_eq!(
+            parse_expr_sexp("case a + 1 when 1 then 'x' end"),
+            "(case (+ a 1) (when 1 'x'))"
+        );
+        let src = "case a end";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "end", 0));
+        let src = "case when a then 1";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::UnexpectedEof {
+                range: CodeRange { start: 18, end: 18 },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_ranges() {
+        let src = "(a + b) * -c";
+        let mut diags = CodeDiagnostics::new();
+        let expr = parse_expr_src(src, &mut diags).unwrap();
+        assert_eq!(
+            expr,
+            ExprNode {
+                kind: ExprKind::BinaryOp {
+                    op: OperatorName::from("*"),
+                    left: Box::new(ExprNode {
+                        kind: ExprKind::BinaryOp {
+                            op: OperatorName::from("+"),
+                            left: Box::new(ExprNode {
+                                kind: ExprKind::ColumnRef {
+                                    fields: vec![Symbol::from("a")],
+                                    star: false,
+                                },
+                                range: pos(src, "a", 0),
+                            }),
+                            right: Box::new(ExprNode {
+                                kind: ExprKind::ColumnRef {
+                                    fields: vec![Symbol::from("b")],
+                                    star: false,
+                                },
+                                range: pos(src, "b", 0),
+                            }),
+                        },
+                        range: pos(src, "(a + b)", 0),
+                    }),
+                    right: Box::new(ExprNode {
+                        kind: ExprKind::UnaryOp {
+                            op: OperatorName::from("-"),
+                            operand: Box::new(ExprNode {
+                                kind: ExprKind::ColumnRef {
+                                    fields: vec![Symbol::from("c")],
+                                    star: false,
+                                },
+                                range: pos(src, "c", 0),
+                            }),
+                        },
+                        range: pos(src, "-c", 0),
+                    }),
+                },
+                range: pos(src, src, 0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_postfix_ranges() {
+        let src = "a is not null";
+        let mut diags = CodeDiagnostics::new();
+        let expr = parse_expr_src(src, &mut diags).unwrap();
+        assert_eq!(expr.range, pos(src, src, 0));
+        let src = "a not in (1)";
+        let expr = parse_expr_src(src, &mut diags).unwrap();
+        assert_eq!(expr.range, pos(src, src, 0));
+    }
+
+    #[test]
+    fn test_unexpected_eof() {
+        let src = "1 +";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::UnexpectedEof {
+                range: CodeRange { start: 3, end: 3 },
+            }]
+        );
+    }
+}
@@ -0,0 +1,1085 @@
+// Table expressions: `from_clause` and `table_ref` in gram.y.
+
+use crate::{
+    Symbol,
+    ast::{
+        Alias, ColumnDefNode, ExprNode, JoinKind, JoinQual, RangeFunction, SelectNode,
+        TableRefKind, TableRefNode, TableSample,
+    },
+    diag::CodeDiagnostics,
+    pos::CodeRange,
+    token::{Token, TokenKind},
+};
+
+use super::{
+    ParseResult, Parser, check_qualified_name,
+    json::starts_json_func,
+    select::{continues_select, starts_select},
+};
+
+/// The contents of a parenthesized item in `FROM`.
+enum ParenItem {
+    /// `(SELECT ...)`
+    Query(SelectNode),
+    /// `(a JOIN b ...)`
+    Table(TableRefNode),
+}
+
+impl Parser<'_> {
+    /// Parses an item in a `FROM` clause (`table_ref`), including joins.
+    pub(super) fn parse_table_ref(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TableRefNode> {
+        let (table, tok1) = self.parse_table_primary(tok0, diags)?;
+        self.parse_joins(table, tok1, diags)
+    }
+
+    /// Parses a table reference other than a join.
+    fn parse_table_primary(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TableRefNode> {
+        let start = tok0.range.start;
+        if tok0.kind == TokenKind::LParen {
+            let (item, tok1) = self.parse_paren_item(tok0, diags)?;
+            let (alias, tok2) = self.parse_opt_alias(tok1, diags)?;
+            let kind = match item {
+                ParenItem::Query(query) => TableRefKind::Subquery {
+                    lateral: false,
+                    query: Box::new(query),
+                    alias,
+                },
+                ParenItem::Table(table) => with_alias(table.kind, alias),
+            };
+            let table = TableRefNode {
+                kind,
+                range: CodeRange {
+                    start,
+                    end: self.tokens.prev_end(),
+                },
+            };
+            return Ok((table, tok2));
+        }
+        let (kind, tok1) = match tok0.keyword() {
+            Some(&Symbol::KEYWORD_lateral) => {
+                let tok1 = self.tokens.next_token(diags);
+                if tok1.kind == TokenKind::LParen {
+                    let (query, tok2) = self.parse_select_with_parens(tok1, diags)?;
+                    let (alias, tok3) = self.parse_opt_alias(tok2, diags)?;
+                    let kind = TableRefKind::Subquery {
+                        lateral: true,
+                        query: Box::new(query),
+                        alias,
+                    };
+                    (kind, tok3)
+                } else if tok1.is_keyword(&Symbol::KEYWORD_json_table)
+                    && self.tokens.peek_token(diags).kind == TokenKind::LParen
+                {
+                    self.parse_json_table_ref(true, tok1, diags)?
+                } else {
+                    self.parse_function_table(true, tok1, diags)?
+                }
+            }
+            // `JSON_TABLE` is a column name unless followed by `(`.
+            Some(&Symbol::KEYWORD_json_table)
+                if self.tokens.peek_token(diags).kind == TokenKind::LParen =>
+            {
+                self.parse_json_table_ref(false, tok0, diags)?
+            }
+            Some(&Symbol::KEYWORD_only) => {
+                let ((name, inherit), tok1) = self.parse_relation_expr(tok0, diags)?;
+                self.parse_relation_rest(name, inherit, tok1, diags)?
+            }
+            Some(&Symbol::KEYWORD_rows)
+                if self
+                    .tokens
+                    .peek_token(diags)
+                    .is_keyword(&Symbol::KEYWORD_from) =>
+            {
+                self.parse_function_table(false, tok0, diags)?
+            }
+            _ if self.starts_special_func_windowless(&tok0, diags) => {
+                self.parse_function_table(false, tok0, diags)?
+            }
+            _ => {
+                let quoted = matches!(tok0.kind, TokenKind::Identifier { quoted: true, .. });
+                let (name, name_range, tok1) = self.parse_dotted_name(tok0, diags)?;
+                // Column name keywords like `INT` cannot be unqualified function names.
+                if tok1.kind == TokenKind::LParen
+                    && (quoted || name.len() > 1 || name[0].is_type_function_name())
+                {
+                    let (call, tok2) = self.parse_func_call(name, name_range, tok1, diags)?;
+                    let function = RangeFunction {
+                        call,
+                        column_defs: Vec::new(),
+                    };
+                    self.parse_function_table_rest(false, vec![function], false, tok2, diags)?
+                } else if !(quoted || name[0].is_col_id()) {
+                    // Keywords like `LEFT` can only be used as function names.
+                    self.syntax_error(&tok1, diags);
+                    return Err(tok1);
+                } else {
+                    check_qualified_name(&name, name_range, diags);
+                    // `name *` is an obsolete way to include child tables, which is the default.
+                    let tok2 = if tok1.kind == TokenKind::Asterisk {
+                        self.tokens.next_token(diags)
+                    } else {
+                        tok1
+                    };
+                    self.parse_relation_rest(name, true, tok2, diags)?
+                }
+            }
+        };
+        let table = TableRefNode {
+            kind,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((table, tok1))
+    }
+
+    /// Parses a parenthesized query or joined table, possibly nested in further parentheses.
+    fn parse_paren_item(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ParenItem> {
+        let start = tok0.range.start;
+        let tok1 = self.expect_token(tok0, TokenKind::LParen, diags)?;
+        let (query, tok2) = if starts_select(&tok1) {
+            self.parse_select(tok1, diags)?
+        } else if tok1.kind == TokenKind::LParen {
+            let inner_start = tok1.range.start;
+            let (item, tok2) = self.parse_paren_item(tok1, diags)?;
+            match item {
+                // The query may continue, as in `((SELECT 1) UNION SELECT 2)`.
+                ParenItem::Query(query)
+                    if tok2.kind == TokenKind::RParen || continues_select(&tok2) =>
+                {
+                    self.parse_select_continued(inner_start, None, query, tok2, diags)?
+                }
+                item => return self.parse_paren_table(start, inner_start, item, tok2, diags),
+            }
+        } else {
+            let (table, tok2) = self.parse_table_primary(tok1, diags)?;
+            if !starts_join(&tok2) {
+                self.syntax_error(&tok2, diags);
+                return Err(tok2);
+            }
+            let (table, tok3) = self.parse_joins(table, tok2, diags)?;
+            return self.finish_paren_table(start, table, tok3, diags);
+        };
+        if tok2.kind != TokenKind::RParen {
+            self.syntax_error(&tok2, diags);
+            return Err(tok2);
+        }
+        // Parentheses only affect the range.
+        let query = SelectNode {
+            range: CodeRange {
+                start,
+                end: tok2.range.end,
+            },
+            ..query
+        };
+        Ok((ParenItem::Query(query), self.tokens.next_token(diags)))
+    }
+
+    /// Parses the rest of a parenthesized joined table starting with a nested parenthesized item,
+    /// as in `((a JOIN b ON true) AS j JOIN c ON true)`.
+    fn parse_paren_table(
+        &mut self,
+        start: usize,
+        inner_start: usize,
+        item: ParenItem,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ParenItem> {
+        let (kind, tok1) = match item {
+            ParenItem::Query(query) => {
+                let (alias, tok1) = self.parse_opt_alias(tok0, diags)?;
+                let kind = TableRefKind::Subquery {
+                    lateral: false,
+                    query: Box::new(query),
+                    alias,
+                };
+                (kind, tok1)
+            }
+            ParenItem::Table(table) => {
+                let (alias, tok1) = self.parse_opt_alias(tok0, diags)?;
+                (with_alias(table.kind, alias), tok1)
+            }
+        };
+        // Only a joined table can be parenthesized, so the item must be joined with another
+        // unless it is the joined table itself, as in `((a JOIN b ON true))`.
+        let is_bare_join = matches!(kind, TableRefKind::Join { alias: None, .. });
+        if !is_bare_join && !starts_join(&tok1) {
+            self.syntax_error(&tok1, diags);
+            return Err(tok1);
+        }
+        let table = TableRefNode {
+            kind,
+            range: CodeRange {
+                start: inner_start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        let (table, tok2) = self.parse_joins(table, tok1, diags)?;
+        self.finish_paren_table(start, table, tok2, diags)
+    }
+
+    /// Checks `)` after a parenthesized joined table.
+    fn finish_paren_table(
+        &mut self,
+        start: usize,
+        table: TableRefNode,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ParenItem> {
+        if tok0.kind != TokenKind::RParen {
+            self.syntax_error(&tok0, diags);
+            return Err(tok0);
+        }
+        let table = TableRefNode {
+            kind: table.kind,
+            range: CodeRange {
+                start,
+                end: tok0.range.end,
+            },
+        };
+        Ok((ParenItem::Table(table), self.tokens.next_token(diags)))
+    }
+
+    /// Parses joins following a table reference, which associate to the left.
+    fn parse_joins(
+        &mut self,
+        mut left: TableRefNode,
+        mut tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TableRefNode> {
+        loop {
+            let (kind, natural, tok1) = match tok0.keyword() {
+                Some(&Symbol::KEYWORD_cross) => {
+                    let tok1 = self.tokens.next_token(diags);
+                    let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_join, diags)?;
+                    (None, false, tok2)
+                }
+                Some(&Symbol::KEYWORD_natural) => {
+                    let tok1 = self.tokens.next_token(diags);
+                    let (kind, tok2) = self.parse_join_type(tok1, diags)?;
+                    let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_join, diags)?;
+                    (Some(kind), true, tok3)
+                }
+                Some(
+                    &(Symbol::KEYWORD_join
+                    | Symbol::KEYWORD_inner
+                    | Symbol::KEYWORD_left
+                    | Symbol::KEYWORD_right
+                    | Symbol::KEYWORD_full),
+                ) => {
+                    let (kind, tok1) = self.parse_join_type(tok0, diags)?;
+                    let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_join, diags)?;
+                    (Some(kind), false, tok2)
+                }
+                _ => return Ok((left, tok0)),
+            };
+            let (kind, right, qual, tok2) = match kind {
+                // `CROSS JOIN` and `NATURAL JOIN` bind tighter than qualified joins.
+                Some(kind) if !natural => {
+                    let (right, tok2) = self.parse_table_ref(tok1, diags)?;
+                    let (qual, tok3) = self.parse_join_qual(tok2, diags)?;
+                    (kind, right, Some(qual), tok3)
+                }
+                _ => {
+                    let (right, tok2) = self.parse_table_primary(tok1, diags)?;
+                    (kind.unwrap_or(JoinKind::Inner), right, None, tok2)
+                }
+            };
+            let range = CodeRange {
+                start: left.range.start,
+                end: self.tokens.prev_end(),
+            };
+            left = TableRefNode {
+                kind: TableRefKind::Join {
+                    kind,
+                    natural,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    qual,
+                    alias: None,
+                },
+                range,
+            };
+            tok0 = tok2;
+        }
+    }
+
+    /// Parses `[INNER | {LEFT | RIGHT | FULL} [OUTER]]` before `JOIN`.
+    fn parse_join_type(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<JoinKind> {
+        let kind = match tok0.keyword() {
+            Some(&Symbol::KEYWORD_inner) => {
+                return Ok((JoinKind::Inner, self.tokens.next_token(diags)));
+            }
+            Some(&Symbol::KEYWORD_left) => JoinKind::Left,
+            Some(&Symbol::KEYWORD_right) => JoinKind::Right,
+            Some(&Symbol::KEYWORD_full) => JoinKind::Full,
+            _ => return Ok((JoinKind::Inner, tok0)),
+        };
+        let tok1 = self.tokens.next_token(diags);
+        if tok1.is_keyword(&Symbol::KEYWORD_outer) {
+            return Ok((kind, self.tokens.next_token(diags)));
+        }
+        Ok((kind, tok1))
+    }
+
+    /// Parses `ON expr` or `USING (columns) [AS alias]` (`join_qual`).
+    fn parse_join_qual(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<JoinQual> {
+        match tok0.keyword() {
+            Some(&Symbol::KEYWORD_on) => {
+                let tok1 = self.tokens.next_token(diags);
+                let (expr, tok2) = self.parse_expr(tok1, diags)?;
+                Ok((JoinQual::On { expr }, tok2))
+            }
+            Some(&Symbol::KEYWORD_using) => {
+                let tok1 = self.tokens.next_token(diags);
+                let (columns, tok2) = self.parse_name_list_with_parens(tok1, diags)?;
+                if !tok2.is_keyword(&Symbol::KEYWORD_as) {
+                    return Ok((
+                        JoinQual::Using {
+                            columns,
+                            alias: None,
+                        },
+                        tok2,
+                    ));
+                }
+                let tok3 = self.tokens.next_token(diags);
+                let (alias, tok4) = self.parse_col_id(tok3, diags)?;
+                let qual = JoinQual::Using {
+                    columns,
+                    alias: Some(alias),
+                };
+                Ok((qual, tok4))
+            }
+            _ => {
+                self.syntax_error(&tok0, diags);
+                Err(tok0)
+            }
+        }
+    }
+
+    /// Parses a table name with `ONLY` or `*` (`relation_expr`).
+    pub(super) fn parse_relation_expr(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<(Vec<Symbol>, bool)> {
+        if !tok0.is_keyword(&Symbol::KEYWORD_only) {
+            let (name, tok1) = self.parse_qualified_name(tok0, diags)?;
+            // `name *` is an obsolete way to include child tables, which is the default.
+            if tok1.kind == TokenKind::Asterisk {
+                return Ok(((name, true), self.tokens.next_token(diags)));
+            }
+            return Ok(((name, true), tok1));
+        }
+        let tok1 = self.tokens.next_token(diags);
+        if tok1.kind == TokenKind::LParen {
+            let tok2 = self.tokens.next_token(diags);
+            let (name, tok3) = self.parse_qualified_name(tok2, diags)?;
+            let tok4 = self.expect_token(tok3, TokenKind::RParen, diags)?;
+            return Ok(((name, false), tok4));
+        }
+        let (name, tok2) = self.parse_qualified_name(tok1, diags)?;
+        Ok(((name, false), tok2))
+    }
+
+    /// Parses the remainder of a table name: `[alias] [TABLESAMPLE ...]`.
+    fn parse_relation_rest(
+        &mut self,
+        name: Vec<Symbol>,
+        inherit: bool,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TableRefKind> {
+        let (alias, tok1) = self.parse_opt_alias(tok0, diags)?;
+        let (tablesample, tok2) = if tok1.is_keyword(&Symbol::KEYWORD_tablesample) {
+            let (tablesample, tok2) = self.parse_tablesample(tok1, diags)?;
+            (Some(tablesample), tok2)
+        } else {
+            (None, tok1)
+        };
+        let kind = TableRefKind::Relation {
+            name,
+            inherit,
+            alias,
+            tablesample,
+        };
+        Ok((kind, tok2))
+    }
+
+    /// Parses `TABLESAMPLE method (args) [REPEATABLE (seed)]`.
+    fn parse_tablesample(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TableSample> {
+        let start = tok0.range.start;
+        let tok1 = self.expect_keyword(tok0, Symbol::KEYWORD_tablesample, diags)?;
+        let (method, tok2) = self.parse_func_name(tok1, diags)?;
+        let (args, tok3) = self.parse_parenthesized_expr_list(tok2, diags)?;
+        let (repeatable, tok4) = if tok3.is_keyword(&Symbol::KEYWORD_repeatable) {
+            let tok4 = self.tokens.next_token(diags);
+            let tok5 = self.expect_token(tok4, TokenKind::LParen, diags)?;
+            let (seed, tok6) = self.parse_expr(tok5, diags)?;
+            let tok7 = self.expect_token(tok6, TokenKind::RParen, diags)?;
+            (Some(seed), tok7)
+        } else {
+            (None, tok3)
+        };
+        let tablesample = TableSample {
+            method,
+            args,
+            repeatable,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((tablesample, tok4))
+    }
+
+    /// Parses a function call or `ROWS FROM (...)` in `FROM`, followed by
+    /// `[WITH ORDINALITY] [alias]`.
+    fn parse_function_table(
+        &mut self,
+        lateral: bool,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TableRefKind> {
+        if tok0.is_keyword(&Symbol::KEYWORD_rows)
+            && self
+                .tokens
+                .peek_token(diags)
+                .is_keyword(&Symbol::KEYWORD_from)
+        {
+            self.tokens.next_token(diags);
+            let tok1 = self.tokens.next_token(diags);
+            let tok2 = self.expect_token(tok1, TokenKind::LParen, diags)?;
+            let (functions, tok3) =
+                self.parse_comma_list(tok2, diags, Self::parse_rowsfrom_item)?;
+            let tok4 = self.expect_token(tok3, TokenKind::RParen, diags)?;
+            return self.parse_function_table_rest(lateral, functions, true, tok4, diags);
+        }
+        let (call, tok1) = self.parse_func_expr_windowless(tok0, diags)?;
+        let function = RangeFunction {
+            call,
+            column_defs: Vec::new(),
+        };
+        self.parse_function_table_rest(lateral, vec![function], false, tok1, diags)
+    }
+
+    /// Parses a function call in `ROWS FROM` with optional `AS (column definitions)`
+    /// (`rowsfrom_item`).
+    fn parse_rowsfrom_item(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<RangeFunction> {
+        let (call, tok1) = self.parse_func_expr_windowless(tok0, diags)?;
+        let (column_defs, tok2) = if tok1.is_keyword(&Symbol::KEYWORD_as) {
+            let tok2 = self.tokens.next_token(diags);
+            self.parse_column_defs(tok2, diags)?
+        } else {
+            (Vec::new(), tok1)
+        };
+        Ok((RangeFunction { call, column_defs }, tok2))
+    }
+
+    /// Parses `JSON_TABLE(...) [alias]`.
+    fn parse_json_table_ref(
+        &mut self,
+        lateral: bool,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TableRefKind> {
+        let (table, tok1) = self.parse_json_table(tok0, diags)?;
+        let (alias, tok2) = self.parse_opt_alias(tok1, diags)?;
+        let kind = TableRefKind::JsonTable {
+            lateral,
+            table: Box::new(table),
+            alias,
+        };
+        Ok((kind, tok2))
+    }
+
+    /// Parses `[WITH ORDINALITY] [alias]` after a function table.
+    fn parse_function_table_rest(
+        &mut self,
+        lateral: bool,
+        functions: Vec<RangeFunction>,
+        rows_from: bool,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TableRefKind> {
+        let (ordinality, tok1) = if tok0.kind == TokenKind::WithLa {
+            let tok1 = self.tokens.next_token(diags);
+            let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_ordinality, diags)?;
+            (true, tok2)
+        } else {
+            (false, tok0)
+        };
+        let ((alias, column_defs), tok2) = self.parse_opt_func_alias(tok1, diags)?;
+        let kind = TableRefKind::Function {
+            lateral,
+            functions,
+            rows_from,
+            ordinality,
+            alias,
+            column_defs,
+        };
+        Ok((kind, tok2))
+    }
+
+    /// Parses an alias after a function table, which may define the columns of
+    /// a function returning `record` (`func_alias_clause`):
+    /// `[AS] alias [(columns)]`, `[AS] alias (column definitions)`, or `AS (column definitions)`.
+    fn parse_opt_func_alias(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<(Option<Alias>, Vec<ColumnDefNode>)> {
+        if tok0.is_keyword(&Symbol::KEYWORD_as)
+            && self.tokens.peek_token(diags).kind == TokenKind::LParen
+        {
+            let tok1 = self.tokens.next_token(diags);
+            let (column_defs, tok2) = self.parse_column_defs(tok1, diags)?;
+            return Ok(((None, column_defs), tok2));
+        }
+        let (name, tok1) = match self.parse_opt_alias_name(tok0, diags)? {
+            (Some(name), tok1) => (name, tok1),
+            (None, tok1) => return Ok(((None, Vec::new()), tok1)),
+        };
+        if tok1.kind != TokenKind::LParen {
+            let alias = Alias {
+                name,
+                columns: Vec::new(),
+            };
+            return Ok(((Some(alias), Vec::new()), tok1));
+        }
+        let tok2 = self.tokens.next_token(diags);
+        let first_start = tok2.range.start;
+        let (first, mut tok3) = self.parse_col_id(tok2, diags)?;
+        // The first item tells column names from column definitions.
+        if matches!(tok3.kind, TokenKind::Comma | TokenKind::RParen) {
+            let mut columns = vec![first];
+            while tok3.kind == TokenKind::Comma {
+                let tok4 = self.tokens.next_token(diags);
+                let (column, tok5) = self.parse_col_id(tok4, diags)?;
+                columns.push(column);
+                tok3 = tok5;
+            }
+            let tok4 = self.expect_token(tok3, TokenKind::RParen, diags)?;
+            return Ok(((Some(Alias { name, columns }), Vec::new()), tok4));
+        }
+        let (column_def, mut tok4) = self.parse_column_def_rest(first, first_start, tok3, diags)?;
+        let mut column_defs = vec![column_def];
+        while tok4.kind == TokenKind::Comma {
+            let tok5 = self.tokens.next_token(diags);
+            let (column_def, tok6) = self.parse_column_def(tok5, diags)?;
+            column_defs.push(column_def);
+            tok4 = tok6;
+        }
+        let tok5 = self.expect_token(tok4, TokenKind::RParen, diags)?;
+        let alias = Alias {
+            name,
+            columns: Vec::new(),
+        };
+        Ok(((Some(alias), column_defs), tok5))
+    }
+
+    /// Parses `(name type, ...)` (`TableFuncElementList` in parentheses).
+    fn parse_column_defs(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<ColumnDefNode>> {
+        let tok1 = self.expect_token(tok0, TokenKind::LParen, diags)?;
+        let (column_defs, tok2) = self.parse_comma_list(tok1, diags, Self::parse_column_def)?;
+        let tok3 = self.expect_token(tok2, TokenKind::RParen, diags)?;
+        Ok((column_defs, tok3))
+    }
+
+    /// Parses `name type [COLLATE collation]` (`TableFuncElement`).
+    fn parse_column_def(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ColumnDefNode> {
+        let start = tok0.range.start;
+        let (name, tok1) = self.parse_col_id(tok0, diags)?;
+        self.parse_column_def_rest(name, start, tok1, diags)
+    }
+
+    /// Parses the rest of a column definition after its name.
+    fn parse_column_def_rest(
+        &mut self,
+        name: Symbol,
+        start: usize,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ColumnDefNode> {
+        let (type_name, tok1) = self.parse_type_name(tok0, diags)?;
+        let (collation, tok2) = if tok1.is_keyword(&Symbol::KEYWORD_collate) {
+            let tok2 = self.tokens.next_token(diags);
+            self.parse_any_name(tok2, diags)?
+        } else {
+            (Vec::new(), tok1)
+        };
+        let column_def = ColumnDefNode {
+            name,
+            type_name,
+            collation,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((column_def, tok2))
+    }
+
+    /// Whether the token starts a function with a special syntax in `func_expr_windowless`,
+    /// such as `CAST(a AS t)`, `COALESCE(a, b)`, or `CURRENT_DATE`.
+    fn starts_special_func_windowless(&mut self, tok: &Token, diags: &mut CodeDiagnostics) -> bool {
+        tok.is_keyword(&Symbol::KEYWORD_cast)
+            || self.starts_func_expr_common_subexpr(tok, diags)
+            || (starts_json_func(tok) && self.tokens.peek_token(diags).kind == TokenKind::LParen)
+    }
+
+    /// Parses a function call such as `f(1)` or `COALESCE(a, b)` without `OVER` and the like
+    /// (`func_expr_windowless`).
+    pub(super) fn parse_func_expr_windowless(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        if tok0.is_keyword(&Symbol::KEYWORD_cast) {
+            return self.parse_cast(tok0, diags);
+        }
+        if self.starts_func_expr_common_subexpr(&tok0, diags) {
+            return self.parse_func_expr_common_subexpr(tok0, diags);
+        }
+        if starts_json_func(&tok0) && self.tokens.peek_token(diags).kind == TokenKind::LParen {
+            return self.parse_json_func(tok0, diags);
+        }
+        let start = tok0.range.start;
+        let (name, tok1) = self.parse_func_name(tok0, diags)?;
+        let name_range = CodeRange {
+            start,
+            end: self.tokens.prev_end(),
+        };
+        self.parse_func_call(name, name_range, tok1, diags)
+    }
+
+    /// Parses a function name (`func_name`): a `type_function_name` or a qualified name.
+    fn parse_func_name(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<Symbol>> {
+        let quoted = matches!(tok0.kind, TokenKind::Identifier { quoted: true, .. });
+        let (name, range, tok1) = self.parse_dotted_name(tok0, diags)?;
+        let valid = if name.len() == 1 {
+            quoted || name[0].is_type_function_name()
+        } else {
+            quoted || name[0].is_col_id()
+        };
+        if !valid {
+            self.syntax_error(&tok1, diags);
+            return Err(tok1);
+        }
+        check_qualified_name(&name, range, diags);
+        Ok((name, tok1))
+    }
+
+    /// Parses a name followed by any number of `.attr`, leaving the validation of the first
+    /// part to the caller except that it cannot be a reserved keyword.
+    fn parse_dotted_name(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> Result<(Vec<Symbol>, CodeRange, Token), Token> {
+        let start = tok0.range.start;
+        let name = match tok0.kind {
+            TokenKind::Identifier { name, quoted }
+                if quoted || name.is_col_id() || name.is_type_function_name() =>
+            {
+                name
+            }
+            _ => {
+                self.syntax_error(&tok0, diags);
+                return Err(tok0);
+            }
+        };
+        let mut names = vec![name];
+        let mut tok1 = self.tokens.next_token(diags);
+        while tok1.kind == TokenKind::Dot {
+            let tok2 = self.tokens.next_token(diags);
+            let (name, tok3) = self.parse_col_label(tok2, diags)?;
+            names.push(name);
+            tok1 = tok3;
+        }
+        let range = CodeRange {
+            start,
+            end: self.tokens.prev_end(),
+        };
+        Ok((names, range, tok1))
+    }
+
+    /// Parses `[AS] alias [(columns)]`, if any (`opt_alias_clause`).
+    fn parse_opt_alias(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Option<Alias>> {
+        let (name, tok1) = match self.parse_opt_alias_name(tok0, diags)? {
+            (Some(name), tok1) => (name, tok1),
+            (None, tok1) => return Ok((None, tok1)),
+        };
+        let (columns, tok2) = if tok1.kind == TokenKind::LParen {
+            self.parse_name_list_with_parens(tok1, diags)?
+        } else {
+            (Vec::new(), tok1)
+        };
+        Ok((Some(Alias { name, columns }), tok2))
+    }
+
+    /// Parses `[AS] alias` without the column names, if any.
+    fn parse_opt_alias_name(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Option<Symbol>> {
+        match &tok0.kind {
+            TokenKind::Identifier {
+                name: Symbol::KEYWORD_as,
+                quoted: false,
+            } => {
+                let tok1 = self.tokens.next_token(diags);
+                let (name, tok2) = self.parse_col_id(tok1, diags)?;
+                Ok((Some(name), tok2))
+            }
+            TokenKind::Identifier { name, quoted } if *quoted || name.is_col_id() => {
+                Ok((Some(name.clone()), self.tokens.next_token(diags)))
+            }
+            _ => Ok((None, tok0)),
+        }
+    }
+
+    /// Parses `(name, ...)`.
+    pub(super) fn parse_name_list_with_parens(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<Symbol>> {
+        let tok1 = self.expect_token(tok0, TokenKind::LParen, diags)?;
+        let (names, tok2) = self.parse_comma_list(tok1, diags, Self::parse_col_id)?;
+        let tok3 = self.expect_token(tok2, TokenKind::RParen, diags)?;
+        Ok((names, tok3))
+    }
+}
+
+/// Whether the token begins a join after a table reference.
+fn starts_join(tok: &Token) -> bool {
+    matches!(
+        tok.keyword(),
+        Some(
+            &(Symbol::KEYWORD_cross
+                | Symbol::KEYWORD_natural
+                | Symbol::KEYWORD_join
+                | Symbol::KEYWORD_inner
+                | Symbol::KEYWORD_left
+                | Symbol::KEYWORD_right
+                | Symbol::KEYWORD_full)
+        )
+    )
+}
+
+/// Sets the alias of a parenthesized joined table.
+fn with_alias(kind: TableRefKind, alias: Option<Alias>) -> TableRefKind {
+    match (kind, alias) {
+        (
+            TableRefKind::Join {
+                kind,
+                natural,
+                left,
+                right,
+                qual,
+                ..
+            },
+            Some(alias),
+        ) => TableRefKind::Join {
+            kind,
+            natural,
+            left,
+            right,
+            qual,
+            alias: Some(alias),
+        },
+        (kind, _) => kind,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{SelectBody, StmtKind},
+        diag::CodeDiagnostic,
+        parser::{
+            parse_stmt,
+            sexp::{parse_stmt_errors, syntax_error, table_ref_sexp, unexpected_eof},
+        },
+        pos::pos,
+    };
+
+    use super::*;
+
+    /// Renders the items of the `FROM` clause in `SELECT FROM {from}`.
+    fn sexp(from: &str) -> String {
+        let src = format!("select from {from}");
+        let stmt = parse_stmt(&src).unwrap();
+        let StmtKind::Select(select) = stmt.kind else {
+            panic!("not a select statement: {src}");
+        };
+        let SelectBody::Simple(simple) = select.body else {
+            panic!("not a simple select: {src}");
+        };
+        let items = simple.from.iter().map(table_ref_sexp).collect::<Vec<_>>();
+        items.join(", ")
+    }
+
+    #[test]
+    fn test_relation() {
+        assert_eq!(sexp("t * as u (a, b)"), "(as t (u a b))");
+        assert_eq!(sexp("only s.t"), "(only s.t)");
+        assert_eq!(sexp("only (t) u"), "(as (only t) u)");
+        // `ROWS` is a table name unless followed by `FROM`.
+        assert_eq!(sexp("rows"), "rows");
+    }
+
+    #[test]
+    fn test_tablesample() {
+        assert_eq!(
+            sexp("t u tablesample system (10) repeatable (42)"),
+            "(as (tablesample t (system 10) (repeatable 42)) u)"
+        );
+        assert_eq!(
+            sexp("t tablesample s.bernoulli (1, 2)"),
+            "(tablesample t (s.bernoulli 1 2))"
+        );
+    }
+
+    #[test]
+    fn test_joins() {
+        assert_eq!(
+            sexp("a join b on a.x = b.x left outer join c using (x, y) as j"),
+            "(left-join (join a b (on (= a.x b.x))) c (using (x y) j))"
+        );
+        assert_eq!(
+            sexp("a inner join b on true right join c on false full join d on true"),
+            "(full-join (right-join (join a b (on true)) c (on false)) d (on true))"
+        );
+        assert_eq!(
+            sexp("a cross join b natural left join c, d"),
+            "(natural-left-join (join a b) c), d"
+        );
+    }
+
+    #[test]
+    fn test_join_nesting() {
+        // The right side of a qualified join extends as far as possible.
+        assert_eq!(
+            sexp("a join b cross join c on true"),
+            "(join a (join b c) (on true))"
+        );
+        assert_eq!(
+            sexp("a join b join c on true on false"),
+            "(join a (join b c (on true)) (on false))"
+        );
+        let src = "select from a join b join c on true";
+        assert_eq!(parse_stmt_errors(src), unexpected_eof(src));
+    }
+
+    #[test]
+    fn test_parenthesized_join() {
+        assert_eq!(
+            sexp("(a join b using (x)) as j (p, q)"),
+            "(as (join a b (using (x))) (j p q))"
+        );
+        assert_eq!(
+            sexp("((a natural join b)) join c on true"),
+            "(join (natural-join a b) c (on true))"
+        );
+        assert_eq!(
+            sexp("((a cross join b) j cross join c)"),
+            "(join (as (join a b) j) c)"
+        );
+        assert_eq!(
+            sexp("((select 1) s join t on true)"),
+            "(join (as (select (targets 1)) s) t (on true))"
+        );
+        // Only joined tables can be parenthesized.
+        let src = "select from (t)";
+        assert_eq!(parse_stmt_errors(src), syntax_error(src, ")", 0));
+        let src = "select from ((a join b on true) j)";
+        assert_eq!(parse_stmt_errors(src), syntax_error(src, ")", 1));
+    }
+
+    #[test]
+    fn test_join_range() {
+        let src = "select from (a join b on true) j, c";
+        let StmtKind::Select(select) = parse_stmt(src).unwrap().kind else {
+            unreachable!();
+        };
+        let SelectBody::Simple(simple) = select.body else {
+            panic!("not a simple select: {src}");
+        };
+        assert_eq!(simple.from[0].range, pos(src, "(a join b on true) j", 0));
+        let TableRefKind::Join { left, right, .. } = &simple.from[0].kind else {
+            panic!("not a join");
+        };
+        assert_eq!(left.range, pos(src, "a", 0));
+        assert_eq!(right.range, pos(src, "b", 0));
+    }
+
+    #[test]
+    fn test_subquery() {
+        assert_eq!(sexp("(select 1) s"), "(as (select (targets 1)) s)");
+        assert_eq!(sexp("((select 1))"), "(select (targets 1))");
+        assert_eq!(
+            sexp("((select 1) union select 2) u"),
+            "(as (union (select (targets 1)) (select (targets 2))) u)"
+        );
+        assert_eq!(
+            sexp("t, lateral (select t.a) as s (b)"),
+            "t, (as (lateral (select (targets t.a))) (s b))"
+        );
+        // Only a subquery or a function can follow LATERAL.
+        let src = "select from lateral t";
+        assert_eq!(parse_stmt_errors(src), unexpected_eof(src));
+    }
+
+    #[test]
+    fn test_function() {
+        assert_eq!(
+            sexp("generate_series(1, 3) with ordinality as g (n, i)"),
+            "(as (with-ordinality (call generate_series 1 3)) (g n i))"
+        );
+        assert_eq!(sexp("s.f() f"), "(as (call s.f) f)");
+        assert_eq!(sexp("t, lateral f(t.a)"), "t, (lateral (call f t.a))");
+        assert_eq!(
+            sexp("rows from (f(1), g()) with ordinality"),
+            "(with-ordinality (rows-from (call f 1) (call g)))"
+        );
+        assert_eq!(
+            sexp("lateral rows from (f(1)) r"),
+            "(as (lateral (rows-from (call f 1))) r)"
+        );
+        // Column name keywords cannot be unqualified function names.
+        let src = "select from int(1)";
+        assert_eq!(parse_stmt_errors(src), syntax_error(src, "(", 0));
+        // Function name keywords cannot be table names.
+        let src = "select from left";
+        assert_eq!(parse_stmt_errors(src), unexpected_eof(src));
+    }
+
+    #[test]
+    fn test_special_function() {
+        assert_eq!(sexp("current_date"), "current_date");
+        assert_eq!(
+            sexp("coalesce(1, 2) with ordinality c"),
+            "(as (with-ordinality (coalesce 1 2)) c)"
+        );
+        assert_eq!(
+            sexp("t, lateral cast(t.a as int)"),
+            "t, (lateral (cast t.a pg_catalog.int4))"
+        );
+        assert_eq!(
+            sexp("rows from (current_user, json_scalar(1))"),
+            "(rows-from (sql-call pg_catalog.current_user) (json-scalar 1))"
+        );
+        // Keywords like `COALESCE` are table names unless followed by `(`.
+        assert_eq!(sexp("coalesce c"), "(as coalesce c)");
+    }
+
+    #[test]
+    fn test_function_column_defs() {
+        assert_eq!(
+            sexp("json_to_record(j) as x (a int, b text collate \"C\")"),
+            "(column-defs (as (call json_to_record j) x) (a pg_catalog.int4) \
+             (b text (collate C)))"
+        );
+        assert_eq!(sexp("f() x (a, b)"), "(as (call f) (x a b))");
+        assert_eq!(
+            sexp("json_to_record(j) as (a int)"),
+            "(column-defs (call json_to_record j) (a pg_catalog.int4))"
+        );
+        assert_eq!(
+            sexp("rows from (json_to_record(j) as (a int), f()) with ordinality r"),
+            "(as (with-ordinality (rows-from (column-defs (call json_to_record j) \
+             (a pg_catalog.int4)) (call f))) r)"
+        );
+        // Column names and definitions cannot be mixed.
+        let src = "select from f() x (a, b int)";
+        assert_eq!(parse_stmt_errors(src), syntax_error(src, "int", 0));
+        let src = "select from f() x (a int, b)";
+        assert_eq!(parse_stmt_errors(src), syntax_error(src, ")", 1));
+        // Only function tables can have column definitions.
+        let src = "select from t as (a int)";
+        assert_eq!(parse_stmt_errors(src), syntax_error(src, "(", 0));
+    }
+
+    #[test]
+    fn test_json_table() {
+        assert_eq!(
+            sexp("json_table(a, '$[*]' columns (id for ordinality, x int path '$.x')) j"),
+            "(as (json-table a '$[*]' (columns (id for-ordinality) (x pg_catalog.int4 \
+             (path '$.x')))) j)"
+        );
+        assert_eq!(
+            sexp(
+                "t, lateral json_table(t.doc format json, '$' as root passing 1 as n \
+                 columns (e bool exists path '$.e' false on error, \
+                 v jsonb format json with wrapper omit quotes null on empty error on error, \
+                 nested path '$.items[*]' as items columns (nested text)) \
+                 empty array on error)"
+            ),
+            "t, (lateral (json-table (format-json t.doc) ('$' root) (passing (1 n)) \
+             (columns (e bool exists (path '$.e') (on-error false)) \
+             (v jsonb format-json with-unconditional-wrapper omit-quotes (on-empty null) \
+             (on-error error)) (nested ('$.items[*]' items) (columns (nested text)))) \
+             (on-error empty-array)))"
+        );
+        // `JSON_TABLE` is a column name unless followed by `(`.
+        assert_eq!(sexp("json_table"), "json_table");
+        let src = "select from json_table(a, b columns (x int))";
+        assert_eq!(
+            parse_stmt_errors(src),
+            vec![CodeDiagnostic::JsonTablePathNotString {
+                range: pos(src, "b", 1),
+            }]
+        );
+        let src = "select from json_table(a, '$' columns (x int) null on empty)";
+        assert_eq!(parse_stmt_errors(src), syntax_error(src, "empty", 0));
+    }
+}
@@ -0,0 +1,1277 @@
+// Function calls and windows: `func_application`, `func_expr`, `func_expr_common_subexpr`,
+// and `window_specification` in gram.y.
+
+use crate::{
+    Symbol,
+    ast::{
+        ExprKind, ExprNode, FrameBound, FrameExclusion, FrameMode, FuncCall, SqlValueFunctionKind,
+        WindowDefNode, WindowFrame,
+    },
+    diag::{CodeDiagnostic, CodeDiagnostics},
+    pos::CodeRange,
+    token::{Token, TokenKind},
+};
+
+use super::{
+    ParseResult, Parser, join,
+    types::{int_const, is_sconst},
+};
+
+impl Parser<'_> {
+    /// Parses a function call after its name, including `WITHIN GROUP`, `FILTER`, and `OVER`
+    /// (`func_expr`).
+    pub(super) fn parse_func_expr(
+        &mut self,
+        name: Vec<Symbol>,
+        name_range: CodeRange,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let (mut call, mut tok1) = self.parse_func_application(name, tok0, diags)?;
+        // `name(modifiers) 'literal'` is a typed literal.
+        if is_sconst(&tok1) && !(call.star || call.distinct || call.variadic) {
+            return self.parse_func_type_const(call, name_range, tok1, diags);
+        }
+        if tok1.is_keyword(&Symbol::KEYWORD_within) {
+            let start = tok1.range.start;
+            let tok2 = self.tokens.next_token(diags);
+            let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_group, diags)?;
+            let tok4 = self.expect_token(tok3, TokenKind::LParen, diags)?;
+            let tok5 = self.expect_keyword(tok4, Symbol::KEYWORD_order, diags)?;
+            let (order_by, tok6) = self.parse_sort_clause(tok5, diags)?;
+            let tok7 = self.expect_token(tok6, TokenKind::RParen, diags)?;
+            let range = CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            };
+            if !call.order_by.is_empty() {
+                diags.add(CodeDiagnostic::MultipleOrderByWithWithinGroup { range });
+            }
+            if call.distinct {
+                diags.add(CodeDiagnostic::DistinctWithWithinGroup { range });
+            }
+            if call.variadic {
+                diags.add(CodeDiagnostic::VariadicWithWithinGroup { range });
+            }
+            call.order_by = order_by;
+            call.within_group = true;
+            tok1 = tok7;
+        }
+        let ((filter, over), tok2) = self.parse_opt_filter_over(tok1, diags)?;
+        call.filter = filter;
+        call.over = over;
+        let expr = ExprNode {
+            kind: ExprKind::FuncCall(Box::new(call)),
+            range: CodeRange {
+                start: name_range.start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((expr, tok2))
+    }
+
+    /// Parses `FILTER (WHERE ...)` and `OVER ...` after an aggregate call, if any.
+    pub(super) fn parse_opt_filter_over(
+        &mut self,
+        mut tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<(Option<ExprNode>, Option<WindowDefNode>)> {
+        let mut filter = None;
+        if tok0.is_keyword(&Symbol::KEYWORD_filter) {
+            let tok1 = self.tokens.next_token(diags);
+            let tok2 = self.expect_token(tok1, TokenKind::LParen, diags)?;
+            let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_where, diags)?;
+            let (expr, tok4) = self.parse_expr(tok3, diags)?;
+            filter = Some(expr);
+            tok0 = self.expect_token(tok4, TokenKind::RParen, diags)?;
+        }
+        let mut over = None;
+        if tok0.is_keyword(&Symbol::KEYWORD_over) {
+            let tok1 = self.tokens.next_token(diags);
+            let (window, tok2) = if tok1.kind == TokenKind::LParen {
+                self.parse_window_specification(tok1, diags)?
+            } else {
+                let range = tok1.range;
+                let (name, tok2) = self.parse_col_id(tok1, diags)?;
+                (named_window(name, range), tok2)
+            };
+            over = Some(window);
+            tok0 = tok2;
+        }
+        Ok(((filter, over), tok0))
+    }
+
+    /// Parses a function call after its name, without `WITHIN GROUP`, `FILTER`, or `OVER`
+    /// (`func_application`).
+    pub(super) fn parse_func_call(
+        &mut self,
+        name: Vec<Symbol>,
+        name_range: CodeRange,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let (call, tok1) = self.parse_func_application(name, tok0, diags)?;
+        let expr = ExprNode {
+            kind: ExprKind::FuncCall(Box::new(call)),
+            range: CodeRange {
+                start: name_range.start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((expr, tok1))
+    }
+
+    /// Parses the parenthesized arguments of a function call.
+    fn parse_func_application(
+        &mut self,
+        name: Vec<Symbol>,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<FuncCall> {
+        let mut call = FuncCall {
+            name,
+            args: Vec::new(),
+            star: false,
+            distinct: false,
+            variadic: false,
+            order_by: Vec::new(),
+            within_group: false,
+            filter: None,
+            over: None,
+            sql_syntax: false,
+        };
+        let tok1 = self.expect_token(tok0, TokenKind::LParen, diags)?;
+        // `VARIADIC` cannot be combined with `ALL` or `DISTINCT`,
+        // in which case the reserved keyword is simply a syntax error.
+        let (mut tok2, allow_variadic) = match tok1.kind {
+            TokenKind::RParen => return Ok((call, self.tokens.next_token(diags))),
+            TokenKind::Asterisk => {
+                call.star = true;
+                let tok2 = self.tokens.next_token(diags);
+                let tok3 = self.expect_token(tok2, TokenKind::RParen, diags)?;
+                return Ok((call, tok3));
+            }
+            _ if tok1.is_keyword(&Symbol::KEYWORD_all) => (self.tokens.next_token(diags), false),
+            _ if tok1.is_keyword(&Symbol::KEYWORD_distinct) => {
+                call.distinct = true;
+                (self.tokens.next_token(diags), false)
+            }
+            _ => (tok1, true),
+        };
+        loop {
+            // Only the last argument can be marked VARIADIC.
+            if allow_variadic && tok2.is_keyword(&Symbol::KEYWORD_variadic) {
+                call.variadic = true;
+                let tok3 = self.tokens.next_token(diags);
+                let (arg, tok4) = self.parse_func_arg(tok3, diags)?;
+                call.args.push(arg);
+                tok2 = tok4;
+                break;
+            }
+            let (arg, tok3) = self.parse_func_arg(tok2, diags)?;
+            call.args.push(arg);
+            if tok3.kind != TokenKind::Comma {
+                tok2 = tok3;
+                break;
+            }
+            tok2 = self.tokens.next_token(diags);
+        }
+        if tok2.is_keyword(&Symbol::KEYWORD_order) {
+            let tok3 = self.tokens.next_token(diags);
+            let (order_by, tok4) = self.parse_sort_clause(tok3, diags)?;
+            call.order_by = order_by;
+            tok2 = tok4;
+        }
+        let tok3 = self.expect_token(tok2, TokenKind::RParen, diags)?;
+        Ok((call, tok3))
+    }
+
+    /// Parses an argument, which may be named as in `name => value` (`func_arg_expr`).
+    pub(super) fn parse_func_arg(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        if !self.starts_named_arg(&tok0, diags) {
+            return self.parse_expr(tok0, diags);
+        }
+        let TokenKind::Identifier { name, .. } = tok0.kind else {
+            unreachable!("named argument must start with an identifier");
+        };
+        self.tokens.next_token(diags);
+        let tok1 = self.tokens.next_token(diags);
+        let (value, tok2) = self.parse_expr(tok1, diags)?;
+        let expr = ExprNode {
+            range: join(tok0.range, value.range),
+            kind: ExprKind::NamedArg {
+                name,
+                value: Box::new(value),
+            },
+        };
+        Ok((expr, tok2))
+    }
+
+    /// Whether the token starts a named argument such as `name => value`.
+    pub(super) fn starts_named_arg(&mut self, tok: &Token, diags: &mut CodeDiagnostics) -> bool {
+        match &tok.kind {
+            TokenKind::Identifier { name, quoted } if *quoted || name.is_type_function_name() => {
+                matches!(
+                    self.tokens.peek_token(diags).kind,
+                    TokenKind::FatArrow | TokenKind::ColonEq
+                )
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the token starts a function with a special syntax (`func_expr_common_subexpr`).
+    ///
+    /// Most of the keywords are column names unless followed by `(`, so the next token is
+    /// peeked at for them.
+    pub(super) fn starts_func_expr_common_subexpr(
+        &mut self,
+        tok: &Token,
+        diags: &mut CodeDiagnostics,
+    ) -> bool {
+        let Some(keyword) = tok.keyword() else {
+            return false;
+        };
+        match *keyword {
+            Symbol::KEYWORD_current_catalog
+            | Symbol::KEYWORD_current_date
+            | Symbol::KEYWORD_current_role
+            | Symbol::KEYWORD_current_time
+            | Symbol::KEYWORD_current_timestamp
+            | Symbol::KEYWORD_current_user
+            | Symbol::KEYWORD_localtime
+            | Symbol::KEYWORD_localtimestamp
+            | Symbol::KEYWORD_session_user
+            | Symbol::KEYWORD_system_user
+            | Symbol::KEYWORD_user => true,
+            // `CURRENT_SCHEMA` followed by `(` or a string is an ordinary function or type name.
+            Symbol::KEYWORD_current_schema => {
+                let next = self.tokens.peek_token(diags);
+                next.kind != TokenKind::LParen && !is_sconst(next)
+            }
+            Symbol::KEYWORD_collation => self
+                .tokens
+                .peek_token(diags)
+                .is_keyword(&Symbol::KEYWORD_for),
+            Symbol::KEYWORD_coalesce
+            | Symbol::KEYWORD_extract
+            | Symbol::KEYWORD_greatest
+            | Symbol::KEYWORD_least
+            | Symbol::KEYWORD_merge_action
+            | Symbol::KEYWORD_normalize
+            | Symbol::KEYWORD_nullif
+            | Symbol::KEYWORD_overlay
+            | Symbol::KEYWORD_position
+            | Symbol::KEYWORD_substring
+            | Symbol::KEYWORD_treat
+            | Symbol::KEYWORD_trim => self.tokens.peek_token(diags).kind == TokenKind::LParen,
+            _ => false,
+        }
+    }
+
+    /// Parses a function with a special syntax (`func_expr_common_subexpr`),
+    /// such as `COALESCE(a, b)`, `EXTRACT(field FROM a)`, or `CURRENT_USER`.
+    ///
+    /// As in PostgreSQL, most of them are represented as calls to the underlying functions
+    /// in `pg_catalog`.
+    pub(super) fn parse_func_expr_common_subexpr(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let keyword = tok0
+            .keyword()
+            .cloned()
+            .expect("special function name must be a keyword");
+        let tok1 = self.tokens.next_token(diags);
+        let value_kind = match keyword {
+            Symbol::KEYWORD_current_date => Some(SqlValueFunctionKind::CurrentDate),
+            Symbol::KEYWORD_current_time => Some(SqlValueFunctionKind::CurrentTime),
+            Symbol::KEYWORD_current_timestamp => Some(SqlValueFunctionKind::CurrentTimestamp),
+            Symbol::KEYWORD_localtime => Some(SqlValueFunctionKind::LocalTime),
+            Symbol::KEYWORD_localtimestamp => Some(SqlValueFunctionKind::LocalTimestamp),
+            _ => None,
+        };
+        if let Some(kind) = value_kind {
+            let (precision, tok2) =
+                if kind != SqlValueFunctionKind::CurrentDate && tok1.kind == TokenKind::LParen {
+                    let tok2 = self.tokens.next_token(diags);
+                    let (precision, tok3) = self.parse_iconst(tok2, diags)?;
+                    let tok4 = self.expect_token(tok3, TokenKind::RParen, diags)?;
+                    (Some(precision), tok4)
+                } else {
+                    (None, tok1)
+                };
+            let expr = ExprNode {
+                kind: ExprKind::SqlValueFunction { kind, precision },
+                range: CodeRange {
+                    start: tok0.range.start,
+                    end: self.tokens.prev_end(),
+                },
+            };
+            return Ok((expr, tok2));
+        }
+        let user_function = match keyword {
+            Symbol::KEYWORD_current_role | Symbol::KEYWORD_current_user | Symbol::KEYWORD_user => {
+                Some("current_user")
+            }
+            Symbol::KEYWORD_session_user => Some("session_user"),
+            Symbol::KEYWORD_system_user => Some("system_user"),
+            Symbol::KEYWORD_current_catalog => Some("current_database"),
+            Symbol::KEYWORD_current_schema => Some("current_schema"),
+            _ => None,
+        };
+        if let Some(name) = user_function {
+            let expr = ExprNode {
+                kind: sql_func_call(name, Vec::new()),
+                range: tok0.range,
+            };
+            return Ok((expr, tok1));
+        }
+        if keyword == Symbol::KEYWORD_merge_action {
+            let tok2 = self.expect_token(tok1, TokenKind::LParen, diags)?;
+            let tok3 = self.expect_token(tok2, TokenKind::RParen, diags)?;
+            let expr = ExprNode {
+                kind: ExprKind::MergeAction,
+                range: CodeRange {
+                    start: tok0.range.start,
+                    end: self.tokens.prev_end(),
+                },
+            };
+            return Ok((expr, tok3));
+        }
+        let tok2 = if keyword == Symbol::KEYWORD_collation {
+            let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_for, diags)?;
+            self.expect_token(tok2, TokenKind::LParen, diags)?
+        } else {
+            self.expect_token(tok1, TokenKind::LParen, diags)?
+        };
+        let (kind, tok3) = match keyword {
+            Symbol::KEYWORD_nullif => {
+                let (left, tok3) = self.parse_expr(tok2, diags)?;
+                let tok4 = self.expect_token(tok3, TokenKind::Comma, diags)?;
+                let (right, tok5) = self.parse_expr(tok4, diags)?;
+                let kind = ExprKind::NullIf {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                };
+                (kind, tok5)
+            }
+            Symbol::KEYWORD_coalesce | Symbol::KEYWORD_greatest | Symbol::KEYWORD_least => {
+                let (args, tok3) = self.parse_expr_list(tok2, diags)?;
+                let kind = match keyword {
+                    Symbol::KEYWORD_coalesce => ExprKind::Coalesce { args },
+                    Symbol::KEYWORD_greatest => ExprKind::Greatest { args },
+                    _ => ExprKind::Least { args },
+                };
+                (kind, tok3)
+            }
+            Symbol::KEYWORD_collation => {
+                let (arg, tok3) = self.parse_expr(tok2, diags)?;
+                (sql_func_call("pg_collation_for", vec![arg]), tok3)
+            }
+            Symbol::KEYWORD_extract => self.parse_extract_args(tok2, diags)?,
+            Symbol::KEYWORD_normalize => {
+                let (arg, tok3) = self.parse_expr(tok2, diags)?;
+                let mut args = vec![arg];
+                let tok4 = if tok3.kind == TokenKind::Comma {
+                    let tok4 = self.tokens.next_token(diags);
+                    let (form, tok5) = self.parse_unicode_normal_form(tok4, diags)?;
+                    args.push(form);
+                    tok5
+                } else {
+                    tok3
+                };
+                (sql_func_call("normalize", args), tok4)
+            }
+            Symbol::KEYWORD_overlay => self.parse_overlay_args(tok2, diags)?,
+            Symbol::KEYWORD_position => {
+                let (substring, tok3) = self.parse_b_expr(tok2, diags)?;
+                let tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_in, diags)?;
+                let (string, tok5) = self.parse_b_expr(tok4, diags)?;
+                (sql_func_call("position", vec![string, substring]), tok5)
+            }
+            Symbol::KEYWORD_substring => self.parse_substring_args(tok2, diags)?,
+            // `TREAT(a AS t)` is an ordinary call to the function named after the type.
+            Symbol::KEYWORD_treat => {
+                let (arg, tok3) = self.parse_expr(tok2, diags)?;
+                let tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_as, diags)?;
+                let (type_name, tok5) = self.parse_type_name(tok4, diags)?;
+                let name = vec![
+                    Symbol::from("pg_catalog"),
+                    type_name.name.last().unwrap().clone(),
+                ];
+                (func_call(name, vec![arg], false), tok5)
+            }
+            Symbol::KEYWORD_trim => self.parse_trim_args(tok2, diags)?,
+            _ => unreachable!("not a special function name: {}", &*keyword),
+        };
+        let tok4 = self.expect_token(tok3, TokenKind::RParen, diags)?;
+        let expr = ExprNode {
+            kind,
+            range: CodeRange {
+                start: tok0.range.start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((expr, tok4))
+    }
+
+    /// Parses `field FROM a` in `EXTRACT` (`extract_list`), where the field is passed as a string.
+    fn parse_extract_args(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprKind> {
+        let (field, tok1) = match &tok0.kind {
+            TokenKind::Identifier { name, quoted }
+                if *quoted
+                    || name.keyword_category().is_none()
+                    || matches!(
+                        *name,
+                        Symbol::KEYWORD_year
+                            | Symbol::KEYWORD_month
+                            | Symbol::KEYWORD_day
+                            | Symbol::KEYWORD_hour
+                            | Symbol::KEYWORD_minute
+                            | Symbol::KEYWORD_second
+                    ) =>
+            {
+                (
+                    string_const(name, tok0.range),
+                    self.tokens.next_token(diags),
+                )
+            }
+            _ if is_sconst(&tok0) => self.parse_const(tok0, diags)?,
+            _ => {
+                self.syntax_error(&tok0, diags);
+                return Err(tok0);
+            }
+        };
+        let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_from, diags)?;
+        let (source, tok3) = self.parse_expr(tok2, diags)?;
+        Ok((sql_func_call("extract", vec![field, source]), tok3))
+    }
+
+    /// Parses the arguments of `OVERLAY`, which are `a PLACING b FROM start [FOR count]`
+    /// (`overlay_list`) or ordinary arguments.
+    fn parse_overlay_args(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprKind> {
+        let (mut args, tok1) = self.parse_opt_first_func_arg(tok0, diags)?;
+        if !(is_single_positional_arg(&args) && tok1.is_keyword(&Symbol::KEYWORD_placing)) {
+            return self.parse_plain_special_func_args(
+                vec![Symbol::from("overlay")],
+                args,
+                tok1,
+                diags,
+            );
+        }
+        let tok2 = self.tokens.next_token(diags);
+        let (replacement, tok3) = self.parse_expr(tok2, diags)?;
+        let tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_from, diags)?;
+        let (start, mut tok5) = self.parse_expr(tok4, diags)?;
+        args.extend([replacement, start]);
+        if tok5.is_keyword(&Symbol::KEYWORD_for) {
+            let tok6 = self.tokens.next_token(diags);
+            let (count, tok7) = self.parse_expr(tok6, diags)?;
+            args.push(count);
+            tok5 = tok7;
+        }
+        Ok((sql_func_call("overlay", args), tok5))
+    }
+
+    /// Parses the arguments of `SUBSTRING`, which are `a FROM start FOR count` and its
+    /// variants, `a SIMILAR pattern ESCAPE escape` (`substr_list`), or ordinary arguments.
+    fn parse_substring_args(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprKind> {
+        let (mut args, tok1) = self.parse_opt_first_func_arg(tok0, diags)?;
+        if !is_single_positional_arg(&args) {
+            return self.parse_plain_special_func_args(
+                vec![Symbol::from("substring")],
+                args,
+                tok1,
+                diags,
+            );
+        }
+        let tok2 = match tok1.keyword() {
+            Some(&Symbol::KEYWORD_from) => {
+                let tok2 = self.tokens.next_token(diags);
+                let (start, tok3) = self.parse_expr(tok2, diags)?;
+                args.push(start);
+                if tok3.is_keyword(&Symbol::KEYWORD_for) {
+                    let tok4 = self.tokens.next_token(diags);
+                    let (count, tok5) = self.parse_expr(tok4, diags)?;
+                    args.push(count);
+                    tok5
+                } else {
+                    tok3
+                }
+            }
+            Some(&Symbol::KEYWORD_for) => {
+                let tok2 = self.tokens.next_token(diags);
+                let (count, tok3) = self.parse_expr(tok2, diags)?;
+                // The start defaults to 1 if omitted.
+                let (start, tok4) = if tok3.is_keyword(&Symbol::KEYWORD_from) {
+                    let tok4 = self.tokens.next_token(diags);
+                    self.parse_expr(tok4, diags)?
+                } else {
+                    (int_const(1, tok1.range), tok3)
+                };
+                args.extend([start, count]);
+                tok4
+            }
+            Some(&Symbol::KEYWORD_similar) => {
+                let tok2 = self.tokens.next_token(diags);
+                let (pattern, tok3) = self.parse_expr(tok2, diags)?;
+                let tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_escape, diags)?;
+                let (escape, tok5) = self.parse_expr(tok4, diags)?;
+                args.extend([pattern, escape]);
+                tok5
+            }
+            _ => {
+                return self.parse_plain_special_func_args(
+                    vec![Symbol::from("substring")],
+                    args,
+                    tok1,
+                    diags,
+                );
+            }
+        };
+        Ok((sql_func_call("substring", args), tok2))
+    }
+
+    /// Parses the first argument of `OVERLAY` or `SUBSTRING`, which is absent before `)`.
+    fn parse_opt_first_func_arg(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<ExprNode>> {
+        if tok0.kind == TokenKind::RParen {
+            return Ok((Vec::new(), tok0));
+        }
+        let (arg, tok1) = self.parse_func_arg(tok0, diags)?;
+        Ok((vec![arg], tok1))
+    }
+
+    /// Parses the rest of the ordinary arguments of `OVERLAY`, `SUBSTRING`, or `JSON_OBJECT`
+    /// (`func_arg_list_opt`), which make an ordinary call to the function.
+    pub(super) fn parse_plain_special_func_args(
+        &mut self,
+        name: Vec<Symbol>,
+        mut args: Vec<ExprNode>,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprKind> {
+        let mut tok1 = tok0;
+        if !args.is_empty() && tok1.kind == TokenKind::Comma {
+            let tok2 = self.tokens.next_token(diags);
+            let (rest, tok3) = self.parse_comma_list(tok2, diags, Self::parse_func_arg)?;
+            args.extend(rest);
+            tok1 = tok3;
+        }
+        Ok((func_call(name, args, false), tok1))
+    }
+
+    /// Parses the arguments of `TRIM`, which are `[BOTH | LEADING | TRAILING] [chars] FROM list`
+    /// or `[BOTH | LEADING | TRAILING] list` (`trim_list`).
+    fn parse_trim_args(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprKind> {
+        let (name, tok1) = match tok0.keyword() {
+            Some(&Symbol::KEYWORD_both) => ("btrim", self.tokens.next_token(diags)),
+            Some(&Symbol::KEYWORD_leading) => ("ltrim", self.tokens.next_token(diags)),
+            Some(&Symbol::KEYWORD_trailing) => ("rtrim", self.tokens.next_token(diags)),
+            _ => ("btrim", tok0),
+        };
+        let (args, tok2) = if tok1.is_keyword(&Symbol::KEYWORD_from) {
+            let tok2 = self.tokens.next_token(diags);
+            self.parse_expr_list(tok2, diags)?
+        } else {
+            let (list, tok2) = self.parse_expr_list(tok1, diags)?;
+            // The characters to trim come after the strings.
+            if list.len() == 1 && tok2.is_keyword(&Symbol::KEYWORD_from) {
+                let tok3 = self.tokens.next_token(diags);
+                let (mut args, tok4) = self.parse_expr_list(tok3, diags)?;
+                args.extend(list);
+                (args, tok4)
+            } else {
+                (list, tok2)
+            }
+        };
+        Ok((sql_func_call(name, args), tok2))
+    }
+
+    /// Parses `NFC`, `NFD`, `NFKC`, or `NFKD` into a string literal (`unicode_normal_form`).
+    pub(super) fn parse_unicode_normal_form(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        if !starts_unicode_normal_form(&tok0) {
+            self.syntax_error(&tok0, diags);
+            return Err(tok0);
+        }
+        let form = tok0.keyword().unwrap().to_uppercase();
+        Ok((
+            string_const(&form, tok0.range),
+            self.tokens.next_token(diags),
+        ))
+    }
+
+    /// Parses `( [existing_window_name] [PARTITION BY ...] [ORDER BY ...] [frame] )`.
+    pub(super) fn parse_window_specification(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<WindowDefNode> {
+        let start = tok0.range.start;
+        let mut tok1 = self.expect_token(tok0, TokenKind::LParen, diags)?;
+        let mut refname = None;
+        if let TokenKind::Identifier { name, quoted } = &tok1.kind
+            // These keywords start the following clauses rather than naming a window.
+            && (*quoted
+                || (name.is_col_id()
+                    && !matches!(
+                        *name,
+                        Symbol::KEYWORD_partition
+                            | Symbol::KEYWORD_range
+                            | Symbol::KEYWORD_rows
+                            | Symbol::KEYWORD_groups
+                    )))
+        {
+            refname = Some(name.clone());
+            tok1 = self.tokens.next_token(diags);
+        }
+        let mut partition_by = Vec::new();
+        if tok1.is_keyword(&Symbol::KEYWORD_partition) {
+            let tok2 = self.tokens.next_token(diags);
+            let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_by, diags)?;
+            (partition_by, tok1) = self.parse_expr_list(tok3, diags)?;
+        }
+        let mut order_by = Vec::new();
+        if tok1.is_keyword(&Symbol::KEYWORD_order) {
+            let tok2 = self.tokens.next_token(diags);
+            (order_by, tok1) = self.parse_sort_clause(tok2, diags)?;
+        }
+        let mut frame = None;
+        if matches!(
+            tok1.keyword(),
+            Some(&(Symbol::KEYWORD_range | Symbol::KEYWORD_rows | Symbol::KEYWORD_groups))
+        ) {
+            let (window_frame, tok2) = self.parse_window_frame(tok1, diags)?;
+            frame = Some(window_frame);
+            tok1 = tok2;
+        }
+        if tok1.kind != TokenKind::RParen {
+            self.syntax_error(&tok1, diags);
+            return Err(tok1);
+        }
+        let window = WindowDefNode {
+            name: None,
+            refname,
+            partition_by,
+            order_by,
+            frame,
+            range: CodeRange {
+                start,
+                end: tok1.range.end,
+            },
+        };
+        Ok((window, self.tokens.next_token(diags)))
+    }
+
+    /// Parses `{RANGE | ROWS | GROUPS} frame_extent [EXCLUDE ...]` (`opt_frame_clause`).
+    fn parse_window_frame(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<WindowFrame> {
+        let start = tok0.range.start;
+        let mode = match tok0.keyword() {
+            Some(&Symbol::KEYWORD_range) => FrameMode::Range,
+            Some(&Symbol::KEYWORD_rows) => FrameMode::Rows,
+            Some(&Symbol::KEYWORD_groups) => FrameMode::Groups,
+            _ => {
+                self.syntax_error(&tok0, diags);
+                return Err(tok0);
+            }
+        };
+        let tok1 = self.tokens.next_token(diags);
+        let (start_bound, end_bound, tok2) = if tok1.is_keyword(&Symbol::KEYWORD_between) {
+            let tok2 = self.tokens.next_token(diags);
+            let (start_bound, start_range, tok3) = self.parse_frame_bound(tok2, diags)?;
+            let tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_and, diags)?;
+            let (end_bound, end_range, tok5) = self.parse_frame_bound(tok4, diags)?;
+            if start_bound == FrameBound::UnboundedFollowing {
+                diags.add(CodeDiagnostic::FrameStartUnboundedFollowing { range: start_range });
+            }
+            match (&start_bound, &end_bound) {
+                (_, FrameBound::UnboundedPreceding) => {
+                    diags.add(CodeDiagnostic::FrameEndUnboundedPreceding { range: end_range });
+                }
+                (FrameBound::CurrentRow, FrameBound::Preceding { .. }) => {
+                    diags.add(CodeDiagnostic::FrameCurrentRowWithPreceding { range: end_range });
+                }
+                (
+                    FrameBound::Following { .. },
+                    FrameBound::Preceding { .. } | FrameBound::CurrentRow,
+                ) => {
+                    diags.add(CodeDiagnostic::FrameFollowingWithPreceding { range: end_range });
+                }
+                _ => {}
+            }
+            (start_bound, end_bound, tok5)
+        } else {
+            let (start_bound, start_range, tok2) = self.parse_frame_bound(tok1, diags)?;
+            match start_bound {
+                FrameBound::UnboundedFollowing => {
+                    diags.add(CodeDiagnostic::FrameStartUnboundedFollowing { range: start_range });
+                }
+                FrameBound::Following { .. } => {
+                    diags.add(CodeDiagnostic::FrameFollowingEndsWithCurrentRow {
+                        range: start_range,
+                    });
+                }
+                _ => {}
+            }
+            (start_bound, FrameBound::CurrentRow, tok2)
+        };
+        let (exclusion, tok3) = if tok2.is_keyword(&Symbol::KEYWORD_exclude) {
+            let tok3 = self.tokens.next_token(diags);
+            let (exclusion, tok4) = match tok3.keyword() {
+                Some(&Symbol::KEYWORD_current) => {
+                    let tok4 = self.tokens.next_token(diags);
+                    let tok5 = self.expect_keyword(tok4, Symbol::KEYWORD_row, diags)?;
+                    (FrameExclusion::CurrentRow, tok5)
+                }
+                Some(&Symbol::KEYWORD_group) => {
+                    (FrameExclusion::Group, self.tokens.next_token(diags))
+                }
+                Some(&Symbol::KEYWORD_ties) => {
+                    (FrameExclusion::Ties, self.tokens.next_token(diags))
+                }
+                Some(&Symbol::KEYWORD_no) => {
+                    let tok4 = self.tokens.next_token(diags);
+                    let tok5 = self.expect_keyword(tok4, Symbol::KEYWORD_others, diags)?;
+                    (FrameExclusion::NoOthers, tok5)
+                }
+                _ => {
+                    self.syntax_error(&tok3, diags);
+                    return Err(tok3);
+                }
+            };
+            (exclusion, tok4)
+        } else {
+            (FrameExclusion::NoOthers, tok2)
+        };
+        let frame = WindowFrame {
+            mode,
+            start: start_bound,
+            end: end_bound,
+            exclusion,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((frame, tok3))
+    }
+
+    /// Parses a `frame_bound`, returning its range as well for diagnostics.
+    fn parse_frame_bound(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> Result<(FrameBound, CodeRange, Token), Token> {
+        let start = tok0.range.start;
+        let next_keyword = self.tokens.peek_token(diags).keyword().cloned();
+        let (bound, tok1) = match (tok0.keyword(), next_keyword) {
+            (
+                Some(&Symbol::KEYWORD_unbounded),
+                Some(Symbol::KEYWORD_preceding | Symbol::KEYWORD_following),
+            ) => {
+                let tok1 = self.tokens.next_token(diags);
+                let bound = if tok1.is_keyword(&Symbol::KEYWORD_preceding) {
+                    FrameBound::UnboundedPreceding
+                } else {
+                    FrameBound::UnboundedFollowing
+                };
+                (bound, self.tokens.next_token(diags))
+            }
+            (Some(&Symbol::KEYWORD_current), Some(Symbol::KEYWORD_row)) => {
+                self.tokens.next_token(diags);
+                (FrameBound::CurrentRow, self.tokens.next_token(diags))
+            }
+            _ => {
+                let (offset, tok1) = self.parse_expr(tok0, diags)?;
+                let offset = Box::new(offset);
+                let bound = match tok1.keyword() {
+                    Some(&Symbol::KEYWORD_preceding) => FrameBound::Preceding { offset },
+                    Some(&Symbol::KEYWORD_following) => FrameBound::Following { offset },
+                    _ => {
+                        self.syntax_error(&tok1, diags);
+                        return Err(tok1);
+                    }
+                };
+                (bound, self.tokens.next_token(diags))
+            }
+        };
+        let range = CodeRange {
+            start,
+            end: self.tokens.prev_end(),
+        };
+        Ok((bound, range, tok1))
+    }
+}
+
+/// Whether the token is `NFC`, `NFD`, `NFKC`, or `NFKD` (`unicode_normal_form`).
+pub(super) fn starts_unicode_normal_form(tok: &Token) -> bool {
+    matches!(
+        tok.keyword(),
+        Some(
+            &(Symbol::KEYWORD_nfc
+                | Symbol::KEYWORD_nfd
+                | Symbol::KEYWORD_nfkc
+                | Symbol::KEYWORD_nfkd)
+        )
+    )
+}
+
+/// Whether the arguments are a single argument without a name.
+fn is_single_positional_arg(args: &[ExprNode]) -> bool {
+    matches!(args, [arg] if !matches!(arg.kind, ExprKind::NamedArg { .. }))
+}
+
+/// A function call with the given arguments and nothing else.
+fn func_call(name: Vec<Symbol>, args: Vec<ExprNode>, sql_syntax: bool) -> ExprKind {
+    ExprKind::FuncCall(Box::new(FuncCall {
+        name,
+        args,
+        star: false,
+        distinct: false,
+        variadic: false,
+        order_by: Vec::new(),
+        within_group: false,
+        filter: None,
+        over: None,
+        sql_syntax,
+    }))
+}
+
+/// A call to a function in `pg_catalog` written in a special SQL syntax.
+pub(super) fn sql_func_call(name: &str, args: Vec<ExprNode>) -> ExprKind {
+    func_call(
+        vec![Symbol::from("pg_catalog"), Symbol::from(name)],
+        args,
+        true,
+    )
+}
+
+fn string_const(value: &str, range: CodeRange) -> ExprNode {
+    ExprNode {
+        kind: ExprKind::StringLiteral {
+            value: value.to_string(),
+        },
+        range,
+    }
+}
+
+/// The window in `OVER name`, which refers to a window in the `WINDOW` clause.
+fn named_window(name: Symbol, range: CodeRange) -> WindowDefNode {
+    WindowDefNode {
+        name: Some(name),
+        refname: None,
+        partition_by: Vec::new(),
+        order_by: Vec::new(),
+        frame: None,
+        range,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parser::sexp::{
+            parse_expr_errors, parse_expr_sexp, parse_expr_src, parse_stmt_sexp, syntax_error,
+        },
+        pos::pos,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_args() {
+        assert_eq!(parse_expr_sexp("f()"), "(call f)");
+        assert_eq!(parse_expr_sexp("s.f(1, a + 2)"), "(call s.f 1 (+ a 2))");
+        assert_eq!(parse_expr_sexp("count(*)"), "(call count *)");
+        assert_eq!(parse_expr_sexp("count(all a)"), "(call count a)");
+        assert_eq!(
+            parse_expr_sexp("count(distinct a, b)"),
+            "(call count distinct a b)"
+        );
+    }
+
+    #[test]
+    fn test_named_args() {
+        assert_eq!(
+            parse_expr_sexp("f(1, b => 2, \"C\" := 3)"),
+            "(call f 1 (=> b 2) (=> C 3))"
+        );
+        // Only type function names can be parameter names.
+        let src = "f(int => 1)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "=>", 0));
+        let src = "f(b => 1)";
+        let expr = parse_expr_src(src, &mut CodeDiagnostics::new()).unwrap();
+        let ExprKind::FuncCall(call) = expr.kind else {
+            panic!("not a function call");
+        };
+        assert_eq!(call.args[0].range, pos(src, "b => 1", 0));
+    }
+
+    #[test]
+    fn test_variadic() {
+        assert_eq!(parse_expr_sexp("f(variadic a)"), "(call f (variadic a))");
+        assert_eq!(
+            parse_expr_sexp("f(a, variadic b => c)"),
+            "(call f a (variadic (=> b c)))"
+        );
+        let src = "f(variadic a, b)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, ",", 0));
+        let src = "f(distinct variadic a)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "variadic", 0));
+    }
+
+    #[test]
+    fn test_aggregate_order_by() {
+        assert_eq!(
+            parse_expr_sexp("string_agg(distinct a, ',' order by a desc)"),
+            "(call string_agg distinct a ',' (order-by (desc a)))"
+        );
+    }
+
+    #[test]
+    fn test_within_group() {
+        assert_eq!(
+            parse_expr_sexp("percentile_cont(0.5) within group (order by a)"),
+            "(call percentile_cont 5e-1 (within-group a))"
+        );
+        let src = "f(a order by a) within group (order by b)";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::MultipleOrderByWithWithinGroup {
+                range: pos(src, "within group (order by b)", 0),
+            }]
+        );
+        let src = "f(distinct a) within group (order by b)";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::DistinctWithWithinGroup {
+                range: pos(src, "within group (order by b)", 0),
+            }]
+        );
+        let src = "f(variadic a) within group (order by b)";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::VariadicWithWithinGroup {
+                range: pos(src, "within group (order by b)", 0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_filter_over() {
+        assert_eq!(
+            parse_expr_sexp("count(*) filter (where a > 0) over w"),
+            "(call count * (filter (> a 0)) (over (w)))"
+        );
+        assert_eq!(
+            parse_expr_sexp("sum(a) over (w partition by b order by c)"),
+            "(call sum a (over (_ (ref w) (partition-by b) (order-by c))))"
+        );
+        let src = "count(*) filter (a > 0)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "a", 0));
+        let src = "sum(a) over (order by b) + 1";
+        let expr = parse_expr_src(src, &mut CodeDiagnostics::new()).unwrap();
+        let ExprKind::BinaryOp { left, .. } = expr.kind else {
+            panic!("not a binary operation");
+        };
+        assert_eq!(left.range, pos(src, "sum(a) over (order by b)", 0));
+    }
+
+    #[test]
+    fn test_func_expr_common_subexpr() {
+        assert_eq!(
+            parse_expr_sexp("coalesce(a, b + 1)"),
+            "(coalesce a (+ b 1))"
+        );
+        assert_eq!(parse_expr_sexp("nullif(a, '')"), "(nullif a '')");
+        assert_eq!(parse_expr_sexp("greatest(1, 2, 3)"), "(greatest 1 2 3)");
+        assert_eq!(parse_expr_sexp("least(a)"), "(least a)");
+        // The keywords are column names unless followed by `(`.
+        assert_eq!(parse_expr_sexp("coalesce + least"), "(+ coalesce least)");
+        assert_eq!(parse_expr_sexp("extract + trim"), "(+ extract trim)");
+        let src = "nullif(a)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, ")", 0));
+        let src = "coalesce()";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, ")", 0));
+    }
+
+    #[test]
+    fn test_sql_value_functions() {
+        assert_eq!(parse_expr_sexp("current_date"), "current_date");
+        assert_eq!(parse_expr_sexp("current_time(3)"), "(current_time 3)");
+        assert_eq!(parse_expr_sexp("localtimestamp"), "localtimestamp");
+        assert_eq!(
+            parse_expr_sexp("current_user"),
+            "(sql-call pg_catalog.current_user)"
+        );
+        assert_eq!(
+            parse_expr_sexp("user"),
+            "(sql-call pg_catalog.current_user)"
+        );
+        assert_eq!(
+            parse_expr_sexp("current_catalog"),
+            "(sql-call pg_catalog.current_database)"
+        );
+        // `CURRENT_SCHEMA()` is an ordinary function call.
+        assert_eq!(
+            parse_expr_sexp("current_schema"),
+            "(sql-call pg_catalog.current_schema)"
+        );
+        assert_eq!(parse_expr_sexp("current_schema()"), "(call current_schema)");
+        let src = "current_date(1)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "(", 0));
+        let src = "localtime(a)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "a", 1));
+        let src = "current_time(3) + 1";
+        let expr = parse_expr_src(src, &mut CodeDiagnostics::new()).unwrap();
+        let ExprKind::BinaryOp { left, .. } = expr.kind else {
+            panic!("not a binary operation");
+        };
+        assert_eq!(left.range, pos(src, "current_time(3)", 0));
+    }
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            parse_expr_sexp("extract(year from a)"),
+            "(sql-call pg_catalog.extract 'year' a)"
+        );
+        assert_eq!(
+            parse_expr_sexp("extract(epoch from a)"),
+            "(sql-call pg_catalog.extract 'epoch' a)"
+        );
+        assert_eq!(
+            parse_expr_sexp("extract('dow' from a)"),
+            "(sql-call pg_catalog.extract 'dow' a)"
+        );
+        // Keywords other than the interval fields are not allowed.
+        let src = "extract(time from a)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "time", 0));
+    }
+
+    #[test]
+    fn test_substring() {
+        assert_eq!(
+            parse_expr_sexp("substring(a from 2 for 3)"),
+            "(sql-call pg_catalog.substring a 2 3)"
+        );
+        assert_eq!(
+            parse_expr_sexp("substring(a for 3 from 2)"),
+            "(sql-call pg_catalog.substring a 2 3)"
+        );
+        assert_eq!(
+            parse_expr_sexp("substring(a from 2)"),
+            "(sql-call pg_catalog.substring a 2)"
+        );
+        assert_eq!(
+            parse_expr_sexp("substring(a for 3)"),
+            "(sql-call pg_catalog.substring a 1 3)"
+        );
+        assert_eq!(
+            parse_expr_sexp("substring(a similar 'x' escape '#')"),
+            "(sql-call pg_catalog.substring a 'x' '#')"
+        );
+        // Ordinary arguments make an ordinary call.
+        assert_eq!(
+            parse_expr_sexp("substring(a, 2, 3)"),
+            "(call substring a 2 3)"
+        );
+        assert_eq!(parse_expr_sexp("substring()"), "(call substring)");
+        let src = "substring(a => 1 from 2)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "from", 0));
+        let src = "substring(a, 1 from 2)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "from", 0));
+    }
+
+    #[test]
+    fn test_trim() {
+        assert_eq!(
+            parse_expr_sexp("trim(both 'x' from a)"),
+            "(sql-call pg_catalog.btrim a 'x')"
+        );
+        assert_eq!(
+            parse_expr_sexp("trim(leading from a)"),
+            "(sql-call pg_catalog.ltrim a)"
+        );
+        assert_eq!(
+            parse_expr_sexp("trim(trailing a, 'x')"),
+            "(sql-call pg_catalog.rtrim a 'x')"
+        );
+        assert_eq!(parse_expr_sexp("trim(a)"), "(sql-call pg_catalog.btrim a)");
+        let src = "trim(a, b from c)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "from", 0));
+    }
+
+    #[test]
+    fn test_overlay_position() {
+        assert_eq!(
+            parse_expr_sexp("overlay(a placing 'x' from 2 for 3)"),
+            "(sql-call pg_catalog.overlay a 'x' 2 3)"
+        );
+        assert_eq!(
+            parse_expr_sexp("overlay(a placing 'x' from 2)"),
+            "(sql-call pg_catalog.overlay a 'x' 2)"
+        );
+        assert_eq!(
+            parse_expr_sexp("overlay(a, 'x', 2)"),
+            "(call overlay a 'x' 2)"
+        );
+        let src = "overlay(a placing 'x')";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, ")", 0));
+        assert_eq!(
+            parse_expr_sexp("position('x' in a || b)"),
+            "(sql-call pg_catalog.position (|| a b) 'x')"
+        );
+        // The operands are restricted expressions, which cannot contain `IN`.
+        let src = "position(a in b in c)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "in", 1));
+    }
+
+    #[test]
+    fn test_normalize_treat_collation_for() {
+        assert_eq!(
+            parse_expr_sexp("normalize(a)"),
+            "(sql-call pg_catalog.normalize a)"
+        );
+        assert_eq!(
+            parse_expr_sexp("normalize(a, nfkc)"),
+            "(sql-call pg_catalog.normalize a 'NFKC')"
+        );
+        let src = "normalize(a, 'nfc')";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "'nfc'", 0));
+        assert_eq!(parse_expr_sexp("treat(a as s.t)"), "(call pg_catalog.t a)");
+        assert_eq!(
+            parse_expr_sexp("treat(a as int)"),
+            "(call pg_catalog.int4 a)"
+        );
+        assert_eq!(
+            parse_expr_sexp("collation for (a)"),
+            "(sql-call pg_catalog.pg_collation_for a)"
+        );
+        assert_eq!(
+            parse_stmt_sexp("select collation for ('foo') from t where collation for (a) = 'C'"),
+            "(select (targets (sql-call pg_catalog.pg_collation_for 'foo')) (from t) \
+             (where (= (sql-call pg_catalog.pg_collation_for a) 'C')))"
+        );
+        // `COLLATION` is otherwise a function or type name.
+        assert_eq!(parse_expr_sexp("collation(a)"), "(call collation a)");
+    }
+
+    #[test]
+    fn test_merge_action() {
+        assert_eq!(parse_expr_sexp("merge_action()"), "(merge-action)");
+        assert_eq!(
+            parse_expr_sexp("merge_action() = 'INSERT'"),
+            "(= (merge-action) 'INSERT')"
+        );
+        let src = "merge_action(a)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "a", 1));
+        // `MERGE_ACTION` is otherwise a column name.
+        assert_eq!(parse_expr_sexp("merge_action"), "merge_action");
+    }
+
+    #[test]
+    fn test_window_frame() {
+        assert_eq!(
+            parse_expr_sexp("sum(a) over (rows unbounded preceding)"),
+            "(call sum a (over (_ (rows unbounded-preceding current-row))))"
+        );
+        assert_eq!(
+            parse_expr_sexp(
+                "sum(a) over (order by b range between 1 preceding and unbounded following)"
+            ),
+            "(call sum a (over (_ (order-by b) \
+             (range (preceding 1) unbounded-following))))"
+        );
+        assert_eq!(
+            parse_expr_sexp(
+                "sum(a) over (groups between current row and 2 following exclude current row)"
+            ),
+            "(call sum a (over (_ (groups current-row (following 2) exclude-current-row))))"
+        );
+        assert_eq!(
+            parse_expr_sexp("sum(a) over (rows current row exclude ties)"),
+            "(call sum a (over (_ (rows current-row current-row exclude-ties))))"
+        );
+        assert_eq!(
+            parse_expr_sexp("sum(a) over (rows current row exclude no others)"),
+            "(call sum a (over (_ (rows current-row current-row))))"
+        );
+        let src = "sum(a) over (rows 1)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, ")", 1));
+    }
+
+    #[test]
+    fn test_invalid_window_frame() {
+        let src = "f() over (rows unbounded following)";
+        let diags = parse_expr_errors(src);
+        assert_eq!(
+            diags,
+            vec![CodeDiagnostic::FrameStartUnboundedFollowing {
+                range: pos(src, "unbounded following", 0),
+            }]
+        );
+        assert_eq!(diags[0].sqlstate(), "42P20");
+        let src = "f() over (rows 1 following)";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::FrameFollowingEndsWithCurrentRow {
+                range: pos(src, "1 following", 0),
+            }]
+        );
+        let src = "f() over (rows between current row and unbounded preceding)";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::FrameEndUnboundedPreceding {
+                range: pos(src, "unbounded preceding", 0),
+            }]
+        );
+        let src = "f() over (rows between current row and 1 preceding)";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::FrameCurrentRowWithPreceding {
+                range: pos(src, "1 preceding", 0),
+            }]
+        );
+        let src = "f() over (rows between 1 following and current row)";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::FrameFollowingWithPreceding {
+                range: pos(src, "current row", 0),
+            }]
+        );
+    }
+}
@@ -0,0 +1,400 @@
+// `InsertStmt` in gram.y, including `ON CONFLICT` and `RETURNING`.
+
+use crate::{
+    Symbol,
+    ast::{
+        AssignTargetNode, ConflictAction, ConflictTarget, ExprNode, IndexElemNode, IndexKey,
+        InsertNode, OnConflictNode, OverridingKind, SetClauseNode, SortOrder, TargetNode,
+        WithClause,
+    },
+    diag::CodeDiagnostics,
+    pos::CodeRange,
+    token::{Token, TokenKind},
+};
+
+use super::{ParseResult, Parser, select::starts_select};
+
+impl Parser<'_> {
+    /// Parses `INSERT INTO ...` following the `WITH` clause if any.
+    pub(super) fn parse_insert(
+        &mut self,
+        with: Option<WithClause>,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<InsertNode> {
+        let tok1 = self.expect_keyword(tok0, Symbol::KEYWORD_insert, diags)?;
+        let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_into, diags)?;
+        let (table, tok3) = self.parse_qualified_name(tok2, diags)?;
+        let (alias, tok4) = if tok3.is_keyword(&Symbol::KEYWORD_as) {
+            let tok4 = self.tokens.next_token(diags);
+            let (alias, tok5) = self.parse_col_id(tok4, diags)?;
+            (Some(alias), tok5)
+        } else {
+            (None, tok3)
+        };
+        // `(` starts the column list unless it starts a parenthesized query.
+        let (columns, tok5) = if tok4.kind == TokenKind::LParen && {
+            let next = self.tokens.peek_token(diags);
+            !(starts_select(next) || next.kind == TokenKind::LParen)
+        } {
+            let tok5 = self.tokens.next_token(diags);
+            let (columns, tok6) = self.parse_comma_list(tok5, diags, Self::parse_assign_target)?;
+            let tok7 = self.expect_token(tok6, TokenKind::RParen, diags)?;
+            (columns, tok7)
+        } else {
+            (Vec::new(), tok4)
+        };
+        let (overriding, tok6) = if tok5.is_keyword(&Symbol::KEYWORD_overriding) {
+            let tok6 = self.tokens.next_token(diags);
+            let overriding = match tok6.keyword() {
+                Some(&Symbol::KEYWORD_system) => OverridingKind::System,
+                Some(&Symbol::KEYWORD_user) => OverridingKind::User,
+                _ => {
+                    self.syntax_error(&tok6, diags);
+                    return Err(tok6);
+                }
+            };
+            let tok7 = self.tokens.next_token(diags);
+            let tok8 = self.expect_keyword(tok7, Symbol::KEYWORD_value, diags)?;
+            (Some(overriding), tok8)
+        } else {
+            (None, tok5)
+        };
+        // `DEFAULT VALUES` cannot follow the column list or `OVERRIDING`.
+        let (source, tok7) = if columns.is_empty()
+            && overriding.is_none()
+            && tok6.is_keyword(&Symbol::KEYWORD_default)
+        {
+            let tok7 = self.tokens.next_token(diags);
+            let tok8 = self.expect_keyword(tok7, Symbol::KEYWORD_values, diags)?;
+            (None, tok8)
+        } else {
+            let (query, tok7) = self.parse_select(tok6, diags)?;
+            (Some(query), tok7)
+        };
+        let (on_conflict, tok8) = if tok7.is_keyword(&Symbol::KEYWORD_on) {
+            let (on_conflict, tok8) = self.parse_on_conflict(tok7, diags)?;
+            (Some(on_conflict), tok8)
+        } else {
+            (None, tok7)
+        };
+        let (returning, tok9) = self.parse_opt_returning(tok8, diags)?;
+        let insert = InsertNode {
+            with,
+            table,
+            alias,
+            columns,
+            overriding,
+            source,
+            on_conflict,
+            returning,
+        };
+        Ok((insert, tok9))
+    }
+
+    /// Parses `ON CONFLICT [target] DO {NOTHING | UPDATE SET ... [WHERE ...]}`
+    /// (`opt_on_conflict`).
+    fn parse_on_conflict(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<OnConflictNode> {
+        let start = tok0.range.start;
+        let tok1 = self.expect_keyword(tok0, Symbol::KEYWORD_on, diags)?;
+        let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_conflict, diags)?;
+        let (target, tok3) = if tok2.kind == TokenKind::LParen {
+            let tok3 = self.tokens.next_token(diags);
+            let (elems, tok4) = self.parse_comma_list(tok3, diags, Self::parse_index_elem)?;
+            let tok5 = self.expect_token(tok4, TokenKind::RParen, diags)?;
+            let (predicate, tok6) = self.parse_opt_where(tok5, diags)?;
+            (Some(ConflictTarget::Index { elems, predicate }), tok6)
+        } else if tok2.is_keyword(&Symbol::KEYWORD_on) {
+            let tok3 = self.tokens.next_token(diags);
+            let tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_constraint, diags)?;
+            let (name, tok5) = self.parse_col_id(tok4, diags)?;
+            (Some(ConflictTarget::Constraint(name)), tok5)
+        } else {
+            (None, tok2)
+        };
+        let tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_do, diags)?;
+        let (action, tok5) = match tok4.keyword() {
+            Some(&Symbol::KEYWORD_nothing) => {
+                (ConflictAction::Nothing, self.tokens.next_token(diags))
+            }
+            Some(&Symbol::KEYWORD_update) => {
+                let tok5 = self.tokens.next_token(diags);
+                let tok6 = self.expect_keyword(tok5, Symbol::KEYWORD_set, diags)?;
+                let (set, tok7) = self.parse_comma_list(tok6, diags, Self::parse_set_clause)?;
+                let (where_clause, tok8) = self.parse_opt_where(tok7, diags)?;
+                (ConflictAction::Update { set, where_clause }, tok8)
+            }
+            _ => {
+                self.syntax_error(&tok4, diags);
+                return Err(tok4);
+            }
+        };
+        let on_conflict = OnConflictNode {
+            target,
+            action,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((on_conflict, tok5))
+    }
+
+    /// Parses a column or an expression with options in an index definition (`index_elem`).
+    fn parse_index_elem(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<IndexElemNode> {
+        let start = tok0.range.start;
+        let (key, tok1) = if tok0.kind == TokenKind::LParen {
+            let tok1 = self.tokens.next_token(diags);
+            let (expr, tok2) = self.parse_expr(tok1, diags)?;
+            let tok3 = self.expect_token(tok2, TokenKind::RParen, diags)?;
+            (IndexKey::Expr(expr), tok3)
+        } else if self.starts_func_expr_common_subexpr(&tok0, diags) {
+            let (expr, tok1) = self.parse_func_expr_common_subexpr(tok0, diags)?;
+            (IndexKey::Expr(expr), tok1)
+        } else if matches!(tok0.kind, TokenKind::Identifier { .. })
+            && matches!(
+                self.tokens.peek_token(diags).kind,
+                TokenKind::LParen | TokenKind::Dot
+            )
+        {
+            let (expr, tok1) = self.parse_func_expr_windowless(tok0, diags)?;
+            (IndexKey::Expr(expr), tok1)
+        } else {
+            let (name, tok1) = self.parse_col_id(tok0, diags)?;
+            (IndexKey::Column(name), tok1)
+        };
+        let (collation, tok2) = if tok1.is_keyword(&Symbol::KEYWORD_collate) {
+            let tok2 = self.tokens.next_token(diags);
+            self.parse_any_name(tok2, diags)?
+        } else {
+            (Vec::new(), tok1)
+        };
+        let (opclass, tok3) = match &tok2.kind {
+            TokenKind::Identifier { name, quoted } if *quoted || name.is_col_id() => {
+                self.parse_any_name(tok2, diags)?
+            }
+            _ => (Vec::new(), tok2),
+        };
+        let (order, tok4) = match tok3.keyword() {
+            Some(&Symbol::KEYWORD_asc) => (SortOrder::Asc, self.tokens.next_token(diags)),
+            Some(&Symbol::KEYWORD_desc) => (SortOrder::Desc, self.tokens.next_token(diags)),
+            _ => (SortOrder::Default, tok3),
+        };
+        let (nulls, tok5) = self.parse_opt_nulls_order(tok4, diags);
+        let elem = IndexElemNode {
+            key,
+            collation,
+            opclass,
+            order,
+            nulls,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((elem, tok5))
+    }
+
+    /// Parses an assignment in `SET` (`set_clause`).
+    fn parse_set_clause(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<SetClauseNode> {
+        let start = tok0.range.start;
+        let multiple = tok0.kind == TokenKind::LParen;
+        let (targets, tok1) = if multiple {
+            let tok1 = self.tokens.next_token(diags);
+            let (targets, tok2) = self.parse_comma_list(tok1, diags, Self::parse_assign_target)?;
+            let tok3 = self.expect_token(tok2, TokenKind::RParen, diags)?;
+            (targets, tok3)
+        } else {
+            let (target, tok1) = self.parse_assign_target(tok0, diags)?;
+            (vec![target], tok1)
+        };
+        let tok2 = self.expect_token(tok1, TokenKind::Eq, diags)?;
+        let (value, tok3) = self.parse_expr(tok2, diags)?;
+        let set = SetClauseNode {
+            targets,
+            multiple,
+            value,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((set, tok3))
+    }
+
+    /// Parses a column to assign to with optional subscripts and fields
+    /// (`insert_column_item` or `set_target`).
+    fn parse_assign_target(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<AssignTargetNode> {
+        let start = tok0.range.start;
+        let (name, tok1) = self.parse_col_id(tok0, diags)?;
+        let (indirection, tok2) = self.parse_opt_indirection_items(tok1, diags)?;
+        let target = AssignTargetNode {
+            name,
+            indirection,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((target, tok2))
+    }
+
+    /// Parses `WHERE condition`, if any.
+    fn parse_opt_where(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Option<ExprNode>> {
+        if !tok0.is_keyword(&Symbol::KEYWORD_where) {
+            return Ok((None, tok0));
+        }
+        let tok1 = self.tokens.next_token(diags);
+        let (condition, tok2) = self.parse_expr(tok1, diags)?;
+        Ok((Some(condition), tok2))
+    }
+
+    /// Parses `RETURNING target, ...`, if any (`returning_clause`).
+    fn parse_opt_returning(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<TargetNode>> {
+        if !tok0.is_keyword(&Symbol::KEYWORD_returning) {
+            return Ok((Vec::new(), tok0));
+        }
+        let tok1 = self.tokens.next_token(diags);
+        self.parse_comma_list(tok1, diags, Self::parse_target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::StmtKind,
+        parser::{
+            parse_stmt,
+            sexp::{parse_stmt_errors, parse_stmt_sexp, syntax_error, unexpected_eof},
+        },
+        pos::pos,
+    };
+
+    #[test]
+    fn test_insert() {
+        assert_eq!(
+            parse_stmt_sexp("insert into s.t as x (a, b[1], c.d) values (1, default), (2, 3)"),
+            "(insert (as s.t x) (columns a (indirection b [1]) (indirection c .d)) \
+             (values (1 default) (2 3)))"
+        );
+        assert_eq!(
+            parse_stmt_sexp("insert into t select * from u order by a"),
+            "(insert t (select (targets *) (from u) (order-by a)))"
+        );
+        assert_eq!(
+            parse_stmt_sexp("insert into t default values"),
+            "(insert t default-values)"
+        );
+        assert_eq!(
+            parse_stmt_sexp("insert into t (a) overriding system value values (1)"),
+            "(insert t (columns a) overriding-system (values (1)))"
+        );
+        assert_eq!(
+            parse_stmt_sexp("insert into t overriding user value select 1"),
+            "(insert t overriding-user (select (targets 1)))"
+        );
+        let src = "insert into t (a) default values";
+        assert_eq!(parse_stmt_errors(src), syntax_error(src, "default", 0));
+        let src = "insert into t overriding value select 1";
+        assert_eq!(parse_stmt_errors(src), syntax_error(src, "value", 0));
+    }
+
+    #[test]
+    fn test_insert_parenthesized_query() {
+        // `(` starts a query rather than the column list if followed by one.
+        assert_eq!(
+            parse_stmt_sexp("insert into t (select 1) union (select 2)"),
+            "(insert t (union (select (targets 1)) (select (targets 2))))"
+        );
+        assert_eq!(
+            parse_stmt_sexp("insert into t ((values (1)))"),
+            "(insert t (values (1)))"
+        );
+    }
+
+    #[test]
+    fn test_on_conflict() {
+        assert_eq!(
+            parse_stmt_sexp("insert into t values (1) on conflict do nothing"),
+            "(insert t (values (1)) (on-conflict do-nothing))"
+        );
+        assert_eq!(
+            parse_stmt_sexp(
+                "insert into t values (1) on conflict (a, lower(b) collate \"C\" text_ops, \
+                 (c + 1) desc nulls last) where d do update set a = excluded.a, \
+                 (b, c) = (select 1, 2), d[1] = default where t.a < 0"
+            ),
+            "(insert t (values (1)) (on-conflict (infer a ((call lower b) (collate C) text_ops) \
+             ((+ c 1) desc nulls-last) (where d)) (do-update (= a excluded.a) \
+             (= (b c) (subquery (select (targets 1 2)))) (= (indirection d [1]) default) \
+             (where (< t.a 0)))))"
+        );
+        assert_eq!(
+            parse_stmt_sexp("insert into t values (1) on conflict on constraint t_pkey do nothing"),
+            "(insert t (values (1)) (on-conflict (on-constraint t_pkey) do-nothing))"
+        );
+        assert_eq!(
+            parse_stmt_sexp("insert into t values (1) on conflict (coalesce(a, 0)) do nothing"),
+            "(insert t (values (1)) (on-conflict (infer (coalesce a 0)) do-nothing))"
+        );
+        let src = "insert into t values (1) on conflict do";
+        assert_eq!(parse_stmt_errors(src), unexpected_eof(src));
+        let src = "insert into t values (1) on conflict (a) do update set a";
+        assert_eq!(parse_stmt_errors(src), unexpected_eof(src));
+    }
+
+    #[test]
+    fn test_returning() {
+        assert_eq!(
+            parse_stmt_sexp("insert into t default values returning *, a + 1 as b, c d"),
+            "(insert t default-values (returning * (as (+ a 1) b) (as c d)))"
+        );
+        let src = "insert into t default values returning";
+        assert_eq!(parse_stmt_errors(src), unexpected_eof(src));
+    }
+
+    #[test]
+    fn test_insert_with() {
+        assert_eq!(
+            parse_stmt_sexp(
+                "with a as (insert into t default values returning id) insert into u select id from a"
+            ),
+            "(insert (with (a (insert t default-values (returning id)))) u \
+             (select (targets id) (from a)))"
+        );
+        let src = "with a as (select 1) insert into t table a";
+        let stmt = parse_stmt(src).unwrap();
+        assert_eq!(stmt.range, pos(src, src, 0));
+        let StmtKind::Insert(insert) = stmt.kind else {
+            panic!("not an insert statement");
+        };
+        assert_eq!(
+            insert.with.unwrap().range,
+            pos(src, "with a as (select 1)", 0)
+        );
+    }
+}
@@ -0,0 +1,973 @@
+// SQL/JSON: `json_aggregate_func`, the `JSON_*` forms of `func_expr_common_subexpr`,
+// and `json_table` in gram.y.
+
+use crate::{
+    Symbol,
+    ast::{
+        ExprKind, ExprNode, JsonArgument, JsonArrayAgg, JsonArrayConstructor,
+        JsonArrayQueryConstructor, JsonBehavior, JsonBehaviorKind, JsonEncoding, JsonFormat,
+        JsonFuncExpr, JsonFuncOp, JsonKeyValue, JsonObjectAgg, JsonObjectConstructor, JsonOutput,
+        JsonQuotes, JsonTable, JsonTableColumn, JsonTableColumnKind, JsonTablePath, JsonValueExpr,
+        JsonWrapper, SelectNode,
+    },
+    diag::{CodeDiagnostic, CodeDiagnostics},
+    pos::CodeRange,
+    token::{Token, TokenKind},
+};
+
+use super::{
+    ParseResult, Parser,
+    expr::is_bare_subquery,
+    select::{continues_select, starts_select},
+    types::{is_sconst, system_type_name},
+};
+
+impl Parser<'_> {
+    /// Parses a SQL/JSON constructor, query function, or aggregate such as `JSON_OBJECT(...)`,
+    /// including `FILTER` and `OVER` after an aggregate.
+    pub(super) fn parse_json_func(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let keyword = tok0
+            .keyword()
+            .cloned()
+            .expect("SQL/JSON function name must be a keyword");
+        let tok1 = self.tokens.next_token(diags);
+        let tok2 = self.expect_token(tok1, TokenKind::LParen, diags)?;
+        let (kind, tok3) = match keyword {
+            Symbol::KEYWORD_json_object => self.parse_json_object_args(tok2, diags)?,
+            Symbol::KEYWORD_json_array => self.parse_json_array_args(tok2, diags)?,
+            Symbol::KEYWORD_json_objectagg => {
+                let (item, tok3) = self.parse_json_key_value(tok2, diags)?;
+                let (absent_on_null, tok4) = self.parse_opt_json_null_clause(tok3, false, diags)?;
+                let (unique_keys, tok5) = self.parse_opt_json_unique_keys(tok4, diags)?;
+                let (output, tok6) = self.parse_opt_json_returning(tok5, diags)?;
+                let agg = JsonObjectAgg {
+                    item,
+                    absent_on_null,
+                    unique_keys,
+                    output,
+                    filter: None,
+                    over: None,
+                };
+                (ExprKind::JsonObjectAgg(Box::new(agg)), tok6)
+            }
+            Symbol::KEYWORD_json_arrayagg => {
+                let (element, mut tok3) = self.parse_json_value_expr(tok2, diags)?;
+                let mut order_by = Vec::new();
+                if tok3.is_keyword(&Symbol::KEYWORD_order) {
+                    let tok4 = self.tokens.next_token(diags);
+                    (order_by, tok3) = self.parse_sort_clause(tok4, diags)?;
+                }
+                let (absent_on_null, tok4) = self.parse_opt_json_null_clause(tok3, true, diags)?;
+                let (output, tok5) = self.parse_opt_json_returning(tok4, diags)?;
+                let agg = JsonArrayAgg {
+                    element,
+                    order_by,
+                    absent_on_null,
+                    output,
+                    filter: None,
+                    over: None,
+                };
+                (ExprKind::JsonArrayAgg(Box::new(agg)), tok5)
+            }
+            Symbol::KEYWORD_json => {
+                let (value, tok3) = self.parse_json_value_expr(tok2, diags)?;
+                let (unique_keys, tok4) = self.parse_opt_json_unique_keys(tok3, diags)?;
+                let kind = ExprKind::JsonParse {
+                    value: Box::new(value),
+                    unique_keys,
+                };
+                (kind, tok4)
+            }
+            Symbol::KEYWORD_json_scalar => {
+                let (operand, tok3) = self.parse_expr(tok2, diags)?;
+                let kind = ExprKind::JsonScalar {
+                    operand: Box::new(operand),
+                };
+                (kind, tok3)
+            }
+            Symbol::KEYWORD_json_serialize => {
+                let (value, tok3) = self.parse_json_value_expr(tok2, diags)?;
+                let (output, tok4) = self.parse_opt_json_returning(tok3, diags)?;
+                let kind = ExprKind::JsonSerialize {
+                    value: Box::new(value),
+                    output: output.map(Box::new),
+                };
+                (kind, tok4)
+            }
+            Symbol::KEYWORD_json_exists => {
+                self.parse_json_func_args(JsonFuncOp::Exists, tok2, diags)?
+            }
+            Symbol::KEYWORD_json_query => {
+                self.parse_json_func_args(JsonFuncOp::Query, tok2, diags)?
+            }
+            Symbol::KEYWORD_json_value => {
+                self.parse_json_func_args(JsonFuncOp::Value, tok2, diags)?
+            }
+            _ => unreachable!("not a SQL/JSON function name: {}", &*keyword),
+        };
+        let tok4 = self.expect_token(tok3, TokenKind::RParen, diags)?;
+        let (kind, tok5) = match kind {
+            ExprKind::JsonObjectAgg(mut agg) => {
+                let ((filter, over), tok5) = self.parse_opt_filter_over(tok4, diags)?;
+                (agg.filter, agg.over) = (filter, over);
+                (ExprKind::JsonObjectAgg(agg), tok5)
+            }
+            ExprKind::JsonArrayAgg(mut agg) => {
+                let ((filter, over), tok5) = self.parse_opt_filter_over(tok4, diags)?;
+                (agg.filter, agg.over) = (filter, over);
+                (ExprKind::JsonArrayAgg(agg), tok5)
+            }
+            kind => (kind, tok4),
+        };
+        let expr = ExprNode {
+            kind,
+            range: CodeRange {
+                start: tok0.range.start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((expr, tok5))
+    }
+
+    /// Parses the arguments of `JSON_OBJECT`, which are key-value pairs or ordinary arguments.
+    fn parse_json_object_args(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprKind> {
+        let name = system_type_name("json_object");
+        if tok0.kind == TokenKind::RParen || tok0.is_keyword(&Symbol::KEYWORD_returning) {
+            let (output, tok1) = self.parse_opt_json_returning(tok0, diags)?;
+            let constructor = JsonObjectConstructor {
+                items: Vec::new(),
+                absent_on_null: false,
+                unique_keys: false,
+                output,
+            };
+            return Ok((ExprKind::JsonObject(Box::new(constructor)), tok1));
+        }
+        if self.starts_named_arg(&tok0, diags) {
+            let (arg, tok1) = self.parse_func_arg(tok0, diags)?;
+            return self.parse_plain_special_func_args(name, vec![arg], tok1, diags);
+        }
+        let (key, tok1) = self.parse_json_key(tok0, diags)?;
+        if matches!(tok1.kind, TokenKind::Comma | TokenKind::RParen) {
+            return self.parse_plain_special_func_args(name, vec![key], tok1, diags);
+        }
+        let (first, mut tok2) = self.parse_json_key_value_rest(key, tok1, diags)?;
+        let mut items = vec![first];
+        while tok2.kind == TokenKind::Comma {
+            let tok3 = self.tokens.next_token(diags);
+            let (item, tok4) = self.parse_json_key_value(tok3, diags)?;
+            items.push(item);
+            tok2 = tok4;
+        }
+        let (absent_on_null, tok3) = self.parse_opt_json_null_clause(tok2, false, diags)?;
+        let (unique_keys, tok4) = self.parse_opt_json_unique_keys(tok3, diags)?;
+        let (output, tok5) = self.parse_opt_json_returning(tok4, diags)?;
+        let constructor = JsonObjectConstructor {
+            items,
+            absent_on_null,
+            unique_keys,
+            output,
+        };
+        Ok((ExprKind::JsonObject(Box::new(constructor)), tok5))
+    }
+
+    /// Parses the arguments of `JSON_ARRAY`, which are values or a query.
+    fn parse_json_array_args(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprKind> {
+        if tok0.kind == TokenKind::RParen || tok0.is_keyword(&Symbol::KEYWORD_returning) {
+            let (output, tok1) = self.parse_opt_json_returning(tok0, diags)?;
+            let constructor = JsonArrayConstructor {
+                elements: Vec::new(),
+                absent_on_null: true,
+                output,
+            };
+            return Ok((ExprKind::JsonArray(Box::new(constructor)), tok1));
+        }
+        if starts_select(&tok0) {
+            let (query, tok1) = self.parse_select(tok0, diags)?;
+            return self.parse_json_array_query_rest(query, tok1, diags);
+        }
+        let (first, tok1) = self.parse_json_value_expr(tok0, diags)?;
+        // A parenthesized query followed by `UNION` and the like is a query, as in `SELECT`.
+        if first.format.is_none() && is_bare_subquery(&first.expr) && continues_select(&tok1) {
+            let ExprKind::ScalarSubquery { query } = first.expr.kind else {
+                unreachable!("not a subquery");
+            };
+            let (query, tok2) =
+                self.parse_select_continued(query.range.start, None, *query, tok1, diags)?;
+            return self.parse_json_array_query_rest(query, tok2, diags);
+        }
+        let mut elements = vec![first];
+        let mut tok2 = tok1;
+        while tok2.kind == TokenKind::Comma {
+            let tok3 = self.tokens.next_token(diags);
+            let (element, tok4) = self.parse_json_value_expr(tok3, diags)?;
+            elements.push(element);
+            tok2 = tok4;
+        }
+        let (absent_on_null, tok3) = self.parse_opt_json_null_clause(tok2, true, diags)?;
+        let (output, tok4) = self.parse_opt_json_returning(tok3, diags)?;
+        let constructor = JsonArrayConstructor {
+            elements,
+            absent_on_null,
+            output,
+        };
+        Ok((ExprKind::JsonArray(Box::new(constructor)), tok4))
+    }
+
+    /// Parses `[FORMAT JSON] [RETURNING ...]` after the query in `JSON_ARRAY`.
+    fn parse_json_array_query_rest(
+        &mut self,
+        query: SelectNode,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprKind> {
+        let (format, tok1) = self.parse_opt_json_format(tok0, diags)?;
+        let (output, tok2) = self.parse_opt_json_returning(tok1, diags)?;
+        let constructor = JsonArrayQueryConstructor {
+            query,
+            format,
+            output,
+        };
+        Ok((ExprKind::JsonArrayQuery(Box::new(constructor)), tok2))
+    }
+
+    /// Parses the arguments of `JSON_EXISTS`, `JSON_QUERY`, or `JSON_VALUE`.
+    fn parse_json_func_args(
+        &mut self,
+        op: JsonFuncOp,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprKind> {
+        let (context, tok1) = self.parse_json_value_expr(tok0, diags)?;
+        let tok2 = self.expect_token(tok1, TokenKind::Comma, diags)?;
+        let (path, tok3) = self.parse_expr(tok2, diags)?;
+        let (passing, tok4) = self.parse_opt_json_passing(tok3, diags)?;
+        let (output, tok5) = if op == JsonFuncOp::Exists {
+            (None, tok4)
+        } else {
+            self.parse_opt_json_returning(tok4, diags)?
+        };
+        let (wrapper, quotes, tok6) = if op == JsonFuncOp::Query {
+            let (wrapper, tok6) = self.parse_opt_json_wrapper(tok5, diags)?;
+            let (quotes, tok7) = self.parse_opt_json_quotes(tok6, diags)?;
+            (wrapper, quotes, tok7)
+        } else {
+            (JsonWrapper::Unspecified, JsonQuotes::Unspecified, tok5)
+        };
+        let allow_on_empty = op != JsonFuncOp::Exists;
+        let ((on_empty, on_error), tok7) =
+            self.parse_opt_json_behaviors(tok6, allow_on_empty, diags)?;
+        let func = JsonFuncExpr {
+            op,
+            context,
+            path,
+            passing,
+            output,
+            wrapper,
+            quotes,
+            on_empty,
+            on_error,
+        };
+        Ok((ExprKind::JsonFunc(Box::new(func)), tok7))
+    }
+
+    /// Parses `key : value` or `key VALUE value` (`json_name_and_value`).
+    fn parse_json_key_value(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<JsonKeyValue> {
+        let (key, tok1) = self.parse_json_key(tok0, diags)?;
+        self.parse_json_key_value_rest(key, tok1, diags)
+    }
+
+    /// Parses the key in `json_name_and_value`, which must be a primary expression (`c_expr`)
+    /// if followed by `VALUE`.
+    fn parse_json_key(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let parenthesized = tok0.kind == TokenKind::LParen;
+        let (key, tok1) = self.parse_prefix_expr(tok0, false, diags)?;
+        let is_primary =
+            parenthesized || !matches!(key.kind, ExprKind::UnaryOp { .. } | ExprKind::Not { .. });
+        if is_primary && tok1.is_keyword(&Symbol::KEYWORD_value) {
+            return Ok((key, tok1));
+        }
+        let (key, tok2) = self.parse_expr_continued(key, tok1, diags)?;
+        if tok2.is_keyword(&Symbol::KEYWORD_value) {
+            self.syntax_error(&tok2, diags);
+            return Err(tok2);
+        }
+        Ok((key, tok2))
+    }
+
+    /// Parses `: value` or `VALUE value` after the key in `json_name_and_value`.
+    fn parse_json_key_value_rest(
+        &mut self,
+        key: ExprNode,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<JsonKeyValue> {
+        if !(tok0.kind == TokenKind::Colon || tok0.is_keyword(&Symbol::KEYWORD_value)) {
+            self.syntax_error(&tok0, diags);
+            return Err(tok0);
+        }
+        let tok1 = self.tokens.next_token(diags);
+        let (value, tok2) = self.parse_json_value_expr(tok1, diags)?;
+        Ok((JsonKeyValue { key, value }, tok2))
+    }
+
+    /// Parses an expression with an optional `FORMAT JSON` (`json_value_expr`).
+    fn parse_json_value_expr(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<JsonValueExpr> {
+        let (expr, tok1) = self.parse_expr(tok0, diags)?;
+        let (format, tok2) = self.parse_opt_json_format(tok1, diags)?;
+        Ok((JsonValueExpr { expr, format }, tok2))
+    }
+
+    /// Parses `FORMAT JSON [ENCODING name]`, if any (`json_format_clause_opt`).
+    fn parse_opt_json_format(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Option<JsonFormat>> {
+        if tok0.kind != TokenKind::FormatLa {
+            return Ok((None, tok0));
+        }
+        let tok1 = self.tokens.next_token(diags);
+        let mut tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_json, diags)?;
+        let mut encoding = None;
+        if tok2.is_keyword(&Symbol::KEYWORD_encoding) {
+            let tok3 = self.tokens.next_token(diags);
+            let name_range = tok3.range;
+            let (name, tok4) = self.parse_col_id(tok3, diags)?;
+            encoding = match name.to_lowercase().as_str() {
+                "utf8" => Some(JsonEncoding::Utf8),
+                "utf16" => Some(JsonEncoding::Utf16),
+                "utf32" => Some(JsonEncoding::Utf32),
+                _ => {
+                    diags.add(CodeDiagnostic::UnrecognizedJsonEncoding {
+                        range: name_range,
+                        name: name.to_string(),
+                    });
+                    None
+                }
+            };
+            tok2 = tok4;
+        }
+        let format = JsonFormat {
+            encoding,
+            range: CodeRange {
+                start: tok0.range.start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((Some(format), tok2))
+    }
+
+    /// Parses `RETURNING type [FORMAT JSON]`, if any (`json_returning_clause_opt`).
+    fn parse_opt_json_returning(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Option<JsonOutput>> {
+        if !tok0.is_keyword(&Symbol::KEYWORD_returning) {
+            return Ok((None, tok0));
+        }
+        let tok1 = self.tokens.next_token(diags);
+        let (type_name, tok2) = self.parse_type_name(tok1, diags)?;
+        let (format, tok3) = self.parse_opt_json_format(tok2, diags)?;
+        Ok((Some(JsonOutput { type_name, format }), tok3))
+    }
+
+    /// Parses `NULL ON NULL` or `ABSENT ON NULL`, returning whether null values are absent,
+    /// which is `default` if omitted.
+    fn parse_opt_json_null_clause(
+        &mut self,
+        tok0: Token,
+        default: bool,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<bool> {
+        let absent_on_null = match tok0.keyword() {
+            Some(&Symbol::KEYWORD_null) => false,
+            Some(&Symbol::KEYWORD_absent) => true,
+            _ => return Ok((default, tok0)),
+        };
+        let tok1 = self.tokens.next_token(diags);
+        let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_on, diags)?;
+        let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_null, diags)?;
+        Ok((absent_on_null, tok3))
+    }
+
+    /// Parses `WITH UNIQUE [KEYS]` or `WITHOUT UNIQUE [KEYS]`, returning whether unique keys
+    /// are required (`json_key_uniqueness_constraint_opt`).
+    pub(super) fn parse_opt_json_unique_keys(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<bool> {
+        let unique_keys = match tok0.keyword() {
+            Some(&Symbol::KEYWORD_with) => true,
+            Some(&Symbol::KEYWORD_without) => false,
+            _ => return Ok((false, tok0)),
+        };
+        let tok1 = self.tokens.next_token(diags);
+        let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_unique, diags)?;
+        let tok3 = if tok2.is_keyword(&Symbol::KEYWORD_keys) {
+            self.tokens.next_token(diags)
+        } else {
+            tok2
+        };
+        Ok((unique_keys, tok3))
+    }
+
+    /// Parses `PASSING value AS name, ...`, if any (`json_passing_clause_opt`).
+    fn parse_opt_json_passing(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<JsonArgument>> {
+        if !tok0.is_keyword(&Symbol::KEYWORD_passing) {
+            return Ok((Vec::new(), tok0));
+        }
+        let tok1 = self.tokens.next_token(diags);
+        self.parse_comma_list(tok1, diags, |parser, tok0, diags| {
+            let (value, tok1) = parser.parse_json_value_expr(tok0, diags)?;
+            let tok2 = parser.expect_keyword(tok1, Symbol::KEYWORD_as, diags)?;
+            let (name, tok3) = parser.parse_col_label(tok2, diags)?;
+            Ok((JsonArgument { value, name }, tok3))
+        })
+    }
+
+    /// Parses `WITH [CONDITIONAL | UNCONDITIONAL] [ARRAY] WRAPPER` or
+    /// `WITHOUT [ARRAY] WRAPPER`, if any (`json_wrapper_behavior`).
+    fn parse_opt_json_wrapper(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<JsonWrapper> {
+        let (wrapper, tok1) = match tok0.keyword() {
+            Some(&Symbol::KEYWORD_without) => (JsonWrapper::None, self.tokens.next_token(diags)),
+            Some(&Symbol::KEYWORD_with) => {
+                let tok1 = self.tokens.next_token(diags);
+                match tok1.keyword() {
+                    Some(&Symbol::KEYWORD_conditional) => {
+                        (JsonWrapper::Conditional, self.tokens.next_token(diags))
+                    }
+                    Some(&Symbol::KEYWORD_unconditional) => {
+                        (JsonWrapper::Unconditional, self.tokens.next_token(diags))
+                    }
+                    _ => (JsonWrapper::Unconditional, tok1),
+                }
+            }
+            _ => return Ok((JsonWrapper::Unspecified, tok0)),
+        };
+        let tok2 = if tok1.is_keyword(&Symbol::KEYWORD_array) {
+            self.tokens.next_token(diags)
+        } else {
+            tok1
+        };
+        let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_wrapper, diags)?;
+        Ok((wrapper, tok3))
+    }
+
+    /// Parses `{KEEP | OMIT} QUOTES [ON SCALAR STRING]`, if any (`json_quotes_clause_opt`).
+    fn parse_opt_json_quotes(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<JsonQuotes> {
+        let quotes = match tok0.keyword() {
+            Some(&Symbol::KEYWORD_keep) => JsonQuotes::Keep,
+            Some(&Symbol::KEYWORD_omit) => JsonQuotes::Omit,
+            _ => return Ok((JsonQuotes::Unspecified, tok0)),
+        };
+        let tok1 = self.tokens.next_token(diags);
+        let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_quotes, diags)?;
+        if !tok2.is_keyword(&Symbol::KEYWORD_on) {
+            return Ok((quotes, tok2));
+        }
+        let tok3 = self.tokens.next_token(diags);
+        let tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_scalar, diags)?;
+        let tok5 = self.expect_keyword(tok4, Symbol::KEYWORD_string, diags)?;
+        Ok((quotes, tok5))
+    }
+
+    /// Parses `behavior ON EMPTY`, `behavior ON ERROR`, or both in this order, if any
+    /// (`json_behavior_clause_opt`). Only `ON ERROR` is allowed unless `allow_on_empty` is true.
+    fn parse_opt_json_behaviors(
+        &mut self,
+        tok0: Token,
+        allow_on_empty: bool,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<(Option<JsonBehavior>, Option<JsonBehavior>)> {
+        if !starts_json_behavior(&tok0) {
+            return Ok(((None, None), tok0));
+        }
+        let (first, tok1) = self.parse_json_behavior(tok0, diags)?;
+        let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_on, diags)?;
+        if !(allow_on_empty && tok2.is_keyword(&Symbol::KEYWORD_empty)) {
+            let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_error, diags)?;
+            return Ok(((None, Some(first)), tok3));
+        }
+        let tok3 = self.tokens.next_token(diags);
+        if !starts_json_behavior(&tok3) {
+            return Ok(((Some(first), None), tok3));
+        }
+        let (second, tok4) = self.parse_json_behavior(tok3, diags)?;
+        let tok5 = self.expect_keyword(tok4, Symbol::KEYWORD_on, diags)?;
+        let tok6 = self.expect_keyword(tok5, Symbol::KEYWORD_error, diags)?;
+        Ok(((Some(first), Some(second)), tok6))
+    }
+
+    /// Parses a behavior before `ON EMPTY` or `ON ERROR` (`json_behavior`).
+    fn parse_json_behavior(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<JsonBehavior> {
+        let start = tok0.range.start;
+        let (kind, tok1) = match tok0.keyword() {
+            Some(&Symbol::KEYWORD_default) => {
+                let tok1 = self.tokens.next_token(diags);
+                let (expr, tok2) = self.parse_expr(tok1, diags)?;
+                (JsonBehaviorKind::Default(Box::new(expr)), tok2)
+            }
+            Some(&Symbol::KEYWORD_empty) => {
+                let tok1 = self.tokens.next_token(diags);
+                match tok1.keyword() {
+                    Some(&Symbol::KEYWORD_array) => {
+                        (JsonBehaviorKind::EmptyArray, self.tokens.next_token(diags))
+                    }
+                    Some(&Symbol::KEYWORD_object) => {
+                        (JsonBehaviorKind::EmptyObject, self.tokens.next_token(diags))
+                    }
+                    // `EMPTY` alone is a nonstandard shorthand for `EMPTY ARRAY`.
+                    _ => (JsonBehaviorKind::EmptyArray, tok1),
+                }
+            }
+            Some(keyword) => {
+                let kind = match *keyword {
+                    Symbol::KEYWORD_null => JsonBehaviorKind::Null,
+                    Symbol::KEYWORD_error => JsonBehaviorKind::Error,
+                    Symbol::KEYWORD_true => JsonBehaviorKind::True,
+                    Symbol::KEYWORD_false => JsonBehaviorKind::False,
+                    Symbol::KEYWORD_unknown => JsonBehaviorKind::Unknown,
+                    _ => {
+                        self.syntax_error(&tok0, diags);
+                        return Err(tok0);
+                    }
+                };
+                (kind, self.tokens.next_token(diags))
+            }
+            None => {
+                self.syntax_error(&tok0, diags);
+                return Err(tok0);
+            }
+        };
+        let behavior = JsonBehavior {
+            kind,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((behavior, tok1))
+    }
+
+    /// Parses `JSON_TABLE(...)` in `FROM`, without the alias.
+    pub(super) fn parse_json_table(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<JsonTable> {
+        let tok1 = self.expect_keyword(tok0, Symbol::KEYWORD_json_table, diags)?;
+        let tok2 = self.expect_token(tok1, TokenKind::LParen, diags)?;
+        let (context, tok3) = self.parse_json_value_expr(tok2, diags)?;
+        let tok4 = self.expect_token(tok3, TokenKind::Comma, diags)?;
+        // The path is parsed as an expression, but only a string literal is supported.
+        let (path_expr, tok5) = self.parse_expr(tok4, diags)?;
+        let path_range = path_expr.range;
+        let path = match path_expr.kind {
+            ExprKind::StringLiteral { value } => value,
+            _ => {
+                diags.add(CodeDiagnostic::JsonTablePathNotString { range: path_range });
+                String::new()
+            }
+        };
+        let (name, tok6) = self.parse_opt_json_path_name(tok5, diags)?;
+        let path = JsonTablePath {
+            path,
+            name,
+            range: CodeRange {
+                start: path_range.start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        let (passing, tok7) = self.parse_opt_json_passing(tok6, diags)?;
+        let (columns, tok8) = self.parse_json_table_columns(tok7, diags)?;
+        let ((_, on_error), tok9) = self.parse_opt_json_behaviors(tok8, false, diags)?;
+        let tok10 = self.expect_token(tok9, TokenKind::RParen, diags)?;
+        let table = JsonTable {
+            context,
+            path,
+            passing,
+            columns,
+            on_error,
+        };
+        Ok((table, tok10))
+    }
+
+    /// Parses `AS name` after a path in `JSON_TABLE`, if any.
+    fn parse_opt_json_path_name(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Option<Symbol>> {
+        if !tok0.is_keyword(&Symbol::KEYWORD_as) {
+            return Ok((None, tok0));
+        }
+        let tok1 = self.tokens.next_token(diags);
+        let (name, tok2) = self.parse_col_id(tok1, diags)?;
+        Ok((Some(name), tok2))
+    }
+
+    /// Parses `COLUMNS (column, ...)` in `JSON_TABLE`.
+    fn parse_json_table_columns(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<JsonTableColumn>> {
+        let tok1 = self.expect_keyword(tok0, Symbol::KEYWORD_columns, diags)?;
+        let tok2 = self.expect_token(tok1, TokenKind::LParen, diags)?;
+        let (columns, tok3) = self.parse_comma_list(tok2, diags, Self::parse_json_table_column)?;
+        let tok4 = self.expect_token(tok3, TokenKind::RParen, diags)?;
+        Ok((columns, tok4))
+    }
+
+    /// Parses a column definition in `JSON_TABLE` (`json_table_column_definition`).
+    fn parse_json_table_column(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<JsonTableColumn> {
+        let start = tok0.range.start;
+        let (kind, tok1) = if tok0.is_keyword(&Symbol::KEYWORD_nested) && {
+            // `NESTED` is a column name unless followed by a path.
+            let next = self.tokens.peek_token(diags);
+            next.is_keyword(&Symbol::KEYWORD_path) || is_sconst(next)
+        } {
+            let mut tok1 = self.tokens.next_token(diags);
+            if tok1.is_keyword(&Symbol::KEYWORD_path) {
+                tok1 = self.tokens.next_token(diags);
+            }
+            let path_start = tok1.range.start;
+            let (path, tok2) = self.parse_json_path_string(tok1, diags)?;
+            let (name, tok3) = self.parse_opt_json_path_name(tok2, diags)?;
+            let path = JsonTablePath {
+                path,
+                name,
+                range: CodeRange {
+                    start: path_start,
+                    end: self.tokens.prev_end(),
+                },
+            };
+            let (columns, tok4) = self.parse_json_table_columns(tok3, diags)?;
+            (JsonTableColumnKind::Nested { path, columns }, tok4)
+        } else {
+            let (name, tok1) = self.parse_col_id(tok0, diags)?;
+            if tok1.is_keyword(&Symbol::KEYWORD_for) {
+                let tok2 = self.tokens.next_token(diags);
+                let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_ordinality, diags)?;
+                (JsonTableColumnKind::Ordinality { name }, tok3)
+            } else {
+                let (type_name, tok2) = self.parse_type_name(tok1, diags)?;
+                if tok2.is_keyword(&Symbol::KEYWORD_exists) {
+                    let tok3 = self.tokens.next_token(diags);
+                    let (path, tok4) = self.parse_opt_json_column_path(tok3, diags)?;
+                    let ((_, on_error), tok5) =
+                        self.parse_opt_json_behaviors(tok4, false, diags)?;
+                    let kind = JsonTableColumnKind::Exists {
+                        name,
+                        type_name,
+                        path,
+                        on_error,
+                    };
+                    (kind, tok5)
+                } else {
+                    let (format, tok3) = self.parse_opt_json_format(tok2, diags)?;
+                    let (path, tok4) = self.parse_opt_json_column_path(tok3, diags)?;
+                    let (wrapper, tok5) = self.parse_opt_json_wrapper(tok4, diags)?;
+                    let (quotes, tok6) = self.parse_opt_json_quotes(tok5, diags)?;
+                    let ((on_empty, on_error), tok7) =
+                        self.parse_opt_json_behaviors(tok6, true, diags)?;
+                    let kind = JsonTableColumnKind::Regular {
+                        name,
+                        type_name,
+                        format,
+                        path,
+                        wrapper,
+                        quotes,
+                        on_empty,
+                        on_error,
+                    };
+                    (kind, tok7)
+                }
+            }
+        };
+        let column = JsonTableColumn {
+            kind,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((column, tok1))
+    }
+
+    /// Parses `PATH path` of a column in `JSON_TABLE`, if any
+    /// (`json_table_column_path_clause_opt`).
+    fn parse_opt_json_column_path(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Option<String>> {
+        if !tok0.is_keyword(&Symbol::KEYWORD_path) {
+            return Ok((None, tok0));
+        }
+        let tok1 = self.tokens.next_token(diags);
+        let (path, tok2) = self.parse_json_path_string(tok1, diags)?;
+        Ok((Some(path), tok2))
+    }
+
+    /// Parses a string literal as a path in `JSON_TABLE` (`Sconst`).
+    fn parse_json_path_string(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<String> {
+        match tok0.kind {
+            TokenKind::String(value) | TokenKind::DollarString(value) => {
+                Ok((value, self.tokens.next_token(diags)))
+            }
+            _ => {
+                self.syntax_error(&tok0, diags);
+                Err(tok0)
+            }
+        }
+    }
+}
+
+/// Whether the token is a keyword starting a SQL/JSON function when followed by `(`.
+pub(super) fn starts_json_func(tok: &Token) -> bool {
+    matches!(
+        tok.keyword(),
+        Some(
+            &(Symbol::KEYWORD_json
+                | Symbol::KEYWORD_json_array
+                | Symbol::KEYWORD_json_arrayagg
+                | Symbol::KEYWORD_json_exists
+                | Symbol::KEYWORD_json_object
+                | Symbol::KEYWORD_json_objectagg
+                | Symbol::KEYWORD_json_query
+                | Symbol::KEYWORD_json_scalar
+                | Symbol::KEYWORD_json_serialize
+                | Symbol::KEYWORD_json_value)
+        )
+    )
+}
+
+/// Whether the token starts a behavior before `ON EMPTY` or `ON ERROR`.
+fn starts_json_behavior(tok: &Token) -> bool {
+    matches!(
+        tok.keyword(),
+        Some(
+            &(Symbol::KEYWORD_default
+                | Symbol::KEYWORD_empty
+                | Symbol::KEYWORD_error
+                | Symbol::KEYWORD_null
+                | Symbol::KEYWORD_true
+                | Symbol::KEYWORD_false
+                | Symbol::KEYWORD_unknown)
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parser::sexp::{parse_expr_errors, parse_expr_sexp, syntax_error},
+        pos::pos,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_json_object() {
+        assert_eq!(parse_expr_sexp("json_object()"), "(json-object)");
+        assert_eq!(
+            parse_expr_sexp("json_object('a' : 1, 'b' value b format json)"),
+            "(json-object (: 'a' 1) (: 'b' (format-json b)))"
+        );
+        assert_eq!(
+            parse_expr_sexp("json_object(k : v absent on null with unique keys returning jsonb)"),
+            "(json-object (: k v) absent-on-null unique (returning jsonb))"
+        );
+        assert_eq!(
+            parse_expr_sexp("json_object(returning text format json encoding utf8)"),
+            "(json-object (returning text format-json-utf8))"
+        );
+        // The key before `:` is a general expression, but the key before `VALUE` is not.
+        assert_eq!(
+            parse_expr_sexp("json_object(a || b : 1)"),
+            "(json-object (: (|| a b) 1))"
+        );
+        let src = "json_object(a || b value 1)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "value", 0));
+        let src = "json_object(-a value 1)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "value", 0));
+        assert_eq!(
+            parse_expr_sexp("json_object((-a) value 1)"),
+            "(json-object (: (- a) 1))"
+        );
+    }
+
+    #[test]
+    fn test_json_object_legacy() {
+        // `JSON_OBJECT` with ordinary arguments calls the function taking text arrays.
+        assert_eq!(
+            parse_expr_sexp("json_object(a)"),
+            "(call pg_catalog.json_object a)"
+        );
+        assert_eq!(
+            parse_expr_sexp("json_object(k, v)"),
+            "(call pg_catalog.json_object k v)"
+        );
+        assert_eq!(
+            parse_expr_sexp("json_object(keys => k, vals => v)"),
+            "(call pg_catalog.json_object (=> keys k) (=> vals v))"
+        );
+    }
+
+    #[test]
+    fn test_json_array() {
+        assert_eq!(parse_expr_sexp("json_array()"), "(json-array)");
+        assert_eq!(
+            parse_expr_sexp("json_array(1, a format json null on null returning jsonb)"),
+            "(json-array 1 (format-json a) null-on-null (returning jsonb))"
+        );
+        assert_eq!(
+            parse_expr_sexp("json_array(select a from t format json)"),
+            "(json-array (select (targets a) (from t)) format-json)"
+        );
+        assert_eq!(
+            parse_expr_sexp("json_array((select 1) union (select 2))"),
+            "(json-array (union (select (targets 1)) (select (targets 2))))"
+        );
+        assert_eq!(
+            parse_expr_sexp("json_array((select 1))"),
+            "(json-array (subquery (select (targets 1))))"
+        );
+    }
+
+    #[test]
+    fn test_json_aggregates() {
+        assert_eq!(
+            parse_expr_sexp(
+                "json_objectagg(k : v absent on null with unique) filter (where k > 0)"
+            ),
+            "(json-objectagg (: k v) absent-on-null unique (filter (> k 0)))"
+        );
+        assert_eq!(
+            parse_expr_sexp("json_arrayagg(a order by b desc null on null returning jsonb) over w"),
+            "(json-arrayagg a (order-by (desc b)) null-on-null (returning jsonb) (over (w)))"
+        );
+    }
+
+    #[test]
+    fn test_json_scalar_functions() {
+        assert_eq!(
+            parse_expr_sexp("json('{}' with unique keys)"),
+            "(json '{}' unique)"
+        );
+        assert_eq!(
+            parse_expr_sexp("json_scalar(1 + 2)"),
+            "(json-scalar (+ 1 2))"
+        );
+        assert_eq!(
+            parse_expr_sexp("json_serialize(a format json returning bytea)"),
+            "(json-serialize (format-json a) (returning bytea))"
+        );
+        // Without `(`, `JSON` is a type name or a column name.
+        assert_eq!(parse_expr_sexp("json '{}'"), "(cast '{}' pg_catalog.json)");
+        assert_eq!(parse_expr_sexp("json_value"), "json_value");
+    }
+
+    #[test]
+    fn test_json_query_functions() {
+        assert_eq!(
+            parse_expr_sexp("json_exists(a, '$.x' passing 1 as x, b as \"Y\" false on error)"),
+            "(json-exists a '$.x' (passing (1 x) (b Y)) (on-error false))"
+        );
+        assert_eq!(
+            parse_expr_sexp("json_value(a, '$' returning int default 0 on empty error on error)"),
+            "(json-value a '$' (returning pg_catalog.int4) (on-empty (default 0)) (on-error error))"
+        );
+        assert_eq!(
+            parse_expr_sexp(
+                "json_query(a, '$' with conditional array wrapper omit quotes on scalar string)"
+            ),
+            "(json-query a '$' with-conditional-wrapper omit-quotes)"
+        );
+        assert_eq!(
+            parse_expr_sexp(
+                "json_query(a, '$' with wrapper keep quotes empty on empty empty object on error)"
+            ),
+            "(json-query a '$' with-unconditional-wrapper keep-quotes (on-empty empty-array) \
+             (on-error empty-object))"
+        );
+        assert_eq!(
+            parse_expr_sexp("json_query(a, '$' without wrapper null on error)"),
+            "(json-query a '$' without-wrapper (on-error null))"
+        );
+        // `JSON_EXISTS` has no `RETURNING` or `ON EMPTY`, and only `JSON_QUERY` has a wrapper.
+        let src = "json_exists(a, '$' true on empty)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "empty", 0));
+        let src = "json_value(a, '$' with wrapper)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "with", 0));
+        let src = "json_query(a, '$' error)";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, ")", 0));
+    }
+
+    #[test]
+    fn test_json_encoding() {
+        assert_eq!(
+            parse_expr_sexp("json_serialize(a format json encoding UTF16)"),
+            "(json-serialize (format-json-utf16 a))"
+        );
+        let src = "json_serialize(a format json encoding latin1)";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::UnrecognizedJsonEncoding {
+                range: pos(src, "latin1", 0),
+                name: "latin1".to_string(),
+            }]
+        );
+    }
+}
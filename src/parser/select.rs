@@ -0,0 +1,1160 @@
+// Queries: `SelectStmt` and its clauses in gram.y.
+
+use crate::{
+    Symbol,
+    ast::{
+        DistinctClause, ExprKind, ExprNode, GroupByItem, GroupingSetKind, GroupingSetNode,
+        LockStrength, LockWaitPolicy, LockingNode, NullsOrder, OperatorName, SelectBody,
+        SelectNode, SetOp, SimpleSelect, SortByNode, SortOrder, TargetNode, WindowDefNode,
+        WithClause,
+    },
+    diag::{CodeDiagnostic, CodeDiagnostics},
+    pos::CodeRange,
+    token::{Token, TokenKind},
+};
+
+use super::{ParseResult, Parser, expr::symbol_op, with::starts_with_clause};
+
+impl Parser<'_> {
+    /// Parses a query (`SelectStmt`), which may be parenthesized.
+    pub(super) fn parse_select(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<SelectNode> {
+        let start = tok0.range.start;
+        let (with, tok1) = if starts_with_clause(&tok0) {
+            let (with, tok1) = self.parse_with_clause(tok0, diags)?;
+            (Some(with), tok1)
+        } else {
+            (None, tok0)
+        };
+        let (first, tok2) = self.parse_select_primary(tok1, diags)?;
+        self.parse_select_continued(start, with, first, tok2, diags)
+    }
+
+    /// Parses the rest of a query after its first operand: set operations, `ORDER BY`, and so on.
+    pub(super) fn parse_select_continued(
+        &mut self,
+        start: usize,
+        with: Option<WithClause>,
+        first: SelectNode,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<SelectNode> {
+        let (mut select, tok1) = self.parse_set_ops(first, SetOpPrec::Union, tok0, diags)?;
+        if let Some(with) = with {
+            if select.with.is_some() {
+                diags.add(CodeDiagnostic::MultipleClauses {
+                    range: with.range,
+                    clause: "WITH",
+                });
+            }
+            select.with = Some(with);
+        }
+        let tok2 = self.parse_select_options(&mut select, tok1, diags)?;
+        select.range = CodeRange {
+            start,
+            end: self.tokens.prev_end(),
+        };
+        Ok((select, tok2))
+    }
+
+    /// Parses set operations of at least the given precedence, following the left operand.
+    fn parse_set_ops(
+        &mut self,
+        mut left: SelectNode,
+        min_prec: SetOpPrec,
+        mut tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<SelectNode> {
+        loop {
+            let (op, prec) = match tok0.keyword() {
+                Some(&Symbol::KEYWORD_union) => (SetOp::Union, SetOpPrec::Union),
+                Some(&Symbol::KEYWORD_except) => (SetOp::Except, SetOpPrec::Union),
+                Some(&Symbol::KEYWORD_intersect) => (SetOp::Intersect, SetOpPrec::Intersect),
+                _ => return Ok((left, tok0)),
+            };
+            if prec < min_prec {
+                return Ok((left, tok0));
+            }
+            let tok1 = self.tokens.next_token(diags);
+            let (all, tok2) = match tok1.keyword() {
+                Some(&Symbol::KEYWORD_all) => (true, self.tokens.next_token(diags)),
+                Some(&Symbol::KEYWORD_distinct) => (false, self.tokens.next_token(diags)),
+                _ => (false, tok1),
+            };
+            let (right, tok3) = self.parse_select_primary(tok2, diags)?;
+            // The right operand takes operators binding tighter, so that the operators are
+            // left-associative.
+            let (right, tok4) = if prec == SetOpPrec::Union {
+                self.parse_set_ops(right, SetOpPrec::Intersect, tok3, diags)?
+            } else {
+                (right, tok3)
+            };
+            let range = CodeRange {
+                start: left.range.start,
+                end: self.tokens.prev_end(),
+            };
+            left = bare_select(
+                SelectBody::SetOp {
+                    op,
+                    all,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                },
+                range,
+            );
+            tok0 = tok4;
+        }
+    }
+
+    /// Parses an operand of set operations: a `SELECT`, `VALUES`, or `TABLE` without `ORDER BY`
+    /// and so on, or a parenthesized query.
+    pub(super) fn parse_select_primary(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<SelectNode> {
+        if tok0.kind == TokenKind::LParen {
+            return self.parse_select_with_parens(tok0, diags);
+        }
+        let start = tok0.range.start;
+        let (body, tok1) = match tok0.keyword() {
+            Some(&Symbol::KEYWORD_values) => {
+                let tok1 = self.tokens.next_token(diags);
+                let (rows, tok2) =
+                    self.parse_comma_list(tok1, diags, Self::parse_parenthesized_expr_list)?;
+                (SelectBody::Values { rows }, tok2)
+            }
+            Some(&Symbol::KEYWORD_table) => {
+                let tok1 = self.tokens.next_token(diags);
+                let ((name, inherit), tok2) = self.parse_relation_expr(tok1, diags)?;
+                (SelectBody::Table { name, inherit }, tok2)
+            }
+            _ => self.parse_simple_select(tok0, diags)?,
+        };
+        let range = CodeRange {
+            start,
+            end: self.tokens.prev_end(),
+        };
+        Ok((bare_select(body, range), tok1))
+    }
+
+    /// Parses a parenthesized query (`select_with_parens`).
+    pub(super) fn parse_select_with_parens(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<SelectNode> {
+        let start = tok0.range.start;
+        let tok1 = self.expect_token(tok0, TokenKind::LParen, diags)?;
+        let (select, tok2) = self.parse_select(tok1, diags)?;
+        if tok2.kind != TokenKind::RParen {
+            self.syntax_error(&tok2, diags);
+            return Err(tok2);
+        }
+        // Parentheses only affect the range.
+        let select = SelectNode {
+            range: CodeRange {
+                start,
+                end: tok2.range.end,
+            },
+            ..select
+        };
+        Ok((select, self.tokens.next_token(diags)))
+    }
+
+    /// Parses `ORDER BY`, `LIMIT`, `OFFSET`, `FETCH`, and locking clauses following a query body.
+    fn parse_select_options(
+        &mut self,
+        select: &mut SelectNode,
+        mut tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> Result<Token, Token> {
+        if tok0.is_keyword(&Symbol::KEYWORD_order) {
+            let tok1 = self.tokens.next_token(diags);
+            let (order_by, tok2) = self.parse_sort_clause(tok1, diags)?;
+            // The query may already have one inside parentheses.
+            if !select.order_by.is_empty() {
+                diags.add(CodeDiagnostic::MultipleClauses {
+                    range: tok0.range,
+                    clause: "ORDER BY",
+                });
+            }
+            select.order_by = order_by;
+            tok0 = tok2;
+        }
+        // Locking clauses can come either before or after the limit clauses.
+        let (locking, tok1) = self.parse_locking_clauses(tok0, diags)?;
+        let tok2 = self.parse_select_limit(select, tok1, diags)?;
+        let tok3 = if locking.is_empty() {
+            let (locking, tok3) = self.parse_locking_clauses(tok2, diags)?;
+            select.locking.extend(locking);
+            tok3
+        } else {
+            select.locking.extend(locking);
+            tok2
+        };
+        if select.with_ties
+            && let Some(locking) = select
+                .locking
+                .iter()
+                .find(|locking| locking.wait_policy == LockWaitPolicy::Skip)
+        {
+            diags.add(CodeDiagnostic::SkipLockedWithTies {
+                range: locking.range,
+            });
+        }
+        Ok(tok3)
+    }
+
+    /// Parses `BY sortby_list` after `ORDER`.
+    pub(super) fn parse_sort_clause(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<SortByNode>> {
+        let tok1 = self.expect_keyword(tok0, Symbol::KEYWORD_by, diags)?;
+        self.parse_comma_list(tok1, diags, Self::parse_sortby)
+    }
+
+    fn parse_sortby(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<SortByNode> {
+        let start = tok0.range.start;
+        let (expr, mut tok1) = self.parse_expr(tok0, diags)?;
+        let order = match tok1.keyword() {
+            Some(&Symbol::KEYWORD_asc) => {
+                tok1 = self.tokens.next_token(diags);
+                SortOrder::Asc
+            }
+            Some(&Symbol::KEYWORD_desc) => {
+                tok1 = self.tokens.next_token(diags);
+                SortOrder::Desc
+            }
+            Some(&Symbol::KEYWORD_using) => {
+                let tok2 = self.tokens.next_token(diags);
+                let (op, tok3) = self.parse_qual_all_op(tok2, diags)?;
+                tok1 = tok3;
+                SortOrder::Using { op }
+            }
+            _ => SortOrder::Default,
+        };
+        let (nulls, tok2) = self.parse_opt_nulls_order(tok1, diags);
+        let sortby = SortByNode {
+            expr,
+            order,
+            nulls,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((sortby, tok2))
+    }
+
+    /// Parses `NULLS {FIRST | LAST}`, if any (`opt_nulls_order`).
+    pub(super) fn parse_opt_nulls_order(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> (NullsOrder, Token) {
+        // The token filter only produces `NullsLa` before `FIRST` or `LAST`.
+        if tok0.kind != TokenKind::NullsLa {
+            return (NullsOrder::Default, tok0);
+        }
+        let tok1 = self.tokens.next_token(diags);
+        let nulls = if tok1.is_keyword(&Symbol::KEYWORD_first) {
+            NullsOrder::First
+        } else {
+            NullsOrder::Last
+        };
+        (nulls, self.tokens.next_token(diags))
+    }
+
+    /// Parses `LIMIT`, `OFFSET`, and `FETCH` clauses, if any.
+    fn parse_select_limit(
+        &mut self,
+        select: &mut SelectNode,
+        mut tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> Result<Token, Token> {
+        let mut has_limit = false;
+        let mut has_offset = false;
+        loop {
+            match tok0.keyword() {
+                Some(&Symbol::KEYWORD_limit) if !has_limit => {
+                    has_limit = true;
+                    // The query may already have one inside parentheses.
+                    if select.limit.is_some() {
+                        diags.add(CodeDiagnostic::MultipleClauses {
+                            range: tok0.range,
+                            clause: "LIMIT",
+                        });
+                    }
+                    let tok1 = self.tokens.next_token(diags);
+                    let (limit, tok2) = if tok1.is_keyword(&Symbol::KEYWORD_all) {
+                        let limit = ExprNode {
+                            kind: ExprKind::NullLiteral,
+                            range: tok1.range,
+                        };
+                        (limit, self.tokens.next_token(diags))
+                    } else {
+                        self.parse_expr(tok1, diags)?
+                    };
+                    if tok2.kind == TokenKind::Comma {
+                        let tok3 = self.tokens.next_token(diags);
+                        let (_, tok4) = self.parse_expr(tok3, diags)?;
+                        diags.add(CodeDiagnostic::LimitCommaNotSupported { range: tok0.range });
+                        tok0 = tok4;
+                    } else {
+                        tok0 = tok2;
+                    }
+                    select.limit = Some(limit);
+                }
+                Some(&Symbol::KEYWORD_fetch) if !has_limit => {
+                    has_limit = true;
+                    if select.limit.is_some() {
+                        diags.add(CodeDiagnostic::MultipleClauses {
+                            range: tok0.range,
+                            clause: "LIMIT",
+                        });
+                    }
+                    let tok1 = self.tokens.next_token(diags);
+                    tok0 = self.parse_fetch_clause(select, tok1, diags)?;
+                }
+                Some(&Symbol::KEYWORD_offset) if !has_offset => {
+                    has_offset = true;
+                    if select.offset.is_some() {
+                        diags.add(CodeDiagnostic::MultipleClauses {
+                            range: tok0.range,
+                            clause: "OFFSET",
+                        });
+                    }
+                    let tok1 = self.tokens.next_token(diags);
+                    let (offset, mut tok2) = self.parse_expr(tok1, diags)?;
+                    if tok2.is_keyword(&Symbol::KEYWORD_row)
+                        || tok2.is_keyword(&Symbol::KEYWORD_rows)
+                    {
+                        tok2 = self.tokens.next_token(diags);
+                    }
+                    select.offset = Some(offset);
+                    tok0 = tok2;
+                }
+                _ => return Ok(tok0),
+            }
+        }
+    }
+
+    /// Parses `{FIRST|NEXT} [count] {ROW|ROWS} {ONLY|WITH TIES}` after `FETCH`.
+    fn parse_fetch_clause(
+        &mut self,
+        select: &mut SelectNode,
+        tok1: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> Result<Token, Token> {
+        if !(tok1.is_keyword(&Symbol::KEYWORD_first) || tok1.is_keyword(&Symbol::KEYWORD_next)) {
+            self.syntax_error(&tok1, diags);
+            return Err(tok1);
+        }
+        let tok2 = self.tokens.next_token(diags);
+        let (limit, tok3) =
+            if tok2.is_keyword(&Symbol::KEYWORD_row) || tok2.is_keyword(&Symbol::KEYWORD_rows) {
+                let limit = ExprNode {
+                    kind: ExprKind::IntegerLiteral { value: 1 },
+                    range: tok1.range,
+                };
+                (limit, tok2)
+            } else {
+                self.parse_b_expr(tok2, diags)?
+            };
+        if !(tok3.is_keyword(&Symbol::KEYWORD_row) || tok3.is_keyword(&Symbol::KEYWORD_rows)) {
+            self.syntax_error(&tok3, diags);
+            return Err(tok3);
+        }
+        let tok4 = self.tokens.next_token(diags);
+        let tok5 = if tok4.is_keyword(&Symbol::KEYWORD_with) {
+            let tok5 = self.tokens.next_token(diags);
+            let tok6 = self.expect_keyword(tok5, Symbol::KEYWORD_ties, diags)?;
+            select.with_ties = true;
+            if select.order_by.is_empty() {
+                diags.add(CodeDiagnostic::WithTiesWithoutOrderBy { range: tok4.range });
+            }
+            tok6
+        } else {
+            self.expect_keyword(tok4, Symbol::KEYWORD_only, diags)?
+        };
+        select.limit = Some(limit);
+        Ok(tok5)
+    }
+
+    /// Parses `FOR UPDATE` and the like, if any.
+    fn parse_locking_clauses(
+        &mut self,
+        mut tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<LockingNode>> {
+        let mut locking = Vec::new();
+        while tok0.is_keyword(&Symbol::KEYWORD_for) {
+            let start = tok0.range.start;
+            let tok1 = self.tokens.next_token(diags);
+            let (strength, tok2) = match tok1.keyword() {
+                Some(&Symbol::KEYWORD_update) => {
+                    (LockStrength::Update, self.tokens.next_token(diags))
+                }
+                Some(&Symbol::KEYWORD_share) => {
+                    (LockStrength::Share, self.tokens.next_token(diags))
+                }
+                Some(&Symbol::KEYWORD_no) => {
+                    let tok2 = self.tokens.next_token(diags);
+                    let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_key, diags)?;
+                    let tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_update, diags)?;
+                    (LockStrength::NoKeyUpdate, tok4)
+                }
+                Some(&Symbol::KEYWORD_key) => {
+                    let tok2 = self.tokens.next_token(diags);
+                    let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_share, diags)?;
+                    (LockStrength::KeyShare, tok3)
+                }
+                // `FOR READ ONLY` means no locking.
+                Some(&Symbol::KEYWORD_read) => {
+                    let tok2 = self.tokens.next_token(diags);
+                    tok0 = self.expect_keyword(tok2, Symbol::KEYWORD_only, diags)?;
+                    continue;
+                }
+                _ => {
+                    self.syntax_error(&tok1, diags);
+                    return Err(tok1);
+                }
+            };
+            let mut tok3 = tok2;
+            let mut tables = Vec::new();
+            if tok3.is_keyword(&Symbol::KEYWORD_of) {
+                let tok4 = self.tokens.next_token(diags);
+                (tables, tok3) = self.parse_comma_list(tok4, diags, Self::parse_qualified_name)?;
+            }
+            let wait_policy = match tok3.keyword() {
+                Some(&Symbol::KEYWORD_nowait) => {
+                    tok3 = self.tokens.next_token(diags);
+                    LockWaitPolicy::Error
+                }
+                Some(&Symbol::KEYWORD_skip) => {
+                    let tok4 = self.tokens.next_token(diags);
+                    tok3 = self.expect_keyword(tok4, Symbol::KEYWORD_locked, diags)?;
+                    LockWaitPolicy::Skip
+                }
+                _ => LockWaitPolicy::Block,
+            };
+            locking.push(LockingNode {
+                strength,
+                tables,
+                wait_policy,
+                range: CodeRange {
+                    start,
+                    end: self.tokens.prev_end(),
+                },
+            });
+            tok0 = tok3;
+        }
+        Ok((locking, tok0))
+    }
+
+    /// Parses `SELECT ...` up to the `WINDOW` clause (`simple_select`).
+    fn parse_simple_select(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<SelectBody> {
+        let mut tok1 = self.expect_keyword(tok0, Symbol::KEYWORD_select, diags)?;
+        let mut distinct = None;
+        if tok1.is_keyword(&Symbol::KEYWORD_all) {
+            tok1 = self.tokens.next_token(diags);
+        } else if tok1.is_keyword(&Symbol::KEYWORD_distinct) {
+            let tok2 = self.tokens.next_token(diags);
+            if tok2.is_keyword(&Symbol::KEYWORD_on) {
+                let tok3 = self.tokens.next_token(diags);
+                let (exprs, tok4) = self.parse_parenthesized_expr_list(tok3, diags)?;
+                distinct = Some(DistinctClause::DistinctOn { exprs });
+                tok1 = tok4;
+            } else {
+                distinct = Some(DistinctClause::Distinct);
+                tok1 = tok2;
+            }
+        }
+
+        // The target list is optional, except after DISTINCT.
+        let (targets, mut tok2) = if distinct.is_none() && ends_target_list(&tok1) {
+            (Vec::new(), tok1)
+        } else {
+            self.parse_comma_list(tok1, diags, Self::parse_target)?
+        };
+
+        let mut from = Vec::new();
+        if tok2.is_keyword(&Symbol::KEYWORD_from) {
+            let tok3 = self.tokens.next_token(diags);
+            (from, tok2) = self.parse_comma_list(tok3, diags, Self::parse_table_ref)?;
+        }
+
+        let mut where_clause = None;
+        if tok2.is_keyword(&Symbol::KEYWORD_where) {
+            let tok3 = self.tokens.next_token(diags);
+            let (expr, tok4) = self.parse_expr(tok3, diags)?;
+            where_clause = Some(expr);
+            tok2 = tok4;
+        }
+
+        let mut group_by_distinct = false;
+        let mut group_by = Vec::new();
+        if tok2.is_keyword(&Symbol::KEYWORD_group) {
+            let tok3 = self.tokens.next_token(diags);
+            let mut tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_by, diags)?;
+            if tok4.is_keyword(&Symbol::KEYWORD_all) {
+                tok4 = self.tokens.next_token(diags);
+            } else if tok4.is_keyword(&Symbol::KEYWORD_distinct) {
+                group_by_distinct = true;
+                tok4 = self.tokens.next_token(diags);
+            }
+            (group_by, tok2) = self.parse_comma_list(tok4, diags, Self::parse_group_by_item)?;
+        }
+
+        let mut having = None;
+        if tok2.is_keyword(&Symbol::KEYWORD_having) {
+            let tok3 = self.tokens.next_token(diags);
+            let (expr, tok4) = self.parse_expr(tok3, diags)?;
+            having = Some(expr);
+            tok2 = tok4;
+        }
+
+        let mut window = Vec::new();
+        if tok2.is_keyword(&Symbol::KEYWORD_window) {
+            let tok3 = self.tokens.next_token(diags);
+            (window, tok2) = self.parse_comma_list(tok3, diags, Self::parse_window_definition)?;
+        }
+
+        let select = SimpleSelect {
+            distinct,
+            targets,
+            from,
+            where_clause,
+            group_by_distinct,
+            group_by,
+            having,
+            window,
+        };
+        Ok((SelectBody::Simple(Box::new(select)), tok2))
+    }
+
+    /// Parses `( expr_list )`.
+    pub(super) fn parse_parenthesized_expr_list(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<ExprNode>> {
+        let tok1 = self.expect_token(tok0, TokenKind::LParen, diags)?;
+        let (exprs, tok2) = self.parse_expr_list(tok1, diags)?;
+        let tok3 = self.expect_token(tok2, TokenKind::RParen, diags)?;
+        Ok((exprs, tok3))
+    }
+
+    /// Parses an item in a target list (`target_el`).
+    pub(super) fn parse_target(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TargetNode> {
+        if tok0.kind == TokenKind::Asterisk {
+            let target = TargetNode {
+                expr: ExprNode {
+                    kind: ExprKind::ColumnRef {
+                        fields: Vec::new(),
+                        star: true,
+                    },
+                    range: tok0.range,
+                },
+                alias: None,
+                range: tok0.range,
+            };
+            return Ok((target, self.tokens.next_token(diags)));
+        }
+        let start = tok0.range.start;
+        let (expr, tok1) = self.parse_expr(tok0, diags)?;
+        let (alias, tok2) = match &tok1.kind {
+            TokenKind::Identifier {
+                name: Symbol::KEYWORD_as,
+                quoted: false,
+            } => {
+                let tok2 = self.tokens.next_token(diags);
+                let (alias, tok3) = self.parse_col_label(tok2, diags)?;
+                (Some(alias), tok3)
+            }
+            TokenKind::Identifier { name, quoted } if *quoted || name.is_bare_col_label() => {
+                (Some(name.clone()), self.tokens.next_token(diags))
+            }
+            _ => (None, tok1),
+        };
+        let target = TargetNode {
+            expr,
+            alias,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((target, tok2))
+    }
+
+    /// Parses an item in `GROUP BY` (`group_by_item`).
+    fn parse_group_by_item(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<GroupByItem> {
+        let start = tok0.range.start;
+        let (kind, tok1) = match tok0.keyword() {
+            Some(&(Symbol::KEYWORD_rollup | Symbol::KEYWORD_cube))
+                if self.tokens.peek_token(diags).kind == TokenKind::LParen =>
+            {
+                let tok1 = self.tokens.next_token(diags);
+                let (exprs, tok2) = self.parse_parenthesized_expr_list(tok1, diags)?;
+                let kind = if tok0.is_keyword(&Symbol::KEYWORD_rollup) {
+                    GroupingSetKind::Rollup { exprs }
+                } else {
+                    GroupingSetKind::Cube { exprs }
+                };
+                (kind, tok2)
+            }
+            Some(&Symbol::KEYWORD_grouping)
+                if self
+                    .tokens
+                    .peek_token(diags)
+                    .is_keyword(&Symbol::KEYWORD_sets) =>
+            {
+                self.tokens.next_token(diags);
+                let tok1 = self.tokens.next_token(diags);
+                let tok2 = self.expect_token(tok1, TokenKind::LParen, diags)?;
+                let (items, tok3) =
+                    self.parse_comma_list(tok2, diags, Self::parse_group_by_item)?;
+                let tok4 = self.expect_token(tok3, TokenKind::RParen, diags)?;
+                (GroupingSetKind::Sets { items }, tok4)
+            }
+            _ if tok0.kind == TokenKind::LParen
+                && self.tokens.peek_token(diags).kind == TokenKind::RParen =>
+            {
+                self.tokens.next_token(diags);
+                (GroupingSetKind::Empty, self.tokens.next_token(diags))
+            }
+            _ => {
+                let (expr, tok1) = self.parse_expr(tok0, diags)?;
+                return Ok((GroupByItem::Expr(expr), tok1));
+            }
+        };
+        let node = GroupingSetNode {
+            kind,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((GroupByItem::GroupingSet(node), tok1))
+    }
+
+    /// Parses `name AS (window_specification)` in a `WINDOW` clause.
+    fn parse_window_definition(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<WindowDefNode> {
+        let start = tok0.range.start;
+        let (name, tok1) = self.parse_col_id(tok0, diags)?;
+        let tok2 = self.expect_keyword(tok1, Symbol::KEYWORD_as, diags)?;
+        let (mut window, tok3) = self.parse_window_specification(tok2, diags)?;
+        window.name = Some(name);
+        window.range.start = start;
+        Ok((window, tok3))
+    }
+
+    /// Parses an operator in `ORDER BY ... USING` (`qual_all_Op`).
+    fn parse_qual_all_op(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<OperatorName> {
+        if tok0.is_keyword(&Symbol::KEYWORD_operator) {
+            let tok1 = self.tokens.next_token(diags);
+            return self.parse_qual_op_body(tok1, diags);
+        }
+        match symbol_op(&tok0.kind) {
+            Some(op) => Ok((op, self.tokens.next_token(diags))),
+            None => {
+                self.syntax_error(&tok0, diags);
+                Err(tok0)
+            }
+        }
+    }
+}
+
+/// Whether the token cannot start a target list, which is then empty.
+fn ends_target_list(tok: &Token) -> bool {
+    match &tok.kind {
+        TokenKind::Eof | TokenKind::Semicolon | TokenKind::RParen => true,
+        TokenKind::Identifier {
+            name,
+            quoted: false,
+        } => matches!(
+            *name,
+            Symbol::KEYWORD_from
+                | Symbol::KEYWORD_into
+                | Symbol::KEYWORD_where
+                | Symbol::KEYWORD_group
+                | Symbol::KEYWORD_having
+                | Symbol::KEYWORD_window
+                | Symbol::KEYWORD_order
+                | Symbol::KEYWORD_limit
+                | Symbol::KEYWORD_offset
+                | Symbol::KEYWORD_fetch
+                | Symbol::KEYWORD_for
+                | Symbol::KEYWORD_union
+                | Symbol::KEYWORD_intersect
+                | Symbol::KEYWORD_except
+        ),
+        _ => false,
+    }
+}
+
+/// Whether the token continues a query after a parenthesized one, as in `(SELECT 1) UNION ...`.
+pub(super) fn continues_select(tok: &Token) -> bool {
+    matches!(
+        tok.keyword(),
+        Some(
+            &(Symbol::KEYWORD_union
+                | Symbol::KEYWORD_intersect
+                | Symbol::KEYWORD_except
+                | Symbol::KEYWORD_order
+                | Symbol::KEYWORD_limit
+                | Symbol::KEYWORD_offset
+                | Symbol::KEYWORD_fetch
+                | Symbol::KEYWORD_for)
+        )
+    )
+}
+
+/// The precedence of set operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum SetOpPrec {
+    /// `UNION` and `EXCEPT`
+    Union,
+    /// `INTERSECT`
+    Intersect,
+}
+
+/// A query consisting only of the body.
+fn bare_select(body: SelectBody, range: CodeRange) -> SelectNode {
+    SelectNode {
+        with: None,
+        body,
+        order_by: Vec::new(),
+        limit: None,
+        offset: None,
+        with_ties: false,
+        locking: Vec::new(),
+        range,
+    }
+}
+
+/// Whether the token begins a query.
+///
+/// A parenthesized query is not included, as `(` can begin other things.
+pub(super) fn starts_select(tok: &Token) -> bool {
+    matches!(
+        tok.keyword(),
+        Some(&(Symbol::KEYWORD_select | Symbol::KEYWORD_values | Symbol::KEYWORD_table))
+    ) || starts_with_clause(tok)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::StmtKind,
+        parser::{
+            parse_stmt,
+            sexp::{parse_stmt_errors, select_sexp},
+        },
+        pos::pos,
+    };
+
+    use super::*;
+
+    fn sexp(src: &str) -> String {
+        let stmt = parse_stmt(src).unwrap();
+        let StmtKind::Select(select) = stmt.kind else {
+            panic!("not a select statement: {src}");
+        };
+        select_sexp(&select)
+    }
+
+    #[test]
+    fn test_targets() {
+        assert_eq!(
+            sexp("select a, b as c, d e, 1 \"X\", *, t.*"),
+            "(select (targets a (as b c) (as d e) (as 1 X) * t.*))"
+        );
+        // Any keyword can be a label after AS.
+        assert_eq!(sexp("select 1 as from"), "(select (targets (as 1 from)))");
+        assert_eq!(sexp("select 1 as year"), "(select (targets (as 1 year)))");
+        // `SIMILAR` is a bare label unless followed by `TO`.
+        assert_eq!(
+            sexp("select a similar from t"),
+            "(select (targets (as a similar)) (from t))"
+        );
+    }
+
+    #[test]
+    fn test_target_as_label_keyword() {
+        // `YEAR` requires AS to be a label.
+        let src = "select 1 year";
+        assert_eq!(
+            parse_stmt_errors(src),
+            vec![CodeDiagnostic::SyntaxError {
+                range: pos(src, "year", 0),
+                near: "year".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_empty_targets() {
+        assert_eq!(sexp("select"), "(select (targets))");
+        assert_eq!(sexp("select from t"), "(select (targets) (from t))");
+    }
+
+    #[test]
+    fn test_distinct() {
+        assert_eq!(sexp("select all a"), "(select (targets a))");
+        assert_eq!(sexp("select distinct a"), "(select distinct (targets a))");
+        assert_eq!(
+            sexp("select distinct on (a, b) c"),
+            "(select (distinct-on (a b)) (targets c))"
+        );
+        let src = "select distinct from t";
+        assert_eq!(
+            parse_stmt_errors(src),
+            vec![CodeDiagnostic::SyntaxError {
+                range: pos(src, "from", 0),
+                near: "from".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(
+            sexp("select * from s.t u, c.s.t2 as v, t3"),
+            "(select (targets *) (from (as s.t u) (as c.s.t2 v) t3))"
+        );
+    }
+
+    #[test]
+    fn test_from_improper_qualified_name() {
+        let src = "select * from a.b.c.d";
+        assert_eq!(
+            parse_stmt_errors(src),
+            vec![CodeDiagnostic::ImproperQualifiedName {
+                range: pos(src, "a.b.c.d", 0),
+                name: "a.b.c.d".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_where_group_by_having() {
+        assert_eq!(
+            sexp("select a from t where a > 1 group by a, b + 1 having a < 10"),
+            "(select (targets a) (from t) (where (> a 1)) (group-by a (+ b 1)) (having (< a 10)))"
+        );
+        assert_eq!(
+            sexp("select a from t group by distinct a"),
+            "(select (targets a) (from t) (group-by-distinct a))"
+        );
+        assert_eq!(
+            sexp("select a from t group by all a"),
+            "(select (targets a) (from t) (group-by a))"
+        );
+    }
+
+    #[test]
+    fn test_grouping_sets() {
+        assert_eq!(
+            sexp(
+                "select a from t group by rollup (a, b), cube (c), grouping sets ((), (d), e, rollup (f))"
+            ),
+            "(select (targets a) (from t) (group-by (rollup a b) (cube c) (grouping-sets () d e (rollup f))))"
+        );
+        // Without parentheses, they are ordinary column names.
+        assert_eq!(
+            sexp("select a from t group by cube, rollup"),
+            "(select (targets a) (from t) (group-by cube rollup))"
+        );
+    }
+
+    #[test]
+    fn test_window() {
+        assert_eq!(
+            sexp(
+                "select a from t window w as (partition by a, b order by c desc), w2 as (w order by d), w3 as ()"
+            ),
+            "(select (targets a) (from t) (window (w (partition-by a b) (order-by (desc c))) (w2 (ref w) (order-by d)) (w3)))"
+        );
+        assert_eq!(
+            sexp("select window w as (order by a rows 1 preceding)"),
+            "(select (targets) (window (w (order-by a) (rows (preceding 1) current-row))))"
+        );
+    }
+
+    #[test]
+    fn test_order_by() {
+        assert_eq!(
+            sexp(
+                "select a from t order by a asc nulls first, b using <, c desc nulls last, d using operator(pg_catalog.>)"
+            ),
+            "(select (targets a) (from t) (order-by (nulls-first (asc a)) (using < b) (nulls-last (desc c)) (using pg_catalog.> d)))"
+        );
+    }
+
+    #[test]
+    fn test_limit_offset() {
+        assert_eq!(
+            sexp("select a from t limit 10 offset 5"),
+            "(select (targets a) (from t) (limit 10) (offset 5))"
+        );
+        assert_eq!(
+            sexp("select a offset 5 rows limit all"),
+            "(select (targets a) (limit null) (offset 5))"
+        );
+        assert_eq!(
+            sexp("select a offset 5 row fetch first 3 rows only"),
+            "(select (targets a) (limit 3) (offset 5))"
+        );
+        assert_eq!(
+            sexp("select a fetch next row only"),
+            "(select (targets a) (limit 1))"
+        );
+        assert_eq!(
+            sexp("select a order by a fetch first 5 rows with ties"),
+            "(select (targets a) (order-by a) (limit 5) with-ties)"
+        );
+    }
+
+    #[test]
+    fn test_limit_twice() {
+        let src = "select a limit 1 limit 2";
+        assert_eq!(
+            parse_stmt_errors(src),
+            vec![CodeDiagnostic::SyntaxError {
+                range: pos(src, "limit", 1),
+                near: "limit".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_limit_comma() {
+        let src = "select a limit 1, 2";
+        let diags = parse_stmt_errors(src);
+        assert_eq!(
+            diags,
+            vec![CodeDiagnostic::LimitCommaNotSupported {
+                range: pos(src, "limit", 0),
+            }]
+        );
+        assert_eq!(
+            diags[0].hint().as_deref(),
+            Some("Use separate LIMIT and OFFSET clauses.")
+        );
+    }
+
+    #[test]
+    fn test_with_ties_without_order_by() {
+        let src = "select a fetch first 1 row with ties";
+        assert_eq!(
+            parse_stmt_errors(src),
+            vec![CodeDiagnostic::WithTiesWithoutOrderBy {
+                range: pos(src, "with", 0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_locking() {
+        assert_eq!(
+            sexp("select a from t for update of t, s.u nowait for share skip locked"),
+            "(select (targets a) (from t) (for-update (of t s.u) nowait) (for-share skip-locked))"
+        );
+        assert_eq!(
+            sexp("select a from t for no key update for key share"),
+            "(select (targets a) (from t) (for-no-key-update) (for-key-share))"
+        );
+        assert_eq!(
+            sexp("select a from t for read only"),
+            "(select (targets a) (from t))"
+        );
+        assert_eq!(
+            sexp("select a from t for update limit 1"),
+            "(select (targets a) (from t) (limit 1) (for-update))"
+        );
+        assert_eq!(
+            sexp("select a from t limit 1 for update"),
+            "(select (targets a) (from t) (limit 1) (for-update))"
+        );
+    }
+
+    #[test]
+    fn test_skip_locked_with_ties() {
+        let src = "select a from t order by a fetch first 1 row with ties for update skip locked";
+        let diags = parse_stmt_errors(src);
+        assert_eq!(
+            diags,
+            vec![CodeDiagnostic::SkipLockedWithTies {
+                range: pos(src, "for update skip locked", 0),
+            }]
+        );
+        assert_eq!(diags[0].sqlstate(), "0A000");
+    }
+
+    #[test]
+    fn test_select_range() {
+        let src = "select a from t order by a for update";
+        let stmt = parse_stmt(src).unwrap();
+        assert_eq!(stmt.range, pos(src, src, 0));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        assert_eq!(
+            sexp("select 1 union select 2 union all select 3"),
+            "(union-all (union (select (targets 1)) (select (targets 2))) (select (targets 3)))"
+        );
+        // INTERSECT binds tighter than UNION and EXCEPT.
+        assert_eq!(
+            sexp("select 1 except distinct select 2 intersect select 3"),
+            "(except (select (targets 1)) (intersect (select (targets 2)) (select (targets 3))))"
+        );
+        assert_eq!(
+            sexp("select 1 intersect all select 2 union select 3"),
+            "(union (intersect-all (select (targets 1)) (select (targets 2))) (select (targets 3)))"
+        );
+        // ORDER BY and LIMIT apply to the whole.
+        assert_eq!(
+            sexp("select a from t union select b from u order by 1 limit 2"),
+            "(union (select (targets a) (from t)) (select (targets b) (from u)) \
+             (order-by 1) (limit 2))"
+        );
+        let src = "select 1 order by 1 union select 2";
+        assert_eq!(
+            parse_stmt_errors(src),
+            vec![CodeDiagnostic::SyntaxError {
+                range: pos(src, "union", 0),
+                near: "union".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parenthesized_select() {
+        assert_eq!(
+            sexp("(select 1 order by 1 limit 1) union (select 2) order by 1"),
+            "(union (select (targets 1) (order-by 1) (limit 1)) (select (targets 2)) (order-by 1))"
+        );
+        assert_eq!(
+            sexp("((select 1)) limit 1"),
+            "(select (targets 1) (limit 1))"
+        );
+        assert_eq!(
+            sexp("select 1 union (select 2 union select 3)"),
+            "(union (select (targets 1)) (union (select (targets 2)) (select (targets 3))))"
+        );
+        let src = "(select 1) union (select 2)";
+        let stmt = parse_stmt(src).unwrap();
+        assert_eq!(stmt.range, pos(src, src, 0));
+        let StmtKind::Select(select) = stmt.kind else {
+            unreachable!();
+        };
+        let SelectBody::SetOp { left, right, .. } = select.body else {
+            panic!("not a set operation");
+        };
+        assert_eq!(left.range, pos(src, "(select 1)", 0));
+        assert_eq!(right.range, pos(src, "(select 2)", 0));
+    }
+
+    #[test]
+    fn test_multiple_clauses() {
+        let src = "(select 1 order by 1 offset 1) order by 2 offset 2";
+        assert_eq!(
+            parse_stmt_errors(src),
+            vec![
+                CodeDiagnostic::MultipleClauses {
+                    range: pos(src, "order", 1),
+                    clause: "ORDER BY",
+                },
+                CodeDiagnostic::MultipleClauses {
+                    range: pos(src, "offset", 1),
+                    clause: "OFFSET",
+                },
+            ]
+        );
+        let src = "(select 1 limit 1) fetch first row only";
+        assert_eq!(
+            parse_stmt_errors(src),
+            vec![CodeDiagnostic::MultipleClauses {
+                range: pos(src, "fetch", 0),
+                clause: "LIMIT",
+            }]
+        );
+        let src = "with a as (select) (with b as (select) select)";
+        let diags = parse_stmt_errors(src);
+        assert_eq!(
+            diags,
+            vec![CodeDiagnostic::MultipleClauses {
+                range: pos(src, "with a as (select)", 0),
+                clause: "WITH",
+            }]
+        );
+        assert_eq!(diags[0].to_string(), "multiple WITH clauses not allowed");
+    }
+
+    #[test]
+    fn test_values() {
+        assert_eq!(
+            sexp("values (1, 'a'), (2, 'b') order by 1"),
+            "(values (1 'a') (2 'b') (order-by 1))"
+        );
+        assert_eq!(
+            sexp("select 1 union values (2)"),
+            "(union (select (targets 1)) (values (2)))"
+        );
+        let src = "values ()";
+        assert_eq!(
+            parse_stmt_errors(src),
+            vec![CodeDiagnostic::SyntaxError {
+                range: pos(src, ")", 0),
+                near: ")".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(sexp("table s.t"), "(table s.t)");
+        assert_eq!(sexp("table t * limit 1"), "(table t (limit 1))");
+        assert_eq!(
+            sexp("with a as (select) table only a"),
+            "(table (with (a (select (targets)))) (only a))"
+        );
+        assert_eq!(
+            sexp("select from (table t) x, (values (1)) v (a)"),
+            "(select (targets) (from (as (table t) x) (as (values (1)) (v a))))"
+        );
+    }
+}
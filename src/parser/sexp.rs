@@ -0,0 +1,1204 @@
+// S-expression rendering of syntax trees, to keep parser tests concise.
+
+use crate::{
+    Symbol,
+    ast::{
+        Alias, AssignTargetNode, ColumnDefNode, ConflictAction, ConflictTarget, CteMaterialized,
+        CteNode, DistinctClause, ExprKind, ExprNode, FrameBound, FrameExclusion, FrameMode,
+        FuncCall, GroupByItem, GroupingSetKind, IndexElemNode, IndexKey, IndirectionItem,
+        InsertNode, IntervalFields, JoinKind, JoinQual, JsonArgument, JsonBehavior,
+        JsonBehaviorKind, JsonEncoding, JsonFormat, JsonFuncOp, JsonKeyValue, JsonOutput,
+        JsonQuotes, JsonTable, JsonTableColumn, JsonTableColumnKind, JsonTablePath, JsonValueExpr,
+        JsonValueType, JsonWrapper, LikeKind, LockStrength, LockWaitPolicy, LockingNode,
+        NullsOrder, OnConflictNode, OperatorName, OverridingKind, SelectBody, SelectNode,
+        SetClauseNode, SetOp, SortByNode, SortOrder, SqlValueFunctionKind, StmtKind, StmtNode,
+        TableRefKind, TableRefNode, TargetNode, TypeName, WindowDefNode, WindowFrame, WithClause,
+    },
+    diag::{CodeDiagnostic, CodeDiagnostics},
+    pos::{CodeRange, pos},
+    token::TokenKind,
+};
+
+use super::{Parser, parse_stmt, parse_stmt_with_diags};
+
+/// Parses `src` as a single expression, reporting trailing tokens as a syntax error.
+pub(crate) fn parse_expr_src(src: &str, diags: &mut CodeDiagnostics) -> Option<ExprNode> {
+    let mut parser = Parser::new(src);
+    let tok0 = parser.tokens.next_token(diags);
+    match parser.parse_expr(tok0, diags) {
+        Ok((expr, tok1)) if tok1.kind == TokenKind::Eof => Some(expr),
+        Ok((_, tok1)) => {
+            parser.syntax_error(&tok1, diags);
+            None
+        }
+        Err(_) => None,
+    }
+}
+
+/// Renders the expression `src`, which must parse without diagnostics.
+pub(crate) fn parse_expr_sexp(src: &str) -> String {
+    let mut diags = CodeDiagnostics::new();
+    let expr = parse_expr_src(src, &mut diags);
+    assert_eq!(diags.diagnostics, vec![], "{src}");
+    expr_sexp(&expr.unwrap())
+}
+
+/// The diagnostics from parsing `src` as an expression.
+pub(crate) fn parse_expr_errors(src: &str) -> Vec<CodeDiagnostic> {
+    let mut diags = CodeDiagnostics::new();
+    parse_expr_src(src, &mut diags);
+    diags.diagnostics
+}
+
+/// Renders the statement `src`, which must parse without errors.
+pub(crate) fn parse_stmt_sexp(src: &str) -> String {
+    stmt_sexp(&parse_stmt(src).unwrap())
+}
+
+/// The diagnostics from parsing `src` as a statement.
+pub(crate) fn parse_stmt_errors(src: &str) -> Vec<CodeDiagnostic> {
+    let mut diags = CodeDiagnostics::new();
+    parse_stmt_with_diags(src, &mut diags);
+    diags.diagnostics
+}
+
+/// A single syntax error at the `occurrence`-th `near` in `src`.
+pub(crate) fn syntax_error(src: &str, near: &str, occurrence: usize) -> Vec<CodeDiagnostic> {
+    vec![CodeDiagnostic::SyntaxError {
+        range: pos(src, near, occurrence),
+        near: near.to_string(),
+    }]
+}
+
+/// A single syntax error at the end of `src`.
+pub(crate) fn unexpected_eof(src: &str) -> Vec<CodeDiagnostic> {
+    vec![CodeDiagnostic::UnexpectedEof {
+        range: CodeRange {
+            start: src.len(),
+            end: src.len(),
+        },
+    }]
+}
+
+pub(crate) fn stmt_sexp(stmt: &StmtNode) -> String {
+    match &stmt.kind {
+        StmtKind::Select(select) => select_sexp(select),
+        StmtKind::Insert(insert) => insert_sexp(insert),
+        kind => format!("{kind:?}"),
+    }
+}
+
+pub(crate) fn select_sexp(select: &SelectNode) -> String {
+    let mut parts = Vec::new();
+    match &select.body {
+        SelectBody::Simple(simple) => {
+            parts.push("select".to_string());
+            match &simple.distinct {
+                Some(DistinctClause::Distinct) => parts.push("distinct".to_string()),
+                Some(DistinctClause::DistinctOn { exprs }) => {
+                    parts.push(format!("(distinct-on {})", list_sexp(exprs)))
+                }
+                None => {}
+            }
+            parts.push(items_sexp("targets", &simple.targets, target_sexp));
+            if !simple.from.is_empty() {
+                parts.push(items_sexp("from", &simple.from, table_ref_sexp));
+            }
+            if let Some(expr) = &simple.where_clause {
+                parts.push(format!("(where {})", expr_sexp(expr)));
+            }
+            if !simple.group_by.is_empty() {
+                let name = if simple.group_by_distinct {
+                    "group-by-distinct"
+                } else {
+                    "group-by"
+                };
+                parts.push(items_sexp(name, &simple.group_by, group_by_item_sexp));
+            }
+            if let Some(expr) = &simple.having {
+                parts.push(format!("(having {})", expr_sexp(expr)));
+            }
+            if !simple.window.is_empty() {
+                parts.push(items_sexp("window", &simple.window, window_def_sexp));
+            }
+        }
+        SelectBody::Values { rows } => {
+            parts.push("values".to_string());
+            parts.extend(rows.iter().map(|row| list_sexp(row)));
+        }
+        SelectBody::Table { name, inherit } => {
+            parts.push("table".to_string());
+            parts.push(if *inherit {
+                name_sexp(name)
+            } else {
+                format!("(only {})", name_sexp(name))
+            });
+        }
+        SelectBody::SetOp {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            let op = match op {
+                SetOp::Union => "union",
+                SetOp::Intersect => "intersect",
+                SetOp::Except => "except",
+            };
+            parts.push(if *all {
+                format!("{op}-all")
+            } else {
+                op.to_string()
+            });
+            parts.push(select_sexp(left));
+            parts.push(select_sexp(right));
+        }
+    }
+    if let Some(with) = &select.with {
+        parts.insert(1, with_sexp(with));
+    }
+    if !select.order_by.is_empty() {
+        parts.push(items_sexp("order-by", &select.order_by, sortby_sexp));
+    }
+    if let Some(expr) = &select.limit {
+        parts.push(format!("(limit {})", expr_sexp(expr)));
+    }
+    if let Some(expr) = &select.offset {
+        parts.push(format!("(offset {})", expr_sexp(expr)));
+    }
+    if select.with_ties {
+        parts.push("with-ties".to_string());
+    }
+    for locking in &select.locking {
+        parts.push(locking_sexp(locking));
+    }
+    format!("({})", parts.join(" "))
+}
+
+/// Renders `INSERT` like `(insert t (columns a) (values (1)) (returning a))`.
+fn insert_sexp(insert: &InsertNode) -> String {
+    let mut parts = vec!["insert".to_string()];
+    if let Some(with) = &insert.with {
+        parts.push(with_sexp(with));
+    }
+    parts.push(match &insert.alias {
+        Some(alias) => format!("(as {} {})", name_sexp(&insert.table), &**alias),
+        None => name_sexp(&insert.table),
+    });
+    if !insert.columns.is_empty() {
+        parts.push(items_sexp("columns", &insert.columns, assign_target_sexp));
+    }
+    match insert.overriding {
+        Some(OverridingKind::System) => parts.push("overriding-system".to_string()),
+        Some(OverridingKind::User) => parts.push("overriding-user".to_string()),
+        None => {}
+    }
+    parts.push(
+        insert
+            .source
+            .as_ref()
+            .map_or("default-values".to_string(), select_sexp),
+    );
+    if let Some(on_conflict) = &insert.on_conflict {
+        parts.push(on_conflict_sexp(on_conflict));
+    }
+    if !insert.returning.is_empty() {
+        parts.push(items_sexp("returning", &insert.returning, target_sexp));
+    }
+    format!("({})", parts.join(" "))
+}
+
+/// Renders a column like `a` or `(indirection a [1])`.
+fn assign_target_sexp(target: &AssignTargetNode) -> String {
+    if target.indirection.is_empty() {
+        return target.name.to_string();
+    }
+    let mut parts = vec!["indirection".to_string(), target.name.to_string()];
+    parts.extend(target.indirection.iter().map(indirection_item_sexp));
+    format!("({})", parts.join(" "))
+}
+
+/// Renders `ON CONFLICT` like `(on-conflict (infer a) (do-update (= a 1)))`.
+fn on_conflict_sexp(on_conflict: &OnConflictNode) -> String {
+    let mut parts = vec!["on-conflict".to_string()];
+    match &on_conflict.target {
+        Some(ConflictTarget::Index { elems, predicate }) => {
+            let mut infer = vec!["infer".to_string()];
+            infer.extend(elems.iter().map(index_elem_sexp));
+            infer.extend(
+                predicate
+                    .as_ref()
+                    .map(|p| format!("(where {})", expr_sexp(p))),
+            );
+            parts.push(format!("({})", infer.join(" ")));
+        }
+        Some(ConflictTarget::Constraint(name)) => {
+            parts.push(format!("(on-constraint {})", &**name));
+        }
+        None => {}
+    }
+    match &on_conflict.action {
+        ConflictAction::Nothing => parts.push("do-nothing".to_string()),
+        ConflictAction::Update { set, where_clause } => {
+            let mut update = vec!["do-update".to_string()];
+            update.extend(set.iter().map(set_clause_sexp));
+            update.extend(
+                where_clause
+                    .as_ref()
+                    .map(|w| format!("(where {})", expr_sexp(w))),
+            );
+            parts.push(format!("({})", update.join(" ")));
+        }
+    }
+    format!("({})", parts.join(" "))
+}
+
+/// Renders an assignment like `(= a 1)` or `(= (a b) (row 1 2))`.
+fn set_clause_sexp(set: &SetClauseNode) -> String {
+    let targets = if set.multiple {
+        let targets = set
+            .targets
+            .iter()
+            .map(assign_target_sexp)
+            .collect::<Vec<_>>();
+        format!("({})", targets.join(" "))
+    } else {
+        assign_target_sexp(&set.targets[0])
+    };
+    format!("(= {targets} {})", expr_sexp(&set.value))
+}
+
+/// Renders an index element like `a` or `(a (collate C) text_ops desc nulls-last)`.
+fn index_elem_sexp(elem: &IndexElemNode) -> String {
+    let mut parts = vec![match &elem.key {
+        IndexKey::Column(name) => name.to_string(),
+        IndexKey::Expr(expr) => expr_sexp(expr),
+    }];
+    if !elem.collation.is_empty() {
+        parts.push(format!("(collate {})", name_sexp(&elem.collation)));
+    }
+    if !elem.opclass.is_empty() {
+        parts.push(name_sexp(&elem.opclass));
+    }
+    match elem.order {
+        SortOrder::Asc => parts.push("asc".to_string()),
+        SortOrder::Desc => parts.push("desc".to_string()),
+        _ => {}
+    }
+    match elem.nulls {
+        NullsOrder::First => parts.push("nulls-first".to_string()),
+        NullsOrder::Last => parts.push("nulls-last".to_string()),
+        NullsOrder::Default => {}
+    }
+    if parts.len() == 1 {
+        parts.pop().unwrap()
+    } else {
+        format!("({})", parts.join(" "))
+    }
+}
+
+fn items_sexp<T>(name: &str, items: &[T], f: impl Fn(&T) -> String) -> String {
+    let mut parts = vec![name.to_string()];
+    parts.extend(items.iter().map(f));
+    format!("({})", parts.join(" "))
+}
+
+fn with_sexp(with: &WithClause) -> String {
+    let name = if with.recursive {
+        "with-recursive"
+    } else {
+        "with"
+    };
+    items_sexp(name, &with.ctes, cte_sexp)
+}
+
+fn cte_sexp(cte: &CteNode) -> String {
+    let mut parts = vec![cte.name.to_string()];
+    if !cte.columns.is_empty() {
+        parts.push(names_sexp(&cte.columns));
+    }
+    match cte.materialized {
+        CteMaterialized::Default => {}
+        CteMaterialized::Always => parts.push("materialized".to_string()),
+        CteMaterialized::Never => parts.push("not-materialized".to_string()),
+    }
+    parts.push(stmt_sexp(&cte.query));
+    if let Some(search) = &cte.search {
+        let order = if search.breadth_first {
+            "search-breadth-first"
+        } else {
+            "search-depth-first"
+        };
+        parts.push(format!(
+            "({order} {} {})",
+            names_sexp(&search.columns),
+            &*search.seq_column
+        ));
+    }
+    if let Some(cycle) = &cte.cycle {
+        let mut cycle_parts = vec![
+            "cycle".to_string(),
+            names_sexp(&cycle.columns),
+            cycle.mark_column.to_string(),
+        ];
+        if let Some((value, default)) = &cycle.mark_values {
+            cycle_parts.push(expr_sexp(value));
+            cycle_parts.push(expr_sexp(default));
+        }
+        cycle_parts.push(cycle.path_column.to_string());
+        parts.push(format!("({})", cycle_parts.join(" ")));
+    }
+    format!("({})", parts.join(" "))
+}
+
+/// Renders a list of plain names such as `(a b)`.
+fn names_sexp(names: &[Symbol]) -> String {
+    let names = names.iter().map(|name| &**name).collect::<Vec<_>>();
+    format!("({})", names.join(" "))
+}
+
+fn target_sexp(target: &TargetNode) -> String {
+    match &target.alias {
+        Some(alias) => format!("(as {} {})", expr_sexp(&target.expr), &**alias),
+        None => expr_sexp(&target.expr),
+    }
+}
+
+pub(crate) fn table_ref_sexp(table_ref: &TableRefNode) -> String {
+    let (base, alias) = match &table_ref.kind {
+        TableRefKind::Relation {
+            name,
+            inherit,
+            alias,
+            tablesample,
+        } => {
+            let mut base = if *inherit {
+                name_sexp(name)
+            } else {
+                format!("(only {})", name_sexp(name))
+            };
+            if let Some(tablesample) = tablesample {
+                let mut parts = vec![name_sexp(&tablesample.method)];
+                parts.extend(tablesample.args.iter().map(expr_sexp));
+                base = format!("(tablesample {base} ({})", parts.join(" "));
+                if let Some(seed) = &tablesample.repeatable {
+                    base.push_str(&format!(" (repeatable {})", expr_sexp(seed)));
+                }
+                base.push(')');
+            }
+            (base, alias)
+        }
+        TableRefKind::Subquery {
+            lateral,
+            query,
+            alias,
+        } => {
+            let base = if *lateral {
+                format!("(lateral {})", select_sexp(query))
+            } else {
+                select_sexp(query)
+            };
+            (base, alias)
+        }
+        TableRefKind::Function {
+            lateral,
+            functions,
+            rows_from,
+            ordinality,
+            alias,
+            column_defs,
+        } => {
+            let mut base = if *rows_from {
+                items_sexp("rows-from", functions, |function| {
+                    column_defs_sexp(expr_sexp(&function.call), &function.column_defs)
+                })
+            } else {
+                expr_sexp(&functions[0].call)
+            };
+            if *ordinality {
+                base = format!("(with-ordinality {base})");
+            }
+            if *lateral {
+                base = format!("(lateral {base})");
+            }
+            return column_defs_sexp(alias_sexp(base, alias), column_defs);
+        }
+        TableRefKind::JsonTable {
+            lateral,
+            table,
+            alias,
+        } => {
+            let mut base = json_table_sexp(table);
+            if *lateral {
+                base = format!("(lateral {base})");
+            }
+            (base, alias)
+        }
+        TableRefKind::Join {
+            kind,
+            natural,
+            left,
+            right,
+            qual,
+            alias,
+        } => {
+            let kind = match kind {
+                JoinKind::Inner => "join",
+                JoinKind::Left => "left-join",
+                JoinKind::Right => "right-join",
+                JoinKind::Full => "full-join",
+            };
+            let mut parts = vec![
+                format!("{}{kind}", if *natural { "natural-" } else { "" }),
+                table_ref_sexp(left),
+                table_ref_sexp(right),
+            ];
+            match qual {
+                Some(JoinQual::On { expr }) => parts.push(format!("(on {})", expr_sexp(expr))),
+                Some(JoinQual::Using { columns, alias }) => {
+                    let mut using = format!("(using {}", names_sexp(columns));
+                    if let Some(alias) = alias {
+                        using.push_str(&format!(" {}", &**alias));
+                    }
+                    using.push(')');
+                    parts.push(using);
+                }
+                None => {}
+            }
+            (format!("({})", parts.join(" ")), alias)
+        }
+    };
+    alias_sexp(base, alias)
+}
+
+fn alias_sexp(base: String, alias: &Option<Alias>) -> String {
+    match alias {
+        Some(Alias { name, columns }) if columns.is_empty() => format!("(as {base} {})", &**name),
+        Some(Alias { name, columns }) => {
+            let columns = columns.iter().map(|column| &**column).collect::<Vec<_>>();
+            format!("(as {base} ({} {}))", &**name, columns.join(" "))
+        }
+        None => base,
+    }
+}
+
+/// Renders column definitions of a function table like `(column-defs base (a int4))`.
+fn column_defs_sexp(base: String, column_defs: &[ColumnDefNode]) -> String {
+    if column_defs.is_empty() {
+        return base;
+    }
+    let mut parts = vec!["column-defs".to_string(), base];
+    parts.extend(column_defs.iter().map(|column_def| {
+        let mut def = format!(
+            "({} {}",
+            &*column_def.name,
+            type_name_sexp(&column_def.type_name)
+        );
+        if !column_def.collation.is_empty() {
+            def.push_str(&format!(" (collate {})", name_sexp(&column_def.collation)));
+        }
+        def.push(')');
+        def
+    }));
+    format!("({})", parts.join(" "))
+}
+
+fn group_by_item_sexp(item: &GroupByItem) -> String {
+    match item {
+        GroupByItem::Expr(expr) => expr_sexp(expr),
+        GroupByItem::GroupingSet(set) => match &set.kind {
+            GroupingSetKind::Empty => "()".to_string(),
+            GroupingSetKind::Rollup { exprs } => items_sexp("rollup", exprs, expr_sexp),
+            GroupingSetKind::Cube { exprs } => items_sexp("cube", exprs, expr_sexp),
+            GroupingSetKind::Sets { items } => {
+                items_sexp("grouping-sets", items, group_by_item_sexp)
+            }
+        },
+    }
+}
+
+pub(crate) fn window_def_sexp(window: &WindowDefNode) -> String {
+    let mut parts = vec![
+        window
+            .name
+            .as_ref()
+            .map_or("_".to_string(), |n| n.to_string()),
+    ];
+    if let Some(refname) = &window.refname {
+        parts.push(format!("(ref {})", &**refname));
+    }
+    if !window.partition_by.is_empty() {
+        parts.push(items_sexp("partition-by", &window.partition_by, expr_sexp));
+    }
+    if !window.order_by.is_empty() {
+        parts.push(items_sexp("order-by", &window.order_by, sortby_sexp));
+    }
+    if let Some(frame) = &window.frame {
+        parts.push(window_frame_sexp(frame));
+    }
+    format!("({})", parts.join(" "))
+}
+
+fn func_call_sexp(call: &FuncCall) -> String {
+    let head = if call.sql_syntax { "sql-call" } else { "call" };
+    let mut parts = vec![head.to_string(), name_sexp(&call.name)];
+    if call.star {
+        parts.push("*".to_string());
+    }
+    if call.distinct {
+        parts.push("distinct".to_string());
+    }
+    parts.extend(call.args.iter().map(expr_sexp));
+    if call.variadic
+        && let Some(last) = parts.pop()
+    {
+        parts.push(format!("(variadic {last})"));
+    }
+    if !call.order_by.is_empty() {
+        let name = if call.within_group {
+            "within-group"
+        } else {
+            "order-by"
+        };
+        parts.push(items_sexp(name, &call.order_by, sortby_sexp));
+    }
+    if let Some(filter) = &call.filter {
+        parts.push(format!("(filter {})", expr_sexp(filter)));
+    }
+    if let Some(over) = &call.over {
+        parts.push(format!("(over {})", window_def_sexp(over)));
+    }
+    format!("({})", parts.join(" "))
+}
+
+fn window_frame_sexp(frame: &WindowFrame) -> String {
+    let mode = match frame.mode {
+        FrameMode::Range => "range",
+        FrameMode::Rows => "rows",
+        FrameMode::Groups => "groups",
+    };
+    let mut parts = vec![
+        mode.to_string(),
+        frame_bound_sexp(&frame.start),
+        frame_bound_sexp(&frame.end),
+    ];
+    match frame.exclusion {
+        FrameExclusion::NoOthers => {}
+        FrameExclusion::CurrentRow => parts.push("exclude-current-row".to_string()),
+        FrameExclusion::Group => parts.push("exclude-group".to_string()),
+        FrameExclusion::Ties => parts.push("exclude-ties".to_string()),
+    }
+    format!("({})", parts.join(" "))
+}
+
+fn frame_bound_sexp(bound: &FrameBound) -> String {
+    match bound {
+        FrameBound::UnboundedPreceding => "unbounded-preceding".to_string(),
+        FrameBound::Preceding { offset } => format!("(preceding {})", expr_sexp(offset)),
+        FrameBound::CurrentRow => "current-row".to_string(),
+        FrameBound::Following { offset } => format!("(following {})", expr_sexp(offset)),
+        FrameBound::UnboundedFollowing => "unbounded-following".to_string(),
+    }
+}
+
+fn sortby_sexp(sortby: &SortByNode) -> String {
+    let mut s = expr_sexp(&sortby.expr);
+    match &sortby.order {
+        SortOrder::Default => {}
+        SortOrder::Asc => s = format!("(asc {s})"),
+        SortOrder::Desc => s = format!("(desc {s})"),
+        SortOrder::Using { op } => s = format!("(using {} {s})", op_sexp(op)),
+    }
+    match sortby.nulls {
+        NullsOrder::Default => {}
+        NullsOrder::First => s = format!("(nulls-first {s})"),
+        NullsOrder::Last => s = format!("(nulls-last {s})"),
+    }
+    s
+}
+
+fn locking_sexp(locking: &LockingNode) -> String {
+    let mut parts = vec![
+        match locking.strength {
+            LockStrength::KeyShare => "for-key-share",
+            LockStrength::Share => "for-share",
+            LockStrength::NoKeyUpdate => "for-no-key-update",
+            LockStrength::Update => "for-update",
+        }
+        .to_string(),
+    ];
+    if !locking.tables.is_empty() {
+        parts.push(items_sexp("of", &locking.tables, |name| name_sexp(name)));
+    }
+    match locking.wait_policy {
+        LockWaitPolicy::Block => {}
+        LockWaitPolicy::Skip => parts.push("skip-locked".to_string()),
+        LockWaitPolicy::Error => parts.push("nowait".to_string()),
+    }
+    format!("({})", parts.join(" "))
+}
+
+fn name_sexp(name: &[Symbol]) -> String {
+    name.iter().map(|n| &**n).collect::<Vec<_>>().join(".")
+}
+
+pub(crate) fn expr_sexp(expr: &ExprNode) -> String {
+    match &expr.kind {
+        ExprKind::IntegerLiteral { value } => value.to_string(),
+        ExprKind::NumericLiteral { mantissa, scale } => format!("{mantissa}e{}", -scale),
+        ExprKind::StringLiteral { value } => format!("'{value}'"),
+        ExprKind::BitStringLiteral { value, hex } => {
+            format!("{}'{value}'", if *hex { "x" } else { "b" })
+        }
+        ExprKind::BooleanLiteral { value } => value.to_string(),
+        ExprKind::NullLiteral => "null".to_string(),
+        ExprKind::Param { index } => format!("${index}"),
+        ExprKind::Default => "default".to_string(),
+        ExprKind::ColumnRef { fields, star } => match (fields.is_empty(), *star) {
+            (true, _) => "*".to_string(),
+            (false, true) => format!("{}.*", name_sexp(fields)),
+            (false, false) => name_sexp(fields),
+        },
+        ExprKind::Indirection { operand, items } => {
+            let mut parts = vec!["indirection".to_string(), expr_sexp(operand)];
+            parts.extend(items.iter().map(indirection_item_sexp));
+            format!("({})", parts.join(" "))
+        }
+        ExprKind::Array { elements } => items_sexp("array", elements, expr_sexp),
+        ExprKind::ArraySubquery { query } => format!("(array-subquery {})", select_sexp(query)),
+        ExprKind::ScalarSubquery { query } => format!("(subquery {})", select_sexp(query)),
+        ExprKind::Exists { query } => format!("(exists {})", select_sexp(query)),
+        ExprKind::Row { fields, explicit } => items_sexp(
+            if *explicit { "row" } else { "implicit-row" },
+            fields,
+            expr_sexp,
+        ),
+        ExprKind::FuncCall(call) => func_call_sexp(call),
+        ExprKind::NamedArg { name, value } => format!("(=> {} {})", &**name, expr_sexp(value)),
+        ExprKind::UnaryOp { op, operand } => {
+            format!("({} {})", op_sexp(op), expr_sexp(operand))
+        }
+        ExprKind::BinaryOp { op, left, right } => {
+            format!("({} {} {})", op_sexp(op), expr_sexp(left), expr_sexp(right))
+        }
+        ExprKind::And { left, right } => {
+            format!("(and {} {})", expr_sexp(left), expr_sexp(right))
+        }
+        ExprKind::Or { left, right } => format!("(or {} {})", expr_sexp(left), expr_sexp(right)),
+        ExprKind::Not { operand } => format!("(not {})", expr_sexp(operand)),
+        ExprKind::IsNull { operand, negated } => {
+            format!("({}null {})", is_prefix(*negated), expr_sexp(operand))
+        }
+        ExprKind::IsBoolean {
+            operand,
+            value,
+            negated,
+        } => {
+            let value = match value {
+                Some(true) => "true",
+                Some(false) => "false",
+                None => "unknown",
+            };
+            format!("({}{value} {})", is_prefix(*negated), expr_sexp(operand))
+        }
+        ExprKind::IsDistinctFrom {
+            left,
+            right,
+            negated,
+        } => format!(
+            "({}distinct-from {} {})",
+            is_prefix(*negated),
+            expr_sexp(left),
+            expr_sexp(right)
+        ),
+        ExprKind::Between {
+            operand,
+            low,
+            high,
+            negated,
+            symmetric,
+        } => format!(
+            "({}between{} {} {} {})",
+            not_prefix(*negated),
+            if *symmetric { "-symmetric" } else { "" },
+            expr_sexp(operand),
+            expr_sexp(low),
+            expr_sexp(high)
+        ),
+        ExprKind::InList {
+            operand,
+            list,
+            negated,
+        } => format!(
+            "({}in {} {})",
+            not_prefix(*negated),
+            expr_sexp(operand),
+            list_sexp(list)
+        ),
+        ExprKind::InSubquery {
+            operand,
+            query,
+            negated,
+        } => format!(
+            "({}in-subquery {} {})",
+            not_prefix(*negated),
+            expr_sexp(operand),
+            select_sexp(query)
+        ),
+        ExprKind::QuantifiedSubquery {
+            op,
+            all,
+            operand,
+            query,
+        } => format!(
+            "({}-subquery {} {} {})",
+            quantifier(*all),
+            op_sexp(op),
+            expr_sexp(operand),
+            select_sexp(query)
+        ),
+        ExprKind::QuantifiedArray {
+            op,
+            all,
+            operand,
+            array,
+        } => format!(
+            "({} {} {} {})",
+            quantifier(*all),
+            op_sexp(op),
+            expr_sexp(operand),
+            expr_sexp(array)
+        ),
+        ExprKind::Like {
+            kind,
+            operand,
+            pattern,
+            escape,
+            negated,
+        } => {
+            let name = match kind {
+                LikeKind::Like => "like",
+                LikeKind::ILike => "ilike",
+                LikeKind::SimilarTo => "similar-to",
+            };
+            let escape = escape
+                .as_ref()
+                .map_or(String::new(), |e| format!(" {}", expr_sexp(e)));
+            format!(
+                "({}{name} {} {}{escape})",
+                not_prefix(*negated),
+                expr_sexp(operand),
+                expr_sexp(pattern)
+            )
+        }
+        ExprKind::AtTimeZone { operand, zone } => match zone {
+            Some(zone) => format!("(at-time-zone {} {})", expr_sexp(operand), expr_sexp(zone)),
+            None => format!("(at-local {})", expr_sexp(operand)),
+        },
+        ExprKind::Case {
+            operand,
+            whens,
+            default,
+        } => {
+            let mut parts = vec!["case".to_string()];
+            parts.extend(operand.as_deref().map(expr_sexp));
+            parts.extend(whens.iter().map(|when| {
+                format!(
+                    "(when {} {})",
+                    expr_sexp(&when.condition),
+                    expr_sexp(&when.result)
+                )
+            }));
+            parts.extend(
+                default
+                    .as_deref()
+                    .map(|e| format!("(else {})", expr_sexp(e))),
+            );
+            format!("({})", parts.join(" "))
+        }
+        ExprKind::Coalesce { args } => items_sexp("coalesce", args, expr_sexp),
+        ExprKind::NullIf { left, right } => {
+            format!("(nullif {} {})", expr_sexp(left), expr_sexp(right))
+        }
+        ExprKind::Greatest { args } => items_sexp("greatest", args, expr_sexp),
+        ExprKind::Least { args } => items_sexp("least", args, expr_sexp),
+        ExprKind::MergeAction => "(merge-action)".to_string(),
+        ExprKind::SqlValueFunction { kind, precision } => {
+            let name = match kind {
+                SqlValueFunctionKind::CurrentDate => "current_date",
+                SqlValueFunctionKind::CurrentTime => "current_time",
+                SqlValueFunctionKind::CurrentTimestamp => "current_timestamp",
+                SqlValueFunctionKind::LocalTime => "localtime",
+                SqlValueFunctionKind::LocalTimestamp => "localtimestamp",
+            };
+            match precision {
+                Some(precision) => format!("({name} {precision})"),
+                None => name.to_string(),
+            }
+        }
+        ExprKind::Collate { operand, collation } => {
+            format!("(collate {} {})", expr_sexp(operand), name_sexp(collation))
+        }
+        ExprKind::TypeCast { operand, type_name } => {
+            format!(
+                "(cast {} {})",
+                expr_sexp(operand),
+                type_name_sexp(type_name)
+            )
+        }
+        ExprKind::IsJson {
+            operand,
+            item_type,
+            unique_keys,
+            negated,
+        } => {
+            let item_type = match item_type {
+                JsonValueType::Any => "",
+                JsonValueType::Object => "-object",
+                JsonValueType::Array => "-array",
+                JsonValueType::Scalar => "-scalar",
+            };
+            let unique = if *unique_keys { " unique" } else { "" };
+            format!(
+                "({}json{item_type} {}{unique})",
+                is_prefix(*negated),
+                expr_sexp(operand)
+            )
+        }
+        ExprKind::JsonObject(object) => {
+            let mut parts = vec!["json-object".to_string()];
+            parts.extend(object.items.iter().map(json_key_value_sexp));
+            if object.absent_on_null {
+                parts.push("absent-on-null".to_string());
+            }
+            if object.unique_keys {
+                parts.push("unique".to_string());
+            }
+            parts.extend(object.output.as_ref().map(json_output_sexp));
+            format!("({})", parts.join(" "))
+        }
+        ExprKind::JsonArray(array) => {
+            let mut parts = vec!["json-array".to_string()];
+            parts.extend(array.elements.iter().map(json_value_expr_sexp));
+            if !array.absent_on_null {
+                parts.push("null-on-null".to_string());
+            }
+            parts.extend(array.output.as_ref().map(json_output_sexp));
+            format!("({})", parts.join(" "))
+        }
+        ExprKind::JsonArrayQuery(array) => {
+            let mut parts = vec!["json-array".to_string(), select_sexp(&array.query)];
+            parts.extend(array.format.as_ref().map(json_format_sexp));
+            parts.extend(array.output.as_ref().map(json_output_sexp));
+            format!("({})", parts.join(" "))
+        }
+        ExprKind::JsonObjectAgg(agg) => {
+            let mut parts = vec!["json-objectagg".to_string(), json_key_value_sexp(&agg.item)];
+            if agg.absent_on_null {
+                parts.push("absent-on-null".to_string());
+            }
+            if agg.unique_keys {
+                parts.push("unique".to_string());
+            }
+            parts.extend(agg.output.as_ref().map(json_output_sexp));
+            parts.extend(
+                agg.filter
+                    .as_ref()
+                    .map(|f| format!("(filter {})", expr_sexp(f))),
+            );
+            parts.extend(
+                agg.over
+                    .as_ref()
+                    .map(|w| format!("(over {})", window_def_sexp(w))),
+            );
+            format!("({})", parts.join(" "))
+        }
+        ExprKind::JsonArrayAgg(agg) => {
+            let mut parts = vec![
+                "json-arrayagg".to_string(),
+                json_value_expr_sexp(&agg.element),
+            ];
+            if !agg.order_by.is_empty() {
+                parts.push(items_sexp("order-by", &agg.order_by, sortby_sexp));
+            }
+            if !agg.absent_on_null {
+                parts.push("null-on-null".to_string());
+            }
+            parts.extend(agg.output.as_ref().map(json_output_sexp));
+            parts.extend(
+                agg.filter
+                    .as_ref()
+                    .map(|f| format!("(filter {})", expr_sexp(f))),
+            );
+            parts.extend(
+                agg.over
+                    .as_ref()
+                    .map(|w| format!("(over {})", window_def_sexp(w))),
+            );
+            format!("({})", parts.join(" "))
+        }
+        ExprKind::JsonParse { value, unique_keys } => {
+            let unique = if *unique_keys { " unique" } else { "" };
+            format!("(json {}{unique})", json_value_expr_sexp(value))
+        }
+        ExprKind::JsonScalar { operand } => format!("(json-scalar {})", expr_sexp(operand)),
+        ExprKind::JsonSerialize { value, output } => {
+            let mut parts = vec!["json-serialize".to_string(), json_value_expr_sexp(value)];
+            parts.extend(output.as_deref().map(json_output_sexp));
+            format!("({})", parts.join(" "))
+        }
+        ExprKind::JsonFunc(func) => {
+            let name = match func.op {
+                JsonFuncOp::Exists => "json-exists",
+                JsonFuncOp::Query => "json-query",
+                JsonFuncOp::Value => "json-value",
+            };
+            let mut parts = vec![
+                name.to_string(),
+                json_value_expr_sexp(&func.context),
+                expr_sexp(&func.path),
+            ];
+            if !func.passing.is_empty() {
+                parts.push(items_sexp("passing", &func.passing, json_argument_sexp));
+            }
+            parts.extend(func.output.as_ref().map(json_output_sexp));
+            parts.extend(json_wrapper_sexp(func.wrapper));
+            parts.extend(json_quotes_sexp(func.quotes));
+            parts.extend(json_behaviors_sexp(&func.on_empty, &func.on_error));
+            format!("({})", parts.join(" "))
+        }
+    }
+}
+
+/// Renders a value as `v` or `(format-json v)`.
+fn json_value_expr_sexp(value: &JsonValueExpr) -> String {
+    match &value.format {
+        Some(format) => format!("({} {})", json_format_sexp(format), expr_sexp(&value.expr)),
+        None => expr_sexp(&value.expr),
+    }
+}
+
+/// Renders `FORMAT JSON` as `format-json` or `format-json-utf8`.
+fn json_format_sexp(format: &JsonFormat) -> String {
+    let encoding = match format.encoding {
+        Some(JsonEncoding::Utf8) => "-utf8",
+        Some(JsonEncoding::Utf16) => "-utf16",
+        Some(JsonEncoding::Utf32) => "-utf32",
+        None => "",
+    };
+    format!("format-json{encoding}")
+}
+
+fn json_output_sexp(output: &JsonOutput) -> String {
+    let mut parts = vec!["returning".to_string(), type_name_sexp(&output.type_name)];
+    parts.extend(output.format.as_ref().map(json_format_sexp));
+    format!("({})", parts.join(" "))
+}
+
+fn json_key_value_sexp(item: &JsonKeyValue) -> String {
+    format!(
+        "(: {} {})",
+        expr_sexp(&item.key),
+        json_value_expr_sexp(&item.value)
+    )
+}
+
+fn json_argument_sexp(arg: &JsonArgument) -> String {
+    format!("({} {})", json_value_expr_sexp(&arg.value), &*arg.name)
+}
+
+fn json_wrapper_sexp(wrapper: JsonWrapper) -> Option<String> {
+    let wrapper = match wrapper {
+        JsonWrapper::Unspecified => return None,
+        JsonWrapper::None => "without-wrapper",
+        JsonWrapper::Conditional => "with-conditional-wrapper",
+        JsonWrapper::Unconditional => "with-unconditional-wrapper",
+    };
+    Some(wrapper.to_string())
+}
+
+fn json_quotes_sexp(quotes: JsonQuotes) -> Option<String> {
+    let quotes = match quotes {
+        JsonQuotes::Unspecified => return None,
+        JsonQuotes::Keep => "keep-quotes",
+        JsonQuotes::Omit => "omit-quotes",
+    };
+    Some(quotes.to_string())
+}
+
+/// Renders behaviors as `(on-empty b)` and `(on-error b)`.
+fn json_behaviors_sexp(
+    on_empty: &Option<JsonBehavior>,
+    on_error: &Option<JsonBehavior>,
+) -> Vec<String> {
+    let mut parts = Vec::new();
+    if let Some(behavior) = on_empty {
+        parts.push(format!("(on-empty {})", json_behavior_sexp(behavior)));
+    }
+    if let Some(behavior) = on_error {
+        parts.push(format!("(on-error {})", json_behavior_sexp(behavior)));
+    }
+    parts
+}
+
+fn json_behavior_sexp(behavior: &JsonBehavior) -> String {
+    match &behavior.kind {
+        JsonBehaviorKind::Null => "null".to_string(),
+        JsonBehaviorKind::Error => "error".to_string(),
+        JsonBehaviorKind::True => "true".to_string(),
+        JsonBehaviorKind::False => "false".to_string(),
+        JsonBehaviorKind::Unknown => "unknown".to_string(),
+        JsonBehaviorKind::EmptyArray => "empty-array".to_string(),
+        JsonBehaviorKind::EmptyObject => "empty-object".to_string(),
+        JsonBehaviorKind::Default(expr) => format!("(default {})", expr_sexp(expr)),
+    }
+}
+
+/// Renders `JSON_TABLE` like `(json-table v ('$' name) (columns ...))`.
+fn json_table_sexp(table: &JsonTable) -> String {
+    let mut parts = vec![
+        "json-table".to_string(),
+        json_value_expr_sexp(&table.context),
+        json_table_path_sexp(&table.path),
+    ];
+    if !table.passing.is_empty() {
+        parts.push(items_sexp("passing", &table.passing, json_argument_sexp));
+    }
+    parts.push(items_sexp(
+        "columns",
+        &table.columns,
+        json_table_column_sexp,
+    ));
+    parts.extend(json_behaviors_sexp(&None, &table.on_error));
+    format!("({})", parts.join(" "))
+}
+
+fn json_table_path_sexp(path: &JsonTablePath) -> String {
+    match &path.name {
+        Some(name) => format!("('{}' {})", path.path, &**name),
+        None => format!("'{}'", path.path),
+    }
+}
+
+fn json_table_column_sexp(column: &JsonTableColumn) -> String {
+    let mut parts = Vec::new();
+    match &column.kind {
+        JsonTableColumnKind::Ordinality { name } => {
+            parts.extend([name.to_string(), "for-ordinality".to_string()]);
+        }
+        JsonTableColumnKind::Regular {
+            name,
+            type_name,
+            format,
+            path,
+            wrapper,
+            quotes,
+            on_empty,
+            on_error,
+        } => {
+            parts.extend([name.to_string(), type_name_sexp(type_name)]);
+            parts.extend(format.as_ref().map(json_format_sexp));
+            parts.extend(path.as_ref().map(|p| format!("(path '{p}')")));
+            parts.extend(json_wrapper_sexp(*wrapper));
+            parts.extend(json_quotes_sexp(*quotes));
+            parts.extend(json_behaviors_sexp(on_empty, on_error));
+        }
+        JsonTableColumnKind::Exists {
+            name,
+            type_name,
+            path,
+            on_error,
+        } => {
+            parts.extend([
+                name.to_string(),
+                type_name_sexp(type_name),
+                "exists".to_string(),
+            ]);
+            parts.extend(path.as_ref().map(|p| format!("(path '{p}')")));
+            parts.extend(json_behaviors_sexp(&None, on_error));
+        }
+        JsonTableColumnKind::Nested { path, columns } => {
+            parts.extend(["nested".to_string(), json_table_path_sexp(path)]);
+            parts.push(items_sexp("columns", columns, json_table_column_sexp));
+        }
+    }
+    format!("({})", parts.join(" "))
+}
+
+fn indirection_item_sexp(item: &IndirectionItem) -> String {
+    match item {
+        IndirectionItem::Field(name) => format!(".{}", &**name),
+        IndirectionItem::Star => ".*".to_string(),
+        IndirectionItem::Subscript(index) => format!("[{}]", expr_sexp(index)),
+        IndirectionItem::Slice { lower, upper } => {
+            let bound = |b: &Option<Box<ExprNode>>| b.as_deref().map_or(String::new(), expr_sexp);
+            format!("[{}:{}]", bound(lower), bound(upper))
+        }
+    }
+}
+
+/// Renders a type name like `(pg_catalog.varchar 10)[]` or `(setof t)`.
+pub(crate) fn type_name_sexp(type_name: &TypeName) -> String {
+    let mut base = name_sexp(&type_name.name);
+    if type_name.pct_type {
+        base.push_str("%type");
+    }
+    let mut parts = vec![base];
+    if let Some(fields) = type_name.interval_fields {
+        let fields = match fields {
+            IntervalFields::Year => "year",
+            IntervalFields::Month => "month",
+            IntervalFields::Day => "day",
+            IntervalFields::Hour => "hour",
+            IntervalFields::Minute => "minute",
+            IntervalFields::Second => "second",
+            IntervalFields::YearToMonth => "year-to-month",
+            IntervalFields::DayToHour => "day-to-hour",
+            IntervalFields::DayToMinute => "day-to-minute",
+            IntervalFields::DayToSecond => "day-to-second",
+            IntervalFields::HourToMinute => "hour-to-minute",
+            IntervalFields::HourToSecond => "hour-to-second",
+            IntervalFields::MinuteToSecond => "minute-to-second",
+        };
+        parts.push(fields.to_string());
+    }
+    parts.extend(type_name.modifiers.iter().map(expr_sexp));
+    let mut sexp = if parts.len() == 1 {
+        parts.pop().unwrap()
+    } else {
+        format!("({})", parts.join(" "))
+    };
+    for bound in &type_name.array_bounds {
+        match bound {
+            Some(size) => sexp.push_str(&format!("[{size}]")),
+            None => sexp.push_str("[]"),
+        }
+    }
+    if type_name.setof {
+        sexp = format!("(setof {sexp})");
+    }
+    sexp
+}
+
+pub(crate) fn list_sexp(exprs: &[ExprNode]) -> String {
+    let items = exprs.iter().map(expr_sexp).collect::<Vec<_>>();
+    format!("({})", items.join(" "))
+}
+
+fn op_sexp(op: &OperatorName) -> String {
+    let mut names = op.schema.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    names.push(op.name.clone());
+    names.join(".")
+}
+
+fn quantifier(all: bool) -> &'static str {
+    if all { "all" } else { "any" }
+}
+
+fn is_prefix(negated: bool) -> &'static str {
+    if negated { "is-not-" } else { "is-" }
+}
+
+fn not_prefix(negated: bool) -> &'static str {
+    if negated { "not-" } else { "" }
+}
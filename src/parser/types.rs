@@ -0,0 +1,794 @@
+// Type names and typed literals: `Typename`, `func_type`, and the typed forms of `AexprConst`
+// in gram.y.
+
+use crate::{
+    Symbol,
+    ast::{ExprKind, ExprNode, FuncCall, IntervalFields, TypeName},
+    diag::{CodeDiagnostic, CodeDiagnostics},
+    pos::CodeRange,
+    token::{Token, TokenKind},
+};
+
+use super::{ParseResult, Parser, join};
+
+impl Parser<'_> {
+    /// Parses a type name (`Typename`).
+    pub(super) fn parse_type_name(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TypeName> {
+        self.parse_type_name_with(tok0, false, diags)
+    }
+
+    /// Parses a type name in a function signature (`func_type`), which can also be
+    /// the type of a column as in `t.a%TYPE`.
+    #[cfg_attr(
+        not(test),
+        expect(dead_code, reason = "function definitions are not parsed yet")
+    )]
+    pub(super) fn parse_func_type(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TypeName> {
+        self.parse_type_name_with(tok0, true, diags)
+    }
+
+    fn parse_type_name_with(
+        &mut self,
+        tok0: Token,
+        allow_pct_type: bool,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TypeName> {
+        let start = tok0.range.start;
+        let setof = tok0.is_keyword(&Symbol::KEYWORD_setof);
+        let tok1 = if setof {
+            self.tokens.next_token(diags)
+        } else {
+            tok0
+        };
+        let (mut type_name, tok2) = if starts_special_type_name(&tok1) {
+            self.parse_special_type_name(tok1, false, diags)?
+        } else {
+            let name_start = tok1.range.start;
+            let (name, tok2) = self.parse_generic_type_name(tok1, diags)?;
+            if allow_pct_type && name.len() > 1 && tok2.kind == TokenKind::Percent {
+                let tok3 = self.tokens.next_token(diags);
+                let tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_type, diags)?;
+                let type_name = TypeName {
+                    setof,
+                    pct_type: true,
+                    ..self.simple_type_name(name, Vec::new(), start)
+                };
+                return Ok((type_name, tok4));
+            }
+            let (modifiers, tok3) = self.parse_opt_type_modifiers(tok2, diags)?;
+            (self.simple_type_name(name, modifiers, name_start), tok3)
+        };
+        let (array_bounds, tok3) = self.parse_opt_array_bounds(tok2, diags)?;
+        type_name.array_bounds = array_bounds;
+        type_name.setof = setof;
+        type_name.range = CodeRange {
+            start,
+            end: self.tokens.prev_end(),
+        };
+        Ok((type_name, tok3))
+    }
+
+    /// Parses `type_function_name attrs`, the name of `GenericType`.
+    fn parse_generic_type_name(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<Symbol>> {
+        let mut name = match tok0.kind {
+            TokenKind::Identifier { name, quoted } if quoted || name.is_type_function_name() => {
+                vec![name]
+            }
+            _ => {
+                self.syntax_error(&tok0, diags);
+                return Err(tok0);
+            }
+        };
+        let mut tok1 = self.tokens.next_token(diags);
+        while tok1.kind == TokenKind::Dot {
+            let tok2 = self.tokens.next_token(diags);
+            // Any keyword can be used after a dot (`attr_name`).
+            let TokenKind::Identifier { name: attr, .. } = tok2.kind else {
+                self.syntax_error(&tok2, diags);
+                return Err(tok2);
+            };
+            name.push(attr);
+            tok1 = self.tokens.next_token(diags);
+        }
+        Ok((name, tok1))
+    }
+
+    /// Parses a type name with a special syntax, such as `DOUBLE PRECISION` or
+    /// `TIMESTAMP(3) WITH TIME ZONE` (`Numeric`, `Bit`, `Character`, `ConstDatetime`,
+    /// `ConstInterval`, and `JsonType`).
+    ///
+    /// In a typed literal (`constant`), `INTERVAL` fields are not parsed because they follow
+    /// the literal, and `CHAR` and `BIT` get no default length.
+    fn parse_special_type_name(
+        &mut self,
+        tok0: Token,
+        constant: bool,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<TypeName> {
+        let keyword = tok0.keyword().expect("special type name must be a keyword");
+        let start = tok0.range.start;
+        let mut tok1 = self.tokens.next_token(diags);
+        let mut modifiers = Vec::new();
+        let mut interval_fields = None;
+        let name = match *keyword {
+            Symbol::KEYWORD_int | Symbol::KEYWORD_integer => "int4",
+            Symbol::KEYWORD_smallint => "int2",
+            Symbol::KEYWORD_bigint => "int8",
+            Symbol::KEYWORD_real => "float4",
+            Symbol::KEYWORD_boolean => "bool",
+            Symbol::KEYWORD_json => "json",
+            Symbol::KEYWORD_double => {
+                tok1 = self.expect_keyword(tok1, Symbol::KEYWORD_precision, diags)?;
+                "float8"
+            }
+            Symbol::KEYWORD_float => {
+                if tok1.kind != TokenKind::LParen {
+                    "float8"
+                } else {
+                    let tok2 = self.tokens.next_token(diags);
+                    let range = tok2.range;
+                    let (precision, tok3) = self.parse_iconst(tok2, diags)?;
+                    tok1 = self.expect_token(tok3, TokenKind::RParen, diags)?;
+                    if precision < 1 {
+                        diags.add(CodeDiagnostic::FloatPrecisionTooSmall { range });
+                    } else if precision > 53 {
+                        diags.add(CodeDiagnostic::FloatPrecisionTooLarge { range });
+                    }
+                    if precision <= 24 { "float4" } else { "float8" }
+                }
+            }
+            Symbol::KEYWORD_decimal | Symbol::KEYWORD_dec | Symbol::KEYWORD_numeric => {
+                (modifiers, tok1) = self.parse_opt_type_modifiers(tok1, diags)?;
+                "numeric"
+            }
+            Symbol::KEYWORD_bit => {
+                let varying = tok1.is_keyword(&Symbol::KEYWORD_varying);
+                if varying {
+                    tok1 = self.tokens.next_token(diags);
+                }
+                (modifiers, tok1) = self.parse_opt_type_modifiers(tok1, diags)?;
+                if varying {
+                    "varbit"
+                } else {
+                    if modifiers.is_empty() && !constant {
+                        modifiers.push(int_const(1, tok0.range));
+                    }
+                    "bit"
+                }
+            }
+            Symbol::KEYWORD_character
+            | Symbol::KEYWORD_char
+            | Symbol::KEYWORD_varchar
+            | Symbol::KEYWORD_national
+            | Symbol::KEYWORD_nchar => {
+                if *keyword == Symbol::KEYWORD_national {
+                    if !(tok1.is_keyword(&Symbol::KEYWORD_character)
+                        || tok1.is_keyword(&Symbol::KEYWORD_char))
+                    {
+                        self.syntax_error(&tok1, diags);
+                        return Err(tok1);
+                    }
+                    tok1 = self.tokens.next_token(diags);
+                }
+                let mut varying = *keyword == Symbol::KEYWORD_varchar;
+                if !varying && tok1.is_keyword(&Symbol::KEYWORD_varying) {
+                    varying = true;
+                    tok1 = self.tokens.next_token(diags);
+                }
+                if tok1.kind == TokenKind::LParen {
+                    let (length, tok2) = self.parse_paren_iconst(tok1, diags)?;
+                    modifiers.push(length);
+                    tok1 = tok2;
+                } else if !varying && !constant {
+                    modifiers.push(int_const(1, tok0.range));
+                }
+                if varying { "varchar" } else { "bpchar" }
+            }
+            Symbol::KEYWORD_time | Symbol::KEYWORD_timestamp => {
+                if tok1.kind == TokenKind::LParen {
+                    let (precision, tok2) = self.parse_paren_iconst(tok1, diags)?;
+                    modifiers.push(precision);
+                    tok1 = tok2;
+                }
+                let with_time_zone = tok1.kind == TokenKind::WithLa;
+                if with_time_zone || tok1.kind == TokenKind::WithoutLa {
+                    let tok2 = self.tokens.next_token(diags);
+                    let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_time, diags)?;
+                    tok1 = self.expect_keyword(tok3, Symbol::KEYWORD_zone, diags)?;
+                }
+                match (*keyword == Symbol::KEYWORD_timestamp, with_time_zone) {
+                    (true, true) => "timestamptz",
+                    (true, false) => "timestamp",
+                    (false, true) => "timetz",
+                    (false, false) => "time",
+                }
+            }
+            Symbol::KEYWORD_interval => {
+                if tok1.kind == TokenKind::LParen {
+                    let (precision, tok2) = self.parse_paren_iconst(tok1, diags)?;
+                    modifiers.push(precision);
+                    tok1 = tok2;
+                } else if !constant {
+                    let (fields, tok2) = self.parse_opt_interval(tok1, diags)?;
+                    if let Some((fields, precision)) = fields {
+                        interval_fields = Some(fields);
+                        modifiers.extend(precision);
+                    }
+                    tok1 = tok2;
+                }
+                "interval"
+            }
+            _ => unreachable!("not a special type name: {}", &**keyword),
+        };
+        let type_name = TypeName {
+            interval_fields,
+            ..self.simple_type_name(system_type_name(name), modifiers, start)
+        };
+        Ok((type_name, tok1))
+    }
+
+    /// Parses the fields of an `INTERVAL` type (`opt_interval`), along with the precision of
+    /// `SECOND (p)`.
+    fn parse_opt_interval(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Option<(IntervalFields, Option<ExprNode>)>> {
+        let first = match tok0.keyword() {
+            Some(&Symbol::KEYWORD_year) => IntervalFields::Year,
+            Some(&Symbol::KEYWORD_month) => IntervalFields::Month,
+            Some(&Symbol::KEYWORD_day) => IntervalFields::Day,
+            Some(&Symbol::KEYWORD_hour) => IntervalFields::Hour,
+            Some(&Symbol::KEYWORD_minute) => IntervalFields::Minute,
+            Some(&Symbol::KEYWORD_second) => IntervalFields::Second,
+            _ => return Ok((None, tok0)),
+        };
+        let mut tok1 = self.tokens.next_token(diags);
+        let mut fields = first;
+        if tok1.is_keyword(&Symbol::KEYWORD_to)
+            && matches!(
+                first,
+                IntervalFields::Year
+                    | IntervalFields::Day
+                    | IntervalFields::Hour
+                    | IntervalFields::Minute
+            )
+        {
+            let tok2 = self.tokens.next_token(diags);
+            fields = match (first, tok2.keyword()) {
+                (IntervalFields::Year, Some(&Symbol::KEYWORD_month)) => IntervalFields::YearToMonth,
+                (IntervalFields::Day, Some(&Symbol::KEYWORD_hour)) => IntervalFields::DayToHour,
+                (IntervalFields::Day, Some(&Symbol::KEYWORD_minute)) => IntervalFields::DayToMinute,
+                (IntervalFields::Day, Some(&Symbol::KEYWORD_second)) => IntervalFields::DayToSecond,
+                (IntervalFields::Hour, Some(&Symbol::KEYWORD_minute)) => {
+                    IntervalFields::HourToMinute
+                }
+                (IntervalFields::Hour, Some(&Symbol::KEYWORD_second)) => {
+                    IntervalFields::HourToSecond
+                }
+                (IntervalFields::Minute, Some(&Symbol::KEYWORD_second)) => {
+                    IntervalFields::MinuteToSecond
+                }
+                _ => {
+                    self.syntax_error(&tok2, diags);
+                    return Err(tok2);
+                }
+            };
+            tok1 = self.tokens.next_token(diags);
+        }
+        let mut precision = None;
+        if tok1.kind == TokenKind::LParen
+            && matches!(
+                fields,
+                IntervalFields::Second
+                    | IntervalFields::DayToSecond
+                    | IntervalFields::HourToSecond
+                    | IntervalFields::MinuteToSecond
+            )
+        {
+            let (expr, tok2) = self.parse_paren_iconst(tok1, diags)?;
+            precision = Some(expr);
+            tok1 = tok2;
+        }
+        Ok((Some((fields, precision)), tok1))
+    }
+
+    /// Parses `( expr_list )` after a type name if any (`opt_type_modifiers`).
+    fn parse_opt_type_modifiers(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<ExprNode>> {
+        if tok0.kind != TokenKind::LParen {
+            return Ok((Vec::new(), tok0));
+        }
+        let tok1 = self.tokens.next_token(diags);
+        let (modifiers, tok2) = self.parse_expr_list(tok1, diags)?;
+        let tok3 = self.expect_token(tok2, TokenKind::RParen, diags)?;
+        Ok((modifiers, tok3))
+    }
+
+    /// Parses array dimensions after a type name (`opt_array_bounds` and `ARRAY [n]`).
+    fn parse_opt_array_bounds(
+        &mut self,
+        mut tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<Vec<Option<i32>>> {
+        if tok0.is_keyword(&Symbol::KEYWORD_array) {
+            let tok1 = self.tokens.next_token(diags);
+            if tok1.kind != TokenKind::LBracket {
+                return Ok((vec![None], tok1));
+            }
+            let tok2 = self.tokens.next_token(diags);
+            let (size, tok3) = self.parse_iconst(tok2, diags)?;
+            let tok4 = self.expect_token(tok3, TokenKind::RBracket, diags)?;
+            return Ok((vec![Some(size)], tok4));
+        }
+        let mut bounds = Vec::new();
+        while tok0.kind == TokenKind::LBracket {
+            let tok1 = self.tokens.next_token(diags);
+            if tok1.kind == TokenKind::RBracket {
+                bounds.push(None);
+                tok0 = self.tokens.next_token(diags);
+            } else {
+                let (size, tok2) = self.parse_iconst(tok1, diags)?;
+                bounds.push(Some(size));
+                tok0 = self.expect_token(tok2, TokenKind::RBracket, diags)?;
+            }
+        }
+        Ok((bounds, tok0))
+    }
+
+    /// Parses a typed literal of a type name with a special syntax, such as
+    /// `TIMESTAMP '2000-01-01'` or `INTERVAL '1' DAY`
+    /// (`ConstTypename Sconst` and the `ConstInterval` forms in `AexprConst`).
+    pub(super) fn parse_special_type_const(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let is_interval = tok0.is_keyword(&Symbol::KEYWORD_interval);
+        let (mut type_name, tok1) = self.parse_special_type_name(tok0, true, diags)?;
+        let (literal, mut tok2) = self.parse_sconst(tok1, diags)?;
+        // `INTERVAL (p) 'literal'` cannot have fields.
+        if is_interval && type_name.modifiers.is_empty() {
+            let (fields, tok3) = self.parse_opt_interval(tok2, diags)?;
+            if let Some((fields, precision)) = fields {
+                type_name.interval_fields = Some(fields);
+                type_name.modifiers.extend(precision);
+            }
+            tok2 = tok3;
+        }
+        let range = CodeRange {
+            start: type_name.range.start,
+            end: self.tokens.prev_end(),
+        };
+        Ok((type_cast(literal, type_name, range), tok2))
+    }
+
+    /// Parses a typed literal `name 'literal'` of a generic type after its name.
+    pub(super) fn parse_generic_type_const(
+        &mut self,
+        name: Vec<Symbol>,
+        name_range: CodeRange,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let type_name = self.simple_type_name(name, Vec::new(), name_range.start);
+        let (literal, tok1) = self.parse_sconst(tok0, diags)?;
+        let range = join(name_range, literal.range);
+        Ok((type_cast(literal, type_name, range), tok1))
+    }
+
+    /// Parses a typed literal `name(modifiers) 'literal'` after the function-call-like part,
+    /// which has been parsed as a function call.
+    pub(super) fn parse_func_type_const(
+        &mut self,
+        call: FuncCall,
+        name_range: CodeRange,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        for arg in &call.args {
+            if let ExprKind::NamedArg { .. } = arg.kind {
+                diags.add(CodeDiagnostic::TypeModifierWithParameterName { range: arg.range });
+            }
+        }
+        if let (Some(first), Some(last)) = (call.order_by.first(), call.order_by.last()) {
+            let range = join(first.range, last.range);
+            diags.add(CodeDiagnostic::TypeModifierWithOrderBy { range });
+        }
+        let type_name = self.simple_type_name(call.name, call.args, name_range.start);
+        let (literal, tok1) = self.parse_sconst(tok0, diags)?;
+        let range = join(name_range, literal.range);
+        Ok((type_cast(literal, type_name, range), tok1))
+    }
+
+    /// Parses a string literal (`Sconst`).
+    fn parse_sconst(&mut self, tok0: Token, diags: &mut CodeDiagnostics) -> ParseResult<ExprNode> {
+        match tok0.kind {
+            TokenKind::String(value) | TokenKind::DollarString(value) => {
+                let expr = ExprNode {
+                    kind: ExprKind::StringLiteral { value },
+                    range: tok0.range,
+                };
+                Ok((expr, self.tokens.next_token(diags)))
+            }
+            _ => {
+                self.syntax_error(&tok0, diags);
+                Err(tok0)
+            }
+        }
+    }
+
+    /// Parses `( Iconst )` into an integer literal.
+    fn parse_paren_iconst(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<ExprNode> {
+        let tok1 = self.expect_token(tok0, TokenKind::LParen, diags)?;
+        let range = tok1.range;
+        let (value, tok2) = self.parse_iconst(tok1, diags)?;
+        let tok3 = self.expect_token(tok2, TokenKind::RParen, diags)?;
+        Ok((int_const(value, range), tok3))
+    }
+
+    /// Parses an integer literal that fits in 32 bits (`Iconst`).
+    pub(super) fn parse_iconst(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<i32> {
+        if let TokenKind::Integer(value) = &tok0.kind
+            && let Ok(value) = i32::try_from(value)
+        {
+            return Ok((value, self.tokens.next_token(diags)));
+        }
+        self.syntax_error(&tok0, diags);
+        Err(tok0)
+    }
+
+    /// A type name without array bounds, ending at the last consumed token.
+    fn simple_type_name(
+        &self,
+        name: Vec<Symbol>,
+        modifiers: Vec<ExprNode>,
+        start: usize,
+    ) -> TypeName {
+        TypeName {
+            name,
+            modifiers,
+            interval_fields: None,
+            array_bounds: Vec::new(),
+            setof: false,
+            pct_type: false,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        }
+    }
+}
+
+/// Whether the token is a keyword starting a type name with a special syntax,
+/// such as `DOUBLE` in `DOUBLE PRECISION`.
+pub(super) fn starts_special_type_name(tok: &Token) -> bool {
+    matches!(
+        tok.keyword(),
+        Some(
+            &(Symbol::KEYWORD_int
+                | Symbol::KEYWORD_integer
+                | Symbol::KEYWORD_smallint
+                | Symbol::KEYWORD_bigint
+                | Symbol::KEYWORD_real
+                | Symbol::KEYWORD_float
+                | Symbol::KEYWORD_double
+                | Symbol::KEYWORD_decimal
+                | Symbol::KEYWORD_dec
+                | Symbol::KEYWORD_numeric
+                | Symbol::KEYWORD_boolean
+                | Symbol::KEYWORD_bit
+                | Symbol::KEYWORD_character
+                | Symbol::KEYWORD_char
+                | Symbol::KEYWORD_varchar
+                | Symbol::KEYWORD_national
+                | Symbol::KEYWORD_nchar
+                | Symbol::KEYWORD_time
+                | Symbol::KEYWORD_timestamp
+                | Symbol::KEYWORD_interval
+                | Symbol::KEYWORD_json)
+        )
+    )
+}
+
+/// Whether the token following a special type name keyword makes it a typed literal
+/// such as `INT '1'` or `DOUBLE PRECISION '1'`, rather than a column name such as `time`.
+pub(super) fn continues_special_type_const(tok: &Token) -> bool {
+    match &tok.kind {
+        TokenKind::String(_)
+        | TokenKind::DollarString(_)
+        | TokenKind::LParen
+        | TokenKind::WithLa
+        | TokenKind::WithoutLa => true,
+        _ => matches!(
+            tok.keyword(),
+            Some(
+                &(Symbol::KEYWORD_precision
+                    | Symbol::KEYWORD_varying
+                    | Symbol::KEYWORD_character
+                    | Symbol::KEYWORD_char)
+            )
+        ),
+    }
+}
+
+/// Whether the token is a string literal (`Sconst`).
+pub(super) fn is_sconst(tok: &Token) -> bool {
+    matches!(tok.kind, TokenKind::String(_) | TokenKind::DollarString(_))
+}
+
+/// The name of a type in `pg_catalog` (`SystemTypeName`).
+pub(super) fn system_type_name(name: &str) -> Vec<Symbol> {
+    vec![Symbol::from("pg_catalog"), Symbol::from(name)]
+}
+
+pub(super) fn type_cast(operand: ExprNode, type_name: TypeName, range: CodeRange) -> ExprNode {
+    ExprNode {
+        kind: ExprKind::TypeCast {
+            operand: Box::new(operand),
+            type_name: Box::new(type_name),
+        },
+        range,
+    }
+}
+
+pub(super) fn int_const(value: i32, range: CodeRange) -> ExprNode {
+    ExprNode {
+        kind: ExprKind::IntegerLiteral {
+            value: value.into(),
+        },
+        range,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parser::sexp::{
+            parse_expr_errors, parse_expr_sexp, parse_expr_src, syntax_error, type_name_sexp,
+        },
+        pos::pos,
+    };
+
+    use super::*;
+
+    /// Renders the type of `x::type`.
+    fn type_sexp(type_src: &str) -> String {
+        let sexp = parse_expr_sexp(&format!("x::{type_src}"));
+        let inner = sexp.strip_prefix("(cast x ").unwrap();
+        inner.strip_suffix(')').unwrap().to_string()
+    }
+
+    #[test]
+    fn test_cast() {
+        assert_eq!(
+            parse_expr_sexp("cast(a + 1 as text)"),
+            "(cast (+ a 1) text)"
+        );
+        assert_eq!(parse_expr_sexp("a::int"), "(cast a pg_catalog.int4)");
+        assert_eq!(parse_expr_sexp("a::text::s.t"), "(cast (cast a text) s.t)");
+        // `::` binds tighter than unary minus.
+        assert_eq!(parse_expr_sexp("-a::int"), "(- (cast a pg_catalog.int4))");
+        assert_eq!(
+            parse_expr_sexp("a + b::int"),
+            "(+ a (cast b pg_catalog.int4))"
+        );
+    }
+
+    #[test]
+    fn test_cast_range() {
+        let src = "1 + cast(a as int)";
+        let expr = parse_expr_src(src, &mut CodeDiagnostics::new()).unwrap();
+        let ExprKind::BinaryOp { right, .. } = expr.kind else {
+            panic!("not a binary operation: {expr:?}");
+        };
+        assert_eq!(right.range, pos(src, "cast(a as int)", 0));
+        let ExprKind::TypeCast { type_name, .. } = right.kind else {
+            panic!("not a type cast: {right:?}");
+        };
+        assert_eq!(type_name.range, pos(src, "int", 0));
+    }
+
+    #[test]
+    fn test_special_type_names() {
+        assert_eq!(type_sexp("integer"), "pg_catalog.int4");
+        assert_eq!(type_sexp("bigint"), "pg_catalog.int8");
+        assert_eq!(type_sexp("double precision"), "pg_catalog.float8");
+        assert_eq!(type_sexp("float(24)"), "pg_catalog.float4");
+        assert_eq!(type_sexp("numeric(10, 2)"), "(pg_catalog.numeric 10 2)");
+        assert_eq!(type_sexp("character varying(5)"), "(pg_catalog.varchar 5)");
+        assert_eq!(type_sexp("national char"), "(pg_catalog.bpchar 1)");
+        assert_eq!(type_sexp("bit varying"), "pg_catalog.varbit");
+        assert_eq!(type_sexp("bit"), "(pg_catalog.bit 1)");
+        assert_eq!(
+            type_sexp("timestamp(3) with time zone"),
+            "(pg_catalog.timestamptz 3)"
+        );
+        assert_eq!(type_sexp("time without time zone"), "pg_catalog.time");
+        assert_eq!(type_sexp("json"), "pg_catalog.json");
+    }
+
+    #[test]
+    fn test_interval() {
+        assert_eq!(type_sexp("interval"), "pg_catalog.interval");
+        assert_eq!(type_sexp("interval(3)"), "(pg_catalog.interval 3)");
+        assert_eq!(type_sexp("interval month"), "(pg_catalog.interval month)");
+        assert_eq!(
+            type_sexp("interval day to second(6)"),
+            "(pg_catalog.interval day-to-second 6)"
+        );
+        let src = "x::interval month to day";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "to", 0));
+        let src = "x::interval day to year";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "year", 0));
+    }
+
+    #[test]
+    fn test_generic_type_names() {
+        assert_eq!(type_sexp("\"int\""), "int");
+        assert_eq!(type_sexp("s.t(1, 'a')"), "(s.t 1 'a')");
+        // Type function name keywords can be type names, but column name keywords cannot.
+        assert_eq!(type_sexp("left"), "left");
+        let src = "x::between";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "between", 0));
+    }
+
+    #[test]
+    fn test_array_bounds() {
+        assert_eq!(type_sexp("int[]"), "pg_catalog.int4[]");
+        assert_eq!(type_sexp("varchar(3)[2][]"), "(pg_catalog.varchar 3)[2][]");
+        assert_eq!(type_sexp("int array"), "pg_catalog.int4[]");
+        assert_eq!(type_sexp("int array[3]"), "pg_catalog.int4[3]");
+        let src = "x::int[a]";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "a", 0));
+    }
+
+    #[test]
+    fn test_setof() {
+        assert_eq!(
+            parse_expr_sexp("cast(a as setof t[])"),
+            "(cast a (setof t[]))"
+        );
+    }
+
+    #[test]
+    fn test_pct_type() {
+        let src = "setof t.a%type";
+        let mut diags = CodeDiagnostics::new();
+        let mut parser = Parser::new(src);
+        let tok0 = parser.tokens.next_token(&mut diags);
+        let (type_name, tok1) = parser.parse_func_type(tok0, &mut diags).unwrap();
+        assert_eq!(diags.diagnostics, vec![]);
+        assert_eq!(tok1.kind, TokenKind::Eof);
+        assert_eq!(type_name_sexp(&type_name), "(setof t.a%type)");
+        assert_eq!(type_name.range, pos(src, src, 0));
+        // `%` is an operator elsewhere.
+        assert_eq!(parse_expr_sexp("x::t.a % type"), "(% (cast x t.a) type)");
+    }
+
+    #[test]
+    fn test_typed_literals() {
+        assert_eq!(parse_expr_sexp("int '1'"), "(cast '1' pg_catalog.int4)");
+        assert_eq!(
+            parse_expr_sexp("date '2000-01-01'"),
+            "(cast '2000-01-01' date)"
+        );
+        assert_eq!(parse_expr_sexp("s.t $$x$$"), "(cast 'x' s.t)");
+        assert_eq!(
+            parse_expr_sexp("double precision '1'"),
+            "(cast '1' pg_catalog.float8)"
+        );
+        assert_eq!(
+            parse_expr_sexp("timestamp(3) with time zone 'now'"),
+            "(cast 'now' (pg_catalog.timestamptz 3))"
+        );
+        // No default length is given in literals.
+        assert_eq!(
+            parse_expr_sexp("char 'abc'"),
+            "(cast 'abc' pg_catalog.bpchar)"
+        );
+        assert_eq!(parse_expr_sexp("N'abc'"), "(cast 'abc' pg_catalog.bpchar)");
+        assert_eq!(
+            parse_expr_sexp("varchar(3) 'a'"),
+            "(cast 'a' (pg_catalog.varchar 3))"
+        );
+        assert_eq!(parse_expr_sexp("t(1, 2) 'a'"), "(cast 'a' (t 1 2))");
+        // Type function name keywords can be type names.
+        assert_eq!(parse_expr_sexp("left 'a'"), "(cast 'a' left)");
+    }
+
+    #[test]
+    fn test_interval_literals() {
+        assert_eq!(
+            parse_expr_sexp("interval '1' day"),
+            "(cast '1' (pg_catalog.interval day))"
+        );
+        assert_eq!(
+            parse_expr_sexp("interval '1:00' hour to second(3)"),
+            "(cast '1:00' (pg_catalog.interval hour-to-second 3))"
+        );
+        assert_eq!(
+            parse_expr_sexp("interval(3) '1'"),
+            "(cast '1' (pg_catalog.interval 3))"
+        );
+    }
+
+    #[test]
+    fn test_special_type_names_as_columns() {
+        assert_eq!(parse_expr_sexp("time + interval"), "(+ time interval)");
+        assert_eq!(parse_expr_sexp("double"), "double");
+        let src = "int 1";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "1", 0));
+    }
+
+    #[test]
+    fn test_typed_literal_range() {
+        let src = "1 + interval '1' day";
+        let expr = parse_expr_src(src, &mut CodeDiagnostics::new()).unwrap();
+        let ExprKind::BinaryOp { right, .. } = expr.kind else {
+            panic!("not a binary operation: {expr:?}");
+        };
+        assert_eq!(right.range, pos(src, "interval '1' day", 0));
+    }
+
+    #[test]
+    fn test_type_modifier_errors() {
+        let src = "t(a => 1) 'x'";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::TypeModifierWithParameterName {
+                range: pos(src, "a => 1", 0),
+            }]
+        );
+        let src = "t(1 order by a) 'x'";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::TypeModifierWithOrderBy {
+                range: pos(src, "a", 0),
+            }]
+        );
+        let src = "count(*) 'x'";
+        assert_eq!(parse_expr_errors(src), syntax_error(src, "'x'", 0));
+    }
+
+    #[test]
+    fn test_float_precision() {
+        let src = "x::float(0)";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::FloatPrecisionTooSmall {
+                range: pos(src, "0", 0),
+            }]
+        );
+        let src = "x::float(54)";
+        assert_eq!(
+            parse_expr_errors(src),
+            vec![CodeDiagnostic::FloatPrecisionTooLarge {
+                range: pos(src, "54", 0),
+            }]
+        );
+    }
+}
@@ -0,0 +1,271 @@
+// Common table expressions: `with_clause` in gram.y.
+
+use crate::{
+    Symbol,
+    ast::{CteCycle, CteMaterialized, CteNode, CteSearch, WithClause},
+    diag::CodeDiagnostics,
+    pos::CodeRange,
+    token::{Token, TokenKind},
+};
+
+use super::{ParseResult, Parser};
+
+impl Parser<'_> {
+    /// Parses `WITH [RECURSIVE] cte, ...` (`with_clause`).
+    pub(super) fn parse_with_clause(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<WithClause> {
+        let start = tok0.range.start;
+        if !starts_with_clause(&tok0) {
+            self.syntax_error(&tok0, diags);
+            return Err(tok0);
+        }
+        let tok1 = self.tokens.next_token(diags);
+        let (recursive, tok2) = if tok1.is_keyword(&Symbol::KEYWORD_recursive) {
+            (true, self.tokens.next_token(diags))
+        } else {
+            (false, tok1)
+        };
+        let (ctes, tok3) = self.parse_comma_list(tok2, diags, Self::parse_cte)?;
+        let with = WithClause {
+            recursive,
+            ctes,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((with, tok3))
+    }
+
+    /// Parses a common table expression (`common_table_expr`).
+    fn parse_cte(&mut self, tok0: Token, diags: &mut CodeDiagnostics) -> ParseResult<CteNode> {
+        let start = tok0.range.start;
+        let (name, tok1) = self.parse_col_id(tok0, diags)?;
+        let (columns, tok2) = if tok1.kind == TokenKind::LParen {
+            self.parse_name_list_with_parens(tok1, diags)?
+        } else {
+            (Vec::new(), tok1)
+        };
+        let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_as, diags)?;
+        let (materialized, tok4) = match tok3.keyword() {
+            Some(&Symbol::KEYWORD_materialized) => {
+                (CteMaterialized::Always, self.tokens.next_token(diags))
+            }
+            Some(&Symbol::KEYWORD_not) => {
+                let tok4 = self.tokens.next_token(diags);
+                let tok5 = self.expect_keyword(tok4, Symbol::KEYWORD_materialized, diags)?;
+                (CteMaterialized::Never, tok5)
+            }
+            _ => (CteMaterialized::Default, tok3),
+        };
+        let tok5 = self.expect_token(tok4, TokenKind::LParen, diags)?;
+        let (query, tok6) = self.parse_preparable_stmt(tok5, diags)?;
+        let tok7 = self.expect_token(tok6, TokenKind::RParen, diags)?;
+        let (search, tok8) = if tok7.is_keyword(&Symbol::KEYWORD_search) {
+            let (search, tok8) = self.parse_cte_search(tok7, diags)?;
+            (Some(search), tok8)
+        } else {
+            (None, tok7)
+        };
+        let (cycle, tok9) = if tok8.is_keyword(&Symbol::KEYWORD_cycle) {
+            let (cycle, tok9) = self.parse_cte_cycle(tok8, diags)?;
+            (Some(cycle), tok9)
+        } else {
+            (None, tok8)
+        };
+        let cte = CteNode {
+            name,
+            columns,
+            materialized,
+            query: Box::new(query),
+            search,
+            cycle,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((cte, tok9))
+    }
+
+    /// Parses `SEARCH {DEPTH | BREADTH} FIRST BY columns SET column` (`opt_search_clause`).
+    fn parse_cte_search(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<CteSearch> {
+        let start = tok0.range.start;
+        let tok1 = self.expect_keyword(tok0, Symbol::KEYWORD_search, diags)?;
+        let breadth_first = match tok1.keyword() {
+            Some(&Symbol::KEYWORD_depth) => false,
+            Some(&Symbol::KEYWORD_breadth) => true,
+            _ => {
+                self.syntax_error(&tok1, diags);
+                return Err(tok1);
+            }
+        };
+        let tok2 = self.tokens.next_token(diags);
+        let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_first, diags)?;
+        let tok4 = self.expect_keyword(tok3, Symbol::KEYWORD_by, diags)?;
+        let (columns, tok5) = self.parse_comma_list(tok4, diags, Self::parse_col_id)?;
+        let tok6 = self.expect_keyword(tok5, Symbol::KEYWORD_set, diags)?;
+        let (seq_column, tok7) = self.parse_col_id(tok6, diags)?;
+        let search = CteSearch {
+            breadth_first,
+            columns,
+            seq_column,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((search, tok7))
+    }
+
+    /// Parses `CYCLE columns SET column [TO value DEFAULT value] USING column`
+    /// (`opt_cycle_clause`).
+    fn parse_cte_cycle(
+        &mut self,
+        tok0: Token,
+        diags: &mut CodeDiagnostics,
+    ) -> ParseResult<CteCycle> {
+        let start = tok0.range.start;
+        let tok1 = self.expect_keyword(tok0, Symbol::KEYWORD_cycle, diags)?;
+        let (columns, tok2) = self.parse_comma_list(tok1, diags, Self::parse_col_id)?;
+        let tok3 = self.expect_keyword(tok2, Symbol::KEYWORD_set, diags)?;
+        let (mark_column, tok4) = self.parse_col_id(tok3, diags)?;
+        let (mark_values, tok5) = if tok4.is_keyword(&Symbol::KEYWORD_to) {
+            let tok5 = self.tokens.next_token(diags);
+            let (value, tok6) = self.parse_const(tok5, diags)?;
+            let tok7 = self.expect_keyword(tok6, Symbol::KEYWORD_default, diags)?;
+            let (default, tok8) = self.parse_const(tok7, diags)?;
+            (Some((value, default)), tok8)
+        } else {
+            (None, tok4)
+        };
+        let tok6 = self.expect_keyword(tok5, Symbol::KEYWORD_using, diags)?;
+        let (path_column, tok7) = self.parse_col_id(tok6, diags)?;
+        let cycle = CteCycle {
+            columns,
+            mark_column,
+            mark_values,
+            path_column,
+            range: CodeRange {
+                start,
+                end: self.tokens.prev_end(),
+            },
+        };
+        Ok((cycle, tok7))
+    }
+}
+
+/// Whether the token begins a `WITH` clause.
+///
+/// `WITH` is turned into `WITH_LA` before `TIME` and `ORDINALITY`,
+/// but both can still begin a `WITH` clause, as in `WITH time AS (...)`.
+pub(super) fn starts_with_clause(tok: &Token) -> bool {
+    tok.is_keyword(&Symbol::KEYWORD_with) || tok.kind == TokenKind::WithLa
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::StmtKind,
+        diag::CodeDiagnostic,
+        parser::{
+            parse_stmt,
+            sexp::{parse_stmt_errors, parse_stmt_sexp},
+        },
+        pos::pos,
+    };
+
+    #[test]
+    fn test_with() {
+        assert_eq!(
+            parse_stmt_sexp("with a as (select 1), b (x, y) as (select 2, 3) select * from a, b"),
+            "(select (with (a (select (targets 1))) (b (x y) (select (targets 2 3)))) \
+             (targets *) (from a b))"
+        );
+        // `WITH TIME` is lexed as `WITH_LA`.
+        assert_eq!(
+            parse_stmt_sexp("with time as (select 1) select"),
+            "(select (with (time (select (targets 1)))) (targets))"
+        );
+    }
+
+    #[test]
+    fn test_with_range() {
+        let src = "with a as (select 1) select 2";
+        let stmt = parse_stmt(src).unwrap();
+        assert_eq!(stmt.range, pos(src, src, 0));
+        let StmtKind::Select(select) = stmt.kind else {
+            panic!("not a select statement");
+        };
+        let with = select.with.unwrap();
+        assert_eq!(with.range, pos(src, "with a as (select 1)", 0));
+        assert_eq!(with.ctes[0].range, pos(src, "a as (select 1)", 0));
+        assert_eq!(with.ctes[0].query.range, pos(src, "select 1", 0));
+    }
+
+    #[test]
+    fn test_materialized() {
+        assert_eq!(
+            parse_stmt_sexp(
+                "with a as materialized (select), b as not materialized (select) select"
+            ),
+            "(select (with (a materialized (select (targets))) \
+             (b not-materialized (select (targets)))) (targets))"
+        );
+        let src = "with a as not (select) select";
+        assert_eq!(
+            parse_stmt_errors(src),
+            vec![CodeDiagnostic::SyntaxError {
+                range: pos(src, "(", 0),
+                near: "(".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_recursive_search_cycle() {
+        assert_eq!(
+            parse_stmt_sexp(
+                "with recursive t (n) as (select 1) \
+                 search depth first by n set ord \
+                 cycle n set is_cycle using path \
+                 select n from t"
+            ),
+            "(select (with-recursive (t (n) (select (targets 1)) \
+             (search-depth-first (n) ord) (cycle (n) is_cycle path))) \
+             (targets n) (from t))"
+        );
+        assert_eq!(
+            parse_stmt_sexp(
+                "with recursive t as (select) search breadth first by a, b set s \
+                 cycle a set c to 'y' default 'n' using p select"
+            ),
+            "(select (with-recursive (t (select (targets)) (search-breadth-first (a b) s) \
+             (cycle (a) c 'y' 'n' p))) (targets))"
+        );
+        // The mark values must be constants.
+        let src = "with t as (select) cycle a set c to 1 + 1 default 0 using p select";
+        assert_eq!(
+            parse_stmt_errors(src),
+            vec![CodeDiagnostic::SyntaxError {
+                range: pos(src, "+", 0),
+                near: "+".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_nested_with() {
+        assert_eq!(
+            parse_stmt_sexp("select from (with a as (select) select) s"),
+            "(select (targets) (from (as (select (with (a (select (targets)))) (targets)) s)))"
+        );
+    }
+}
@@ -4,6 +4,37 @@ pub struct CodeRange {
     pub end: usize,
 }
 
+impl CodeRange {
+    /// The start position as PostgreSQL reports it: 1-based and counted in characters.
+    pub fn position(&self, src: &str) -> usize {
+        src[..self.start].chars().count() + 1
+    }
+
+    /// 1-based line number and column (counted in characters) of the start position.
+    pub fn line_column(&self, src: &str) -> (usize, usize) {
+        let (number, line_start) = line_containing(src, self.start);
+        (number, src[line_start..self.start].chars().count() + 1)
+    }
+}
+
+/// Offsets just past each line terminator in `src`. As in the lexer and libpq,
+/// `\n`, `\r`, and `\r\n` all end a line.
+pub(crate) fn line_ends(src: &str) -> impl Iterator<Item = usize> + '_ {
+    let bytes = src.as_bytes();
+    bytes.iter().enumerate().filter_map(move |(i, &b)| match b {
+        b'\n' => Some(i + 1),
+        b'\r' if bytes.get(i + 1) != Some(&b'\n') => Some(i + 1),
+        _ => None,
+    })
+}
+
+/// The 1-based line number and the start offset of the line containing `offset`.
+pub(crate) fn line_containing(src: &str, offset: usize) -> (usize, usize) {
+    line_ends(src)
+        .take_while(|&end| end <= offset)
+        .fold((1, 0), |(number, _), end| (number + 1, end))
+}
+
 #[cfg(test)]
 pub(crate) fn pos(source: &str, needle: &str, occurrence: usize) -> CodeRange {
     let mut current = 0;
@@ -31,4 +62,25 @@ mod tests {
     fn test_pos_simple() {
         assert_eq!(pos("foo bar", "bar", 0), CodeRange { start: 4, end: 7 })
     }
+
+    #[test]
+    fn test_position_counts_characters() {
+        let src = "select 'ö', x";
+        assert_eq!(pos(src, "select", 0).position(src), 1);
+        assert_eq!(pos(src, "x", 0).position(src), 13);
+    }
+
+    #[test]
+    fn test_line_column() {
+        let src = "select 1;\nselect 'ö', x";
+        assert_eq!(pos(src, "select", 0).line_column(src), (1, 1));
+        assert_eq!(pos(src, "x", 0).line_column(src), (2, 13));
+    }
+
+    #[test]
+    fn test_line_column_carriage_return() {
+        let src = "select 1;\rselect 2;\r\nx";
+        assert_eq!(pos(src, "select", 1).line_column(src), (2, 1));
+        assert_eq!(pos(src, "x", 0).line_column(src), (3, 1));
+    }
 }
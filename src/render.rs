@@ -0,0 +1,382 @@
+// Terminal rendering of diagnostics with source snippets.
+
+use std::fmt::Write;
+
+use crate::{
+    diag::{CodeDiagnostic, Severity},
+    pos::{line_containing, line_ends},
+};
+
+/// How [`render_diagnostic`] lays out a diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RenderStyle {
+    /// The format psql uses:
+    ///
+    /// ```text
+    /// ERROR:  unterminated quoted string
+    /// LINE 1: select 'foo
+    ///                ^
+    /// ```
+    Psql,
+    /// A compiler-like format that underlines the whole range,
+    /// including ranges spanning multiple lines:
+    ///
+    /// ```text
+    /// error[42601]: unterminated quoted string
+    ///  --> 1:8
+    ///   |
+    /// 1 | select 'foo
+    ///   |        ^^^^
+    /// ```
+    ///
+    /// With `color`, ANSI escape sequences are used for highlighting.
+    Rich { color: bool },
+}
+
+/// Renders a diagnostic together with the part of the source it points to.
+pub fn render_diagnostic(src: &str, diag: &CodeDiagnostic, style: RenderStyle) -> String {
+    match style {
+        RenderStyle::Psql => render_psql(src, diag),
+        RenderStyle::Rich { color } => render_rich(src, diag, color),
+    }
+}
+
+fn render_psql(src: &str, diag: &CodeDiagnostic) -> String {
+    let mut out = String::new();
+    writeln!(out, "{}:  {}", diag.severity(), diag).unwrap();
+    let range = diag.range();
+    let line = Line::containing(src, range.start);
+    let prefix = format!("LINE {}: ", line.number);
+    writeln!(out, "{}{}", prefix, line.text).unwrap();
+    writeln!(
+        out,
+        "{}{}^",
+        " ".repeat(prefix.len()),
+        padding(&line.text[..line.clamp(range.start)])
+    )
+    .unwrap();
+    if let Some(detail) = diag.detail() {
+        writeln!(out, "DETAIL:  {}", detail).unwrap();
+    }
+    if let Some(hint) = diag.hint() {
+        writeln!(out, "HINT:  {}", hint).unwrap();
+    }
+    out
+}
+
+fn render_rich(src: &str, diag: &CodeDiagnostic, color: bool) -> String {
+    let paint = Paint { color };
+    let severity_color = match diag.severity() {
+        Severity::Error => RED,
+        Severity::Warning => YELLOW,
+        Severity::Notice | Severity::Info | Severity::Debug => CYAN,
+    };
+    let range = diag.range();
+    let first = Line::containing(src, range.start);
+    // An end at the start of a line belongs to the previous line.
+    let last_char = src[..range.end]
+        .char_indices()
+        .next_back()
+        .map_or(0, |(i, _)| i);
+    let last = Line::containing(src, last_char.max(range.start));
+    let gutter_width = last.number.to_string().len();
+    let gutter = " ".repeat(gutter_width);
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{}: {}",
+        paint.apply(
+            &format!(
+                "{}[{}]",
+                diag.severity().to_string().to_lowercase(),
+                diag.sqlstate()
+            ),
+            severity_color
+        ),
+        paint.apply(&diag.to_string(), BOLD)
+    )
+    .unwrap();
+    let (line_number, column) = range.line_column(src);
+    writeln!(
+        out,
+        "{}{} {}:{}",
+        gutter,
+        paint.apply("-->", BLUE),
+        line_number,
+        column
+    )
+    .unwrap();
+    writeln!(out, "{} {}", gutter, paint.apply("|", BLUE)).unwrap();
+
+    let mut line = first;
+    loop {
+        writeln!(
+            out,
+            "{} {} {}",
+            paint.apply(&format!("{:>gutter_width$}", line.number), BLUE),
+            paint.apply("|", BLUE),
+            line.text
+        )
+        .unwrap();
+        let underline_start = line.clamp(range.start.max(line.start));
+        let underline_end = line.clamp(range.end).max(underline_start);
+        let carets = line.text[underline_start..underline_end]
+            .chars()
+            .count()
+            .max(1);
+        writeln!(
+            out,
+            "{} {} {}{}",
+            gutter,
+            paint.apply("|", BLUE),
+            padding(&line.text[..underline_start]),
+            paint.apply(&"^".repeat(carets), severity_color)
+        )
+        .unwrap();
+        if line.number == last.number {
+            break;
+        }
+        line = Line::containing(src, line.start + line.len_with_newline);
+    }
+
+    if let Some(detail) = diag.detail() {
+        writeln!(
+            out,
+            "{} {} detail: {}",
+            gutter,
+            paint.apply("=", BLUE),
+            detail
+        )
+        .unwrap();
+    }
+    if let Some(hint) = diag.hint() {
+        writeln!(out, "{} {} hint: {}", gutter, paint.apply("=", BLUE), hint).unwrap();
+    }
+    out
+}
+
+/// A line in the source, without the line terminator.
+struct Line<'a> {
+    /// 1-based line number.
+    number: usize,
+    /// Byte offset of the start of the line.
+    start: usize,
+    text: &'a str,
+    len_with_newline: usize,
+}
+
+impl<'a> Line<'a> {
+    fn containing(src: &'a str, offset: usize) -> Self {
+        let (number, start) = line_containing(src, offset);
+        let len_with_newline = line_ends(src).find(|&end| end > start).unwrap_or(src.len()) - start;
+        let text = src[start..start + len_with_newline].trim_end_matches(['\n', '\r']);
+        Line {
+            number,
+            start,
+            text,
+            len_with_newline,
+        }
+    }
+
+    /// Converts a source offset into an offset within `text`.
+    /// Offsets inside the line terminator (e.g. between `\r` and `\n`) map to the end of the text.
+    fn clamp(&self, offset: usize) -> usize {
+        (offset - self.start).min(self.text.len())
+    }
+}
+
+/// Whitespace that lines up with `text` when printed below it.
+/// Tabs are kept so that the alignment does not depend on the tab width.
+fn padding(text: &str) -> String {
+    text.chars()
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect()
+}
+
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const CYAN: &str = "\x1b[1;36m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, Copy)]
+struct Paint {
+    color: bool,
+}
+
+impl Paint {
+    fn apply(&self, text: &str, style: &str) -> String {
+        if self.color {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pos::{CodeRange, pos};
+
+    use super::*;
+
+    #[test]
+    fn test_render_psql() {
+        let src = "select 1;\nselect 'foo";
+        let diag = CodeDiagnostic::UnterminatedString {
+            range: pos(src, "'foo", 0),
+        };
+        assert_eq!(
+            render_diagnostic(src, &diag, RenderStyle::Psql),
+            concat!(
+                "ERROR:  unterminated quoted string\n",
+                "LINE 2: select 'foo\n",
+                "               ^\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_psql_with_hint() {
+        let src = "select\tE'\\u12'";
+        let diag = CodeDiagnostic::InvalidUnicodeEscape {
+            range: pos(src, "\\u12", 0),
+        };
+        assert_eq!(
+            render_diagnostic(src, &diag, RenderStyle::Psql),
+            concat!(
+                "ERROR:  invalid Unicode escape\n",
+                "LINE 1: select\tE'\\u12'\n",
+                "              \t  ^\n",
+                "HINT:  Unicode escapes must be \\uXXXX or \\UXXXXXXXX.\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_rich() {
+        let src = "select 'foo";
+        let diag = CodeDiagnostic::UnterminatedString {
+            range: pos(src, "'foo", 0),
+        };
+        assert_eq!(
+            render_diagnostic(src, &diag, RenderStyle::Rich { color: false }),
+            concat!(
+                "error[42601]: unterminated quoted string\n",
+                " --> 1:8\n",
+                "  |\n",
+                "1 | select 'foo\n",
+                "  |        ^^^^\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_rich_multiline() {
+        let src = "select 1; /* a\n\nb";
+        let diag = CodeDiagnostic::UnterminatedBlockComment {
+            range: CodeRange {
+                start: src.find("/*").unwrap(),
+                end: src.len(),
+            },
+        };
+        assert_eq!(
+            render_diagnostic(src, &diag, RenderStyle::Rich { color: false }),
+            concat!(
+                "error[42601]: unterminated /* comment\n",
+                " --> 1:11\n",
+                "  |\n",
+                "1 | select 1; /* a\n",
+                "  |           ^^^^\n",
+                "2 | \n",
+                "  | ^\n",
+                "3 | b\n",
+                "  | ^\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_rich_non_ascii_end() {
+        let src = "select 'café";
+        let diag = CodeDiagnostic::UnterminatedString {
+            range: pos(src, "'café", 0),
+        };
+        assert_eq!(
+            render_diagnostic(src, &diag, RenderStyle::Rich { color: false }),
+            concat!(
+                "error[42601]: unterminated quoted string\n",
+                " --> 1:8\n",
+                "  |\n",
+                "1 | select 'café\n",
+                "  |        ^^^^^\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_carriage_return() {
+        let src = "select 1;\rselect 'foo";
+        let diag = CodeDiagnostic::UnterminatedString {
+            range: pos(src, "'foo", 0),
+        };
+        assert_eq!(
+            render_diagnostic(src, &diag, RenderStyle::Psql),
+            concat!(
+                "ERROR:  unterminated quoted string\n",
+                "LINE 2: select 'foo\n",
+                "               ^\n",
+            )
+        );
+        assert_eq!(
+            render_diagnostic(src, &diag, RenderStyle::Rich { color: false }),
+            concat!(
+                "error[42601]: unterminated quoted string\n",
+                " --> 2:8\n",
+                "  |\n",
+                "2 | select 'foo\n",
+                "  |        ^^^^\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_after_carriage_return() {
+        let src = "select @\r";
+        let diag = CodeDiagnostic::UnknownToken {
+            range: CodeRange {
+                start: src.len(),
+                end: src.len(),
+            },
+        };
+        assert_eq!(
+            render_diagnostic(src, &diag, RenderStyle::Psql),
+            concat!("ERROR:  unknown token\n", "LINE 2: \n", "        ^\n",)
+        );
+        assert_eq!(
+            render_diagnostic(src, &diag, RenderStyle::Rich { color: false }),
+            concat!(
+                "error[42601]: unknown token\n",
+                " --> 2:1\n",
+                "  |\n",
+                "2 | \n",
+                "  | ^\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_rich_color() {
+        let src = "x";
+        let diag = CodeDiagnostic::UnknownToken {
+            range: pos(src, "x", 0),
+        };
+        let rendered = render_diagnostic(src, &diag, RenderStyle::Rich { color: true });
+        assert!(
+            rendered.starts_with("\x1b[1;31merror[42601]\x1b[0m: \x1b[1munknown token\x1b[0m\n")
+        );
+        assert!(rendered.contains("\x1b[1;31m^\x1b[0m"));
+    }
+}
@@ -34,6 +34,57 @@ impl Symbol {
     }
 }
 
+/// Keyword classification.
+///
+/// These methods are meant for unquoted identifiers.
+/// Quoted identifiers are never keywords regardless of their names.
+impl Symbol {
+    /// Returns the category if the symbol is a keyword.
+    pub fn keyword_category(&self) -> Option<KeywordCategory> {
+        self.keyword_kind().map(|(category, _)| category)
+    }
+
+    /// Whether the symbol is a keyword of the given category.
+    pub fn is_keyword_of(&self, category: KeywordCategory) -> bool {
+        self.keyword_category() == Some(category)
+    }
+
+    /// Whether the symbol can be used as a column label without `AS`
+    /// (`BareColLabel` in gram.y).
+    pub fn is_bare_col_label(&self) -> bool {
+        !matches!(self.keyword_kind(), Some((_, KeywordLabel::AsLabel)))
+    }
+
+    /// Whether the symbol can be used as a column or table name (`ColId` in gram.y).
+    pub fn is_col_id(&self) -> bool {
+        matches!(
+            self.keyword_category(),
+            None | Some(KeywordCategory::Unreserved | KeywordCategory::ColName)
+        )
+    }
+
+    /// Whether the symbol can be used as a function or type name
+    /// (`type_function_name` in gram.y).
+    pub fn is_type_function_name(&self) -> bool {
+        matches!(
+            self.keyword_category(),
+            None | Some(KeywordCategory::Unreserved | KeywordCategory::TypeFuncName)
+        )
+    }
+
+    /// Whether the symbol is not a reserved keyword (`NonReservedWord` in gram.y).
+    pub fn is_non_reserved_word(&self) -> bool {
+        !self.is_keyword_of(KeywordCategory::Reserved)
+    }
+
+    fn keyword_kind(&self) -> Option<(KeywordCategory, KeywordLabel)> {
+        match &self.inner {
+            SymbolCase::Keyword(id) => KEYWORD_KINDS[*id],
+            SymbolCase::Custom(_) => None,
+        }
+    }
+}
+
 impl Deref for Symbol {
     type Target = str;
 
@@ -53,10 +104,7 @@ impl fmt::Debug for Symbol {
 
 impl PartialOrd for Symbol {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if self.trivially_equal(other) {
-            return Some(Ordering::Equal);
-        }
-        Some(<str as Ord>::cmp(&**self, &**other))
+        Some(self.cmp(other))
     }
 
     fn lt(&self, other: &Self) -> bool {
@@ -109,7 +157,7 @@ impl Default for Symbol {
     }
 }
 
-impl<'a> From<&'a str> for Symbol {
+impl From<&str> for Symbol {
     fn from(s: &str) -> Self {
         if let Some(sym) = Symbol::try_from_keyword(s) {
             sym
@@ -139,8 +187,31 @@ enum SymbolCase {
     Custom(String),
 }
 
+/// The category of a keyword, which determines where it can be used as an identifier.
+///
+/// See <https://www.postgresql.org/docs/current/sql-keywords-appendix.html>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeywordCategory {
+    /// Can be used as any kind of name.
+    Unreserved,
+    /// Can be used as a column name or a table name, but not as a function or type name.
+    ColName,
+    /// Can be used as a function or type name, but not as a column name or a table name.
+    TypeFuncName,
+    /// Cannot be used as a name unless quoted.
+    Reserved,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum KeywordLabel {
+    /// Can be used as a column label without `AS`, as in `SELECT 1 foo`.
+    BareLabel,
+    /// Requires `AS` to be used as a column label.
+    AsLabel,
+}
+
 macro_rules! build_keywords {
-    ($($key:expr => ($value:expr, $kwd_const:ident),)*) => {
+    ($($key:expr => ($value:expr, $kwd_const:ident $(, $category:ident, $label:ident)?),)*) => {
         static KEYWORDS: [Option<&'static str>; ID_MAX] = {
             let mut keywords: [Option<&'static str>; ID_MAX] = [None; ID_MAX];
             $(
@@ -149,6 +220,14 @@ macro_rules! build_keywords {
             keywords
         };
 
+        static KEYWORD_KINDS: [Option<(KeywordCategory, KeywordLabel)>; ID_MAX] = {
+            let mut kinds: [Option<(KeywordCategory, KeywordLabel)>; ID_MAX] = [None; ID_MAX];
+            $($(
+                kinds[$value] = Some((KeywordCategory::$category, KeywordLabel::$label));
+            )?)*
+            kinds
+        };
+
         static KEYWORD_MAP: phf::Map<&'static str, usize> = phf_map! {
             $($key => $value,)*
         };
@@ -164,506 +243,506 @@ macro_rules! build_keywords {
 
 build_keywords!(
     "" => (0, KEYWORD__EMPTY_STRING),
-    "abort" => (1, KEYWORD_abort),
-    "absent" => (2, KEYWORD_absent),
-    "absolute" => (3, KEYWORD_absolute),
-    "access" => (4, KEYWORD_access),
-    "action" => (5, KEYWORD_action),
-    "add" => (6, KEYWORD_add),
-    "admin" => (7, KEYWORD_admin),
-    "after" => (8, KEYWORD_after),
-    "aggregate" => (9, KEYWORD_aggregate),
-    "all" => (10, KEYWORD_all),
-    "also" => (11, KEYWORD_also),
-    "alter" => (12, KEYWORD_alter),
-    "always" => (13, KEYWORD_always),
-    "analyse" => (14, KEYWORD_analyse),
-    "analyze" => (15, KEYWORD_analyze),
-    "and" => (16, KEYWORD_and),
-    "any" => (17, KEYWORD_any),
-    "array" => (18, KEYWORD_array),
-    "as" => (19, KEYWORD_as),
-    "asc" => (20, KEYWORD_asc),
-    "asensitive" => (21, KEYWORD_asensitive),
-    "assertion" => (22, KEYWORD_assertion),
-    "assignment" => (23, KEYWORD_assignment),
-    "asymmetric" => (24, KEYWORD_asymmetric),
-    "at" => (25, KEYWORD_at),
-    "atomic" => (26, KEYWORD_atomic),
-    "attach" => (27, KEYWORD_attach),
-    "attribute" => (28, KEYWORD_attribute),
-    "authorization" => (29, KEYWORD_authorization),
-    "backward" => (30, KEYWORD_backward),
-    "before" => (31, KEYWORD_before),
-    "begin" => (32, KEYWORD_begin),
-    "between" => (33, KEYWORD_between),
-    "bigint" => (34, KEYWORD_bigint),
-    "binary" => (35, KEYWORD_binary),
-    "bit" => (36, KEYWORD_bit),
-    "boolean" => (37, KEYWORD_boolean),
-    "both" => (38, KEYWORD_both),
-    "breadth" => (39, KEYWORD_breadth),
-    "by" => (40, KEYWORD_by),
-    "cache" => (41, KEYWORD_cache),
-    "call" => (42, KEYWORD_call),
-    "called" => (43, KEYWORD_called),
-    "cascade" => (44, KEYWORD_cascade),
-    "cascaded" => (45, KEYWORD_cascaded),
-    "case" => (46, KEYWORD_case),
-    "cast" => (47, KEYWORD_cast),
-    "catalog" => (48, KEYWORD_catalog),
-    "chain" => (49, KEYWORD_chain),
-    "char" => (50, KEYWORD_char),
-    "character" => (51, KEYWORD_character),
-    "characteristics" => (52, KEYWORD_characteristics),
-    "check" => (53, KEYWORD_check),
-    "checkpoint" => (54, KEYWORD_checkpoint),
-    "class" => (55, KEYWORD_class),
-    "close" => (56, KEYWORD_close),
-    "cluster" => (57, KEYWORD_cluster),
-    "coalesce" => (58, KEYWORD_coalesce),
-    "collate" => (59, KEYWORD_collate),
-    "collation" => (60, KEYWORD_collation),
-    "column" => (61, KEYWORD_column),
-    "columns" => (62, KEYWORD_columns),
-    "comment" => (63, KEYWORD_comment),
-    "comments" => (64, KEYWORD_comments),
-    "commit" => (65, KEYWORD_commit),
-    "committed" => (66, KEYWORD_committed),
-    "compression" => (67, KEYWORD_compression),
-    "concurrently" => (68, KEYWORD_concurrently),
-    "conditional" => (69, KEYWORD_conditional),
-    "configuration" => (70, KEYWORD_configuration),
-    "conflict" => (71, KEYWORD_conflict),
-    "connection" => (72, KEYWORD_connection),
-    "constraint" => (73, KEYWORD_constraint),
-    "constraints" => (74, KEYWORD_constraints),
-    "content" => (75, KEYWORD_content),
-    "continue" => (76, KEYWORD_continue),
-    "conversion" => (77, KEYWORD_conversion),
-    "copy" => (78, KEYWORD_copy),
-    "cost" => (79, KEYWORD_cost),
-    "create" => (80, KEYWORD_create),
-    "cross" => (81, KEYWORD_cross),
-    "csv" => (82, KEYWORD_csv),
-    "cube" => (83, KEYWORD_cube),
-    "current" => (84, KEYWORD_current),
-    "current_catalog" => (85, KEYWORD_current_catalog),
-    "current_date" => (86, KEYWORD_current_date),
-    "current_role" => (87, KEYWORD_current_role),
-    "current_schema" => (88, KEYWORD_current_schema),
-    "current_time" => (89, KEYWORD_current_time),
-    "current_timestamp" => (90, KEYWORD_current_timestamp),
-    "current_user" => (91, KEYWORD_current_user),
-    "cursor" => (92, KEYWORD_cursor),
-    "cycle" => (93, KEYWORD_cycle),
-    "data" => (94, KEYWORD_data),
-    "database" => (95, KEYWORD_database),
-    "day" => (96, KEYWORD_day),
-    "deallocate" => (97, KEYWORD_deallocate),
-    "dec" => (98, KEYWORD_dec),
-    "decimal" => (99, KEYWORD_decimal),
-    "declare" => (100, KEYWORD_declare),
-    "default" => (101, KEYWORD_default),
-    "defaults" => (102, KEYWORD_defaults),
-    "deferrable" => (103, KEYWORD_deferrable),
-    "deferred" => (104, KEYWORD_deferred),
-    "definer" => (105, KEYWORD_definer),
-    "delete" => (106, KEYWORD_delete),
-    "delimiter" => (107, KEYWORD_delimiter),
-    "delimiters" => (108, KEYWORD_delimiters),
-    "depends" => (109, KEYWORD_depends),
-    "depth" => (110, KEYWORD_depth),
-    "desc" => (111, KEYWORD_desc),
-    "detach" => (112, KEYWORD_detach),
-    "dictionary" => (113, KEYWORD_dictionary),
-    "disable" => (114, KEYWORD_disable),
-    "discard" => (115, KEYWORD_discard),
-    "distinct" => (116, KEYWORD_distinct),
-    "do" => (117, KEYWORD_do),
-    "document" => (118, KEYWORD_document),
-    "domain" => (119, KEYWORD_domain),
-    "double" => (120, KEYWORD_double),
-    "drop" => (121, KEYWORD_drop),
-    "each" => (122, KEYWORD_each),
-    "else" => (123, KEYWORD_else),
-    "empty" => (124, KEYWORD_empty),
-    "enable" => (125, KEYWORD_enable),
-    "encoding" => (126, KEYWORD_encoding),
-    "encrypted" => (127, KEYWORD_encrypted),
-    "end" => (128, KEYWORD_end),
-    "enforced" => (129, KEYWORD_enforced),
-    "enum" => (130, KEYWORD_enum),
-    "error" => (131, KEYWORD_error),
-    "escape" => (132, KEYWORD_escape),
-    "event" => (133, KEYWORD_event),
-    "except" => (134, KEYWORD_except),
-    "exclude" => (135, KEYWORD_exclude),
-    "excluding" => (136, KEYWORD_excluding),
-    "exclusive" => (137, KEYWORD_exclusive),
-    "execute" => (138, KEYWORD_execute),
-    "exists" => (139, KEYWORD_exists),
-    "explain" => (140, KEYWORD_explain),
-    "expression" => (141, KEYWORD_expression),
-    "extension" => (142, KEYWORD_extension),
-    "external" => (143, KEYWORD_external),
-    "extract" => (144, KEYWORD_extract),
-    "false" => (145, KEYWORD_false),
-    "family" => (146, KEYWORD_family),
-    "fetch" => (147, KEYWORD_fetch),
-    "filter" => (148, KEYWORD_filter),
-    "finalize" => (149, KEYWORD_finalize),
-    "first" => (150, KEYWORD_first),
-    "float" => (151, KEYWORD_float),
-    "following" => (152, KEYWORD_following),
-    "for" => (153, KEYWORD_for),
-    "force" => (154, KEYWORD_force),
-    "foreign" => (155, KEYWORD_foreign),
-    "format" => (156, KEYWORD_format),
-    "forward" => (157, KEYWORD_forward),
-    "freeze" => (158, KEYWORD_freeze),
-    "from" => (159, KEYWORD_from),
-    "full" => (160, KEYWORD_full),
-    "function" => (161, KEYWORD_function),
-    "functions" => (162, KEYWORD_functions),
-    "generated" => (163, KEYWORD_generated),
-    "global" => (164, KEYWORD_global),
-    "grant" => (165, KEYWORD_grant),
-    "granted" => (166, KEYWORD_granted),
-    "greatest" => (167, KEYWORD_greatest),
-    "group" => (168, KEYWORD_group),
-    "grouping" => (169, KEYWORD_grouping),
-    "groups" => (170, KEYWORD_groups),
-    "handler" => (171, KEYWORD_handler),
-    "having" => (172, KEYWORD_having),
-    "header" => (173, KEYWORD_header),
-    "hold" => (174, KEYWORD_hold),
-    "hour" => (175, KEYWORD_hour),
-    "identity" => (176, KEYWORD_identity),
-    "if" => (177, KEYWORD_if),
-    "ignore" => (178, KEYWORD_ignore),
-    "ilike" => (179, KEYWORD_ilike),
-    "immediate" => (180, KEYWORD_immediate),
-    "immutable" => (181, KEYWORD_immutable),
-    "implicit" => (182, KEYWORD_implicit),
-    "import" => (183, KEYWORD_import),
-    "in" => (184, KEYWORD_in),
-    "include" => (185, KEYWORD_include),
-    "including" => (186, KEYWORD_including),
-    "increment" => (187, KEYWORD_increment),
-    "indent" => (188, KEYWORD_indent),
-    "index" => (189, KEYWORD_index),
-    "indexes" => (190, KEYWORD_indexes),
-    "inherit" => (191, KEYWORD_inherit),
-    "inherits" => (192, KEYWORD_inherits),
-    "initially" => (193, KEYWORD_initially),
-    "inline" => (194, KEYWORD_inline),
-    "inner" => (195, KEYWORD_inner),
-    "inout" => (196, KEYWORD_inout),
-    "input" => (197, KEYWORD_input),
-    "insensitive" => (198, KEYWORD_insensitive),
-    "insert" => (199, KEYWORD_insert),
-    "instead" => (200, KEYWORD_instead),
-    "int" => (201, KEYWORD_int),
-    "integer" => (202, KEYWORD_integer),
-    "intersect" => (203, KEYWORD_intersect),
-    "interval" => (204, KEYWORD_interval),
-    "into" => (205, KEYWORD_into),
-    "invoker" => (206, KEYWORD_invoker),
-    "is" => (207, KEYWORD_is),
-    "isnull" => (208, KEYWORD_isnull),
-    "isolation" => (209, KEYWORD_isolation),
-    "join" => (210, KEYWORD_join),
-    "json" => (211, KEYWORD_json),
-    "json_array" => (212, KEYWORD_json_array),
-    "json_arrayagg" => (213, KEYWORD_json_arrayagg),
-    "json_exists" => (214, KEYWORD_json_exists),
-    "json_object" => (215, KEYWORD_json_object),
-    "json_objectagg" => (216, KEYWORD_json_objectagg),
-    "json_query" => (217, KEYWORD_json_query),
-    "json_scalar" => (218, KEYWORD_json_scalar),
-    "json_serialize" => (219, KEYWORD_json_serialize),
-    "json_table" => (220, KEYWORD_json_table),
-    "json_value" => (221, KEYWORD_json_value),
-    "keep" => (222, KEYWORD_keep),
-    "key" => (223, KEYWORD_key),
-    "keys" => (224, KEYWORD_keys),
-    "label" => (225, KEYWORD_label),
-    "language" => (226, KEYWORD_language),
-    "large" => (227, KEYWORD_large),
-    "last" => (228, KEYWORD_last),
-    "lateral" => (229, KEYWORD_lateral),
-    "leading" => (230, KEYWORD_leading),
-    "leakproof" => (231, KEYWORD_leakproof),
-    "least" => (232, KEYWORD_least),
-    "left" => (233, KEYWORD_left),
-    "level" => (234, KEYWORD_level),
-    "like" => (235, KEYWORD_like),
-    "limit" => (236, KEYWORD_limit),
-    "listen" => (237, KEYWORD_listen),
-    "load" => (238, KEYWORD_load),
-    "local" => (239, KEYWORD_local),
-    "localtime" => (240, KEYWORD_localtime),
-    "localtimestamp" => (241, KEYWORD_localtimestamp),
-    "location" => (242, KEYWORD_location),
-    "lock" => (243, KEYWORD_lock),
-    "locked" => (244, KEYWORD_locked),
-    "logged" => (245, KEYWORD_logged),
-    "lsn" => (246, KEYWORD_lsn),
-    "mapping" => (247, KEYWORD_mapping),
-    "match" => (248, KEYWORD_match),
-    "matched" => (249, KEYWORD_matched),
-    "materialized" => (250, KEYWORD_materialized),
-    "maxvalue" => (251, KEYWORD_maxvalue),
-    "merge" => (252, KEYWORD_merge),
-    "merge_action" => (253, KEYWORD_merge_action),
-    "method" => (254, KEYWORD_method),
-    "minute" => (255, KEYWORD_minute),
-    "minvalue" => (256, KEYWORD_minvalue),
-    "mode" => (257, KEYWORD_mode),
-    "month" => (258, KEYWORD_month),
-    "move" => (259, KEYWORD_move),
-    "name" => (260, KEYWORD_name),
-    "names" => (261, KEYWORD_names),
-    "national" => (262, KEYWORD_national),
-    "natural" => (263, KEYWORD_natural),
-    "nchar" => (264, KEYWORD_nchar),
-    "nested" => (265, KEYWORD_nested),
-    "new" => (266, KEYWORD_new),
-    "next" => (267, KEYWORD_next),
-    "nfc" => (268, KEYWORD_nfc),
-    "nfd" => (269, KEYWORD_nfd),
-    "nfkc" => (270, KEYWORD_nfkc),
-    "nfkd" => (271, KEYWORD_nfkd),
-    "no" => (272, KEYWORD_no),
-    "none" => (273, KEYWORD_none),
-    "normalize" => (274, KEYWORD_normalize),
-    "normalized" => (275, KEYWORD_normalized),
-    "not" => (276, KEYWORD_not),
-    "nothing" => (277, KEYWORD_nothing),
-    "notify" => (278, KEYWORD_notify),
-    "notnull" => (279, KEYWORD_notnull),
-    "nowait" => (280, KEYWORD_nowait),
-    "null" => (281, KEYWORD_null),
-    "nullif" => (282, KEYWORD_nullif),
-    "nulls" => (283, KEYWORD_nulls),
-    "numeric" => (284, KEYWORD_numeric),
-    "object" => (285, KEYWORD_object),
-    "objects" => (286, KEYWORD_objects),
-    "of" => (287, KEYWORD_of),
-    "off" => (288, KEYWORD_off),
-    "offset" => (289, KEYWORD_offset),
-    "oids" => (290, KEYWORD_oids),
-    "old" => (291, KEYWORD_old),
-    "omit" => (292, KEYWORD_omit),
-    "on" => (293, KEYWORD_on),
-    "only" => (294, KEYWORD_only),
-    "operator" => (295, KEYWORD_operator),
-    "option" => (296, KEYWORD_option),
-    "options" => (297, KEYWORD_options),
-    "or" => (298, KEYWORD_or),
-    "order" => (299, KEYWORD_order),
-    "ordinality" => (300, KEYWORD_ordinality),
-    "others" => (301, KEYWORD_others),
-    "out" => (302, KEYWORD_out),
-    "outer" => (303, KEYWORD_outer),
-    "over" => (304, KEYWORD_over),
-    "overlaps" => (305, KEYWORD_overlaps),
-    "overlay" => (306, KEYWORD_overlay),
-    "overriding" => (307, KEYWORD_overriding),
-    "owned" => (308, KEYWORD_owned),
-    "owner" => (309, KEYWORD_owner),
-    "parallel" => (310, KEYWORD_parallel),
-    "parameter" => (311, KEYWORD_parameter),
-    "parser" => (312, KEYWORD_parser),
-    "partial" => (313, KEYWORD_partial),
-    "partition" => (314, KEYWORD_partition),
-    "partitions" => (315, KEYWORD_partitions),
-    "passing" => (316, KEYWORD_passing),
-    "password" => (317, KEYWORD_password),
-    "path" => (318, KEYWORD_path),
-    "period" => (319, KEYWORD_period),
-    "placing" => (320, KEYWORD_placing),
-    "plan" => (321, KEYWORD_plan),
-    "plans" => (322, KEYWORD_plans),
-    "policy" => (323, KEYWORD_policy),
-    "position" => (324, KEYWORD_position),
-    "preceding" => (325, KEYWORD_preceding),
-    "precision" => (326, KEYWORD_precision),
-    "prepare" => (327, KEYWORD_prepare),
-    "prepared" => (328, KEYWORD_prepared),
-    "preserve" => (329, KEYWORD_preserve),
-    "primary" => (330, KEYWORD_primary),
-    "prior" => (331, KEYWORD_prior),
-    "privileges" => (332, KEYWORD_privileges),
-    "procedural" => (333, KEYWORD_procedural),
-    "procedure" => (334, KEYWORD_procedure),
-    "procedures" => (335, KEYWORD_procedures),
-    "program" => (336, KEYWORD_program),
-    "publication" => (337, KEYWORD_publication),
-    "quote" => (338, KEYWORD_quote),
-    "quotes" => (339, KEYWORD_quotes),
-    "range" => (340, KEYWORD_range),
-    "read" => (341, KEYWORD_read),
-    "real" => (342, KEYWORD_real),
-    "reassign" => (343, KEYWORD_reassign),
-    "recursive" => (344, KEYWORD_recursive),
-    "ref" => (345, KEYWORD_ref),
-    "references" => (346, KEYWORD_references),
-    "referencing" => (347, KEYWORD_referencing),
-    "refresh" => (348, KEYWORD_refresh),
-    "reindex" => (349, KEYWORD_reindex),
-    "relative" => (350, KEYWORD_relative),
-    "release" => (351, KEYWORD_release),
-    "rename" => (352, KEYWORD_rename),
-    "repeatable" => (353, KEYWORD_repeatable),
-    "replace" => (354, KEYWORD_replace),
-    "replica" => (355, KEYWORD_replica),
-    "reset" => (356, KEYWORD_reset),
-    "respect" => (357, KEYWORD_respect),
-    "restart" => (358, KEYWORD_restart),
-    "restrict" => (359, KEYWORD_restrict),
-    "return" => (360, KEYWORD_return),
-    "returning" => (361, KEYWORD_returning),
-    "returns" => (362, KEYWORD_returns),
-    "revoke" => (363, KEYWORD_revoke),
-    "right" => (364, KEYWORD_right),
-    "role" => (365, KEYWORD_role),
-    "rollback" => (366, KEYWORD_rollback),
-    "rollup" => (367, KEYWORD_rollup),
-    "routine" => (368, KEYWORD_routine),
-    "routines" => (369, KEYWORD_routines),
-    "row" => (370, KEYWORD_row),
-    "rows" => (371, KEYWORD_rows),
-    "rule" => (372, KEYWORD_rule),
-    "savepoint" => (373, KEYWORD_savepoint),
-    "scalar" => (374, KEYWORD_scalar),
-    "schema" => (375, KEYWORD_schema),
-    "schemas" => (376, KEYWORD_schemas),
-    "scroll" => (377, KEYWORD_scroll),
-    "search" => (378, KEYWORD_search),
-    "second" => (379, KEYWORD_second),
-    "security" => (380, KEYWORD_security),
-    "select" => (381, KEYWORD_select),
-    "sequence" => (382, KEYWORD_sequence),
-    "sequences" => (383, KEYWORD_sequences),
-    "serializable" => (384, KEYWORD_serializable),
-    "server" => (385, KEYWORD_server),
-    "session" => (386, KEYWORD_session),
-    "session_user" => (387, KEYWORD_session_user),
-    "set" => (388, KEYWORD_set),
-    "setof" => (389, KEYWORD_setof),
-    "sets" => (390, KEYWORD_sets),
-    "share" => (391, KEYWORD_share),
-    "show" => (392, KEYWORD_show),
-    "similar" => (393, KEYWORD_similar),
-    "simple" => (394, KEYWORD_simple),
-    "skip" => (395, KEYWORD_skip),
-    "smallint" => (396, KEYWORD_smallint),
-    "snapshot" => (397, KEYWORD_snapshot),
-    "some" => (398, KEYWORD_some),
-    "source" => (399, KEYWORD_source),
-    "split" => (400, KEYWORD_split),
-    "sql" => (401, KEYWORD_sql),
-    "stable" => (402, KEYWORD_stable),
-    "standalone" => (403, KEYWORD_standalone),
-    "start" => (404, KEYWORD_start),
-    "statement" => (405, KEYWORD_statement),
-    "statistics" => (406, KEYWORD_statistics),
-    "stdin" => (407, KEYWORD_stdin),
-    "stdout" => (408, KEYWORD_stdout),
-    "storage" => (409, KEYWORD_storage),
-    "stored" => (410, KEYWORD_stored),
-    "strict" => (411, KEYWORD_strict),
-    "string" => (412, KEYWORD_string),
-    "strip" => (413, KEYWORD_strip),
-    "subscription" => (414, KEYWORD_subscription),
-    "substring" => (415, KEYWORD_substring),
-    "support" => (416, KEYWORD_support),
-    "symmetric" => (417, KEYWORD_symmetric),
-    "sysid" => (418, KEYWORD_sysid),
-    "system" => (419, KEYWORD_system),
-    "system_user" => (420, KEYWORD_system_user),
-    "table" => (421, KEYWORD_table),
-    "tables" => (422, KEYWORD_tables),
-    "tablesample" => (423, KEYWORD_tablesample),
-    "tablespace" => (424, KEYWORD_tablespace),
-    "target" => (425, KEYWORD_target),
-    "temp" => (426, KEYWORD_temp),
-    "template" => (427, KEYWORD_template),
-    "temporary" => (428, KEYWORD_temporary),
-    "text" => (429, KEYWORD_text),
-    "then" => (430, KEYWORD_then),
-    "ties" => (431, KEYWORD_ties),
-    "time" => (432, KEYWORD_time),
-    "timestamp" => (433, KEYWORD_timestamp),
-    "to" => (434, KEYWORD_to),
-    "trailing" => (435, KEYWORD_trailing),
-    "transaction" => (436, KEYWORD_transaction),
-    "transform" => (437, KEYWORD_transform),
-    "treat" => (438, KEYWORD_treat),
-    "trigger" => (439, KEYWORD_trigger),
-    "trim" => (440, KEYWORD_trim),
-    "true" => (441, KEYWORD_true),
-    "truncate" => (442, KEYWORD_truncate),
-    "trusted" => (443, KEYWORD_trusted),
-    "type" => (444, KEYWORD_type),
-    "types" => (445, KEYWORD_types),
-    "uescape" => (446, KEYWORD_uescape),
-    "unbounded" => (447, KEYWORD_unbounded),
-    "uncommitted" => (448, KEYWORD_uncommitted),
-    "unconditional" => (449, KEYWORD_unconditional),
-    "unencrypted" => (450, KEYWORD_unencrypted),
-    "union" => (451, KEYWORD_union),
-    "unique" => (452, KEYWORD_unique),
-    "unknown" => (453, KEYWORD_unknown),
-    "unlisten" => (454, KEYWORD_unlisten),
-    "unlogged" => (455, KEYWORD_unlogged),
-    "until" => (456, KEYWORD_until),
-    "update" => (457, KEYWORD_update),
-    "user" => (458, KEYWORD_user),
-    "using" => (459, KEYWORD_using),
-    "vacuum" => (460, KEYWORD_vacuum),
-    "valid" => (461, KEYWORD_valid),
-    "validate" => (462, KEYWORD_validate),
-    "validator" => (463, KEYWORD_validator),
-    "value" => (464, KEYWORD_value),
-    "values" => (465, KEYWORD_values),
-    "varchar" => (466, KEYWORD_varchar),
-    "variadic" => (467, KEYWORD_variadic),
-    "varying" => (468, KEYWORD_varying),
-    "verbose" => (469, KEYWORD_verbose),
-    "version" => (470, KEYWORD_version),
-    "view" => (471, KEYWORD_view),
-    "views" => (472, KEYWORD_views),
-    "virtual" => (473, KEYWORD_virtual),
-    "volatile" => (474, KEYWORD_volatile),
-    "wait" => (475, KEYWORD_wait),
-    "when" => (476, KEYWORD_when),
-    "where" => (477, KEYWORD_where),
-    "whitespace" => (478, KEYWORD_whitespace),
-    "window" => (479, KEYWORD_window),
-    "with" => (480, KEYWORD_with),
-    "within" => (481, KEYWORD_within),
-    "without" => (482, KEYWORD_without),
-    "work" => (483, KEYWORD_work),
-    "wrapper" => (484, KEYWORD_wrapper),
-    "write" => (485, KEYWORD_write),
-    "xml" => (486, KEYWORD_xml),
-    "xmlattributes" => (487, KEYWORD_xmlattributes),
-    "xmlconcat" => (488, KEYWORD_xmlconcat),
-    "xmlelement" => (489, KEYWORD_xmlelement),
-    "xmlexists" => (490, KEYWORD_xmlexists),
-    "xmlforest" => (491, KEYWORD_xmlforest),
-    "xmlnamespaces" => (492, KEYWORD_xmlnamespaces),
-    "xmlparse" => (493, KEYWORD_xmlparse),
-    "xmlpi" => (494, KEYWORD_xmlpi),
-    "xmlroot" => (495, KEYWORD_xmlroot),
-    "xmlserialize" => (496, KEYWORD_xmlserialize),
-    "xmltable" => (497, KEYWORD_xmltable),
-    "year" => (498, KEYWORD_year),
-    "yes" => (499, KEYWORD_yes),
-    "zone" => (500, KEYWORD_zone),
+    "abort" => (1, KEYWORD_abort, Unreserved, BareLabel),
+    "absent" => (2, KEYWORD_absent, Unreserved, BareLabel),
+    "absolute" => (3, KEYWORD_absolute, Unreserved, BareLabel),
+    "access" => (4, KEYWORD_access, Unreserved, BareLabel),
+    "action" => (5, KEYWORD_action, Unreserved, BareLabel),
+    "add" => (6, KEYWORD_add, Unreserved, BareLabel),
+    "admin" => (7, KEYWORD_admin, Unreserved, BareLabel),
+    "after" => (8, KEYWORD_after, Unreserved, BareLabel),
+    "aggregate" => (9, KEYWORD_aggregate, Unreserved, BareLabel),
+    "all" => (10, KEYWORD_all, Reserved, BareLabel),
+    "also" => (11, KEYWORD_also, Unreserved, BareLabel),
+    "alter" => (12, KEYWORD_alter, Unreserved, BareLabel),
+    "always" => (13, KEYWORD_always, Unreserved, BareLabel),
+    "analyse" => (14, KEYWORD_analyse, Reserved, BareLabel),
+    "analyze" => (15, KEYWORD_analyze, Reserved, BareLabel),
+    "and" => (16, KEYWORD_and, Reserved, BareLabel),
+    "any" => (17, KEYWORD_any, Reserved, BareLabel),
+    "array" => (18, KEYWORD_array, Reserved, AsLabel),
+    "as" => (19, KEYWORD_as, Reserved, AsLabel),
+    "asc" => (20, KEYWORD_asc, Reserved, BareLabel),
+    "asensitive" => (21, KEYWORD_asensitive, Unreserved, BareLabel),
+    "assertion" => (22, KEYWORD_assertion, Unreserved, BareLabel),
+    "assignment" => (23, KEYWORD_assignment, Unreserved, BareLabel),
+    "asymmetric" => (24, KEYWORD_asymmetric, Reserved, BareLabel),
+    "at" => (25, KEYWORD_at, Unreserved, BareLabel),
+    "atomic" => (26, KEYWORD_atomic, Unreserved, BareLabel),
+    "attach" => (27, KEYWORD_attach, Unreserved, BareLabel),
+    "attribute" => (28, KEYWORD_attribute, Unreserved, BareLabel),
+    "authorization" => (29, KEYWORD_authorization, TypeFuncName, BareLabel),
+    "backward" => (30, KEYWORD_backward, Unreserved, BareLabel),
+    "before" => (31, KEYWORD_before, Unreserved, BareLabel),
+    "begin" => (32, KEYWORD_begin, Unreserved, BareLabel),
+    "between" => (33, KEYWORD_between, ColName, BareLabel),
+    "bigint" => (34, KEYWORD_bigint, ColName, BareLabel),
+    "binary" => (35, KEYWORD_binary, TypeFuncName, BareLabel),
+    "bit" => (36, KEYWORD_bit, ColName, BareLabel),
+    "boolean" => (37, KEYWORD_boolean, ColName, BareLabel),
+    "both" => (38, KEYWORD_both, Reserved, BareLabel),
+    "breadth" => (39, KEYWORD_breadth, Unreserved, BareLabel),
+    "by" => (40, KEYWORD_by, Unreserved, BareLabel),
+    "cache" => (41, KEYWORD_cache, Unreserved, BareLabel),
+    "call" => (42, KEYWORD_call, Unreserved, BareLabel),
+    "called" => (43, KEYWORD_called, Unreserved, BareLabel),
+    "cascade" => (44, KEYWORD_cascade, Unreserved, BareLabel),
+    "cascaded" => (45, KEYWORD_cascaded, Unreserved, BareLabel),
+    "case" => (46, KEYWORD_case, Reserved, BareLabel),
+    "cast" => (47, KEYWORD_cast, Reserved, BareLabel),
+    "catalog" => (48, KEYWORD_catalog, Unreserved, BareLabel),
+    "chain" => (49, KEYWORD_chain, Unreserved, BareLabel),
+    "char" => (50, KEYWORD_char, ColName, AsLabel),
+    "character" => (51, KEYWORD_character, ColName, AsLabel),
+    "characteristics" => (52, KEYWORD_characteristics, Unreserved, BareLabel),
+    "check" => (53, KEYWORD_check, Reserved, BareLabel),
+    "checkpoint" => (54, KEYWORD_checkpoint, Unreserved, BareLabel),
+    "class" => (55, KEYWORD_class, Unreserved, BareLabel),
+    "close" => (56, KEYWORD_close, Unreserved, BareLabel),
+    "cluster" => (57, KEYWORD_cluster, Unreserved, BareLabel),
+    "coalesce" => (58, KEYWORD_coalesce, ColName, BareLabel),
+    "collate" => (59, KEYWORD_collate, Reserved, BareLabel),
+    "collation" => (60, KEYWORD_collation, TypeFuncName, BareLabel),
+    "column" => (61, KEYWORD_column, Reserved, BareLabel),
+    "columns" => (62, KEYWORD_columns, Unreserved, BareLabel),
+    "comment" => (63, KEYWORD_comment, Unreserved, BareLabel),
+    "comments" => (64, KEYWORD_comments, Unreserved, BareLabel),
+    "commit" => (65, KEYWORD_commit, Unreserved, BareLabel),
+    "committed" => (66, KEYWORD_committed, Unreserved, BareLabel),
+    "compression" => (67, KEYWORD_compression, Unreserved, BareLabel),
+    "concurrently" => (68, KEYWORD_concurrently, TypeFuncName, BareLabel),
+    "conditional" => (69, KEYWORD_conditional, Unreserved, BareLabel),
+    "configuration" => (70, KEYWORD_configuration, Unreserved, BareLabel),
+    "conflict" => (71, KEYWORD_conflict, Unreserved, BareLabel),
+    "connection" => (72, KEYWORD_connection, Unreserved, BareLabel),
+    "constraint" => (73, KEYWORD_constraint, Reserved, BareLabel),
+    "constraints" => (74, KEYWORD_constraints, Unreserved, BareLabel),
+    "content" => (75, KEYWORD_content, Unreserved, BareLabel),
+    "continue" => (76, KEYWORD_continue, Unreserved, BareLabel),
+    "conversion" => (77, KEYWORD_conversion, Unreserved, BareLabel),
+    "copy" => (78, KEYWORD_copy, Unreserved, BareLabel),
+    "cost" => (79, KEYWORD_cost, Unreserved, BareLabel),
+    "create" => (80, KEYWORD_create, Reserved, AsLabel),
+    "cross" => (81, KEYWORD_cross, TypeFuncName, BareLabel),
+    "csv" => (82, KEYWORD_csv, Unreserved, BareLabel),
+    "cube" => (83, KEYWORD_cube, Unreserved, BareLabel),
+    "current" => (84, KEYWORD_current, Unreserved, BareLabel),
+    "current_catalog" => (85, KEYWORD_current_catalog, Reserved, BareLabel),
+    "current_date" => (86, KEYWORD_current_date, Reserved, BareLabel),
+    "current_role" => (87, KEYWORD_current_role, Reserved, BareLabel),
+    "current_schema" => (88, KEYWORD_current_schema, TypeFuncName, BareLabel),
+    "current_time" => (89, KEYWORD_current_time, Reserved, BareLabel),
+    "current_timestamp" => (90, KEYWORD_current_timestamp, Reserved, BareLabel),
+    "current_user" => (91, KEYWORD_current_user, Reserved, BareLabel),
+    "cursor" => (92, KEYWORD_cursor, Unreserved, BareLabel),
+    "cycle" => (93, KEYWORD_cycle, Unreserved, BareLabel),
+    "data" => (94, KEYWORD_data, Unreserved, BareLabel),
+    "database" => (95, KEYWORD_database, Unreserved, BareLabel),
+    "day" => (96, KEYWORD_day, Unreserved, AsLabel),
+    "deallocate" => (97, KEYWORD_deallocate, Unreserved, BareLabel),
+    "dec" => (98, KEYWORD_dec, ColName, BareLabel),
+    "decimal" => (99, KEYWORD_decimal, ColName, BareLabel),
+    "declare" => (100, KEYWORD_declare, Unreserved, BareLabel),
+    "default" => (101, KEYWORD_default, Reserved, BareLabel),
+    "defaults" => (102, KEYWORD_defaults, Unreserved, BareLabel),
+    "deferrable" => (103, KEYWORD_deferrable, Reserved, BareLabel),
+    "deferred" => (104, KEYWORD_deferred, Unreserved, BareLabel),
+    "definer" => (105, KEYWORD_definer, Unreserved, BareLabel),
+    "delete" => (106, KEYWORD_delete, Unreserved, BareLabel),
+    "delimiter" => (107, KEYWORD_delimiter, Unreserved, BareLabel),
+    "delimiters" => (108, KEYWORD_delimiters, Unreserved, BareLabel),
+    "depends" => (109, KEYWORD_depends, Unreserved, BareLabel),
+    "depth" => (110, KEYWORD_depth, Unreserved, BareLabel),
+    "desc" => (111, KEYWORD_desc, Reserved, BareLabel),
+    "detach" => (112, KEYWORD_detach, Unreserved, BareLabel),
+    "dictionary" => (113, KEYWORD_dictionary, Unreserved, BareLabel),
+    "disable" => (114, KEYWORD_disable, Unreserved, BareLabel),
+    "discard" => (115, KEYWORD_discard, Unreserved, BareLabel),
+    "distinct" => (116, KEYWORD_distinct, Reserved, BareLabel),
+    "do" => (117, KEYWORD_do, Reserved, BareLabel),
+    "document" => (118, KEYWORD_document, Unreserved, BareLabel),
+    "domain" => (119, KEYWORD_domain, Unreserved, BareLabel),
+    "double" => (120, KEYWORD_double, Unreserved, BareLabel),
+    "drop" => (121, KEYWORD_drop, Unreserved, BareLabel),
+    "each" => (122, KEYWORD_each, Unreserved, BareLabel),
+    "else" => (123, KEYWORD_else, Reserved, BareLabel),
+    "empty" => (124, KEYWORD_empty, Unreserved, BareLabel),
+    "enable" => (125, KEYWORD_enable, Unreserved, BareLabel),
+    "encoding" => (126, KEYWORD_encoding, Unreserved, BareLabel),
+    "encrypted" => (127, KEYWORD_encrypted, Unreserved, BareLabel),
+    "end" => (128, KEYWORD_end, Reserved, BareLabel),
+    "enforced" => (129, KEYWORD_enforced, Unreserved, BareLabel),
+    "enum" => (130, KEYWORD_enum, Unreserved, BareLabel),
+    "error" => (131, KEYWORD_error, Unreserved, BareLabel),
+    "escape" => (132, KEYWORD_escape, Unreserved, BareLabel),
+    "event" => (133, KEYWORD_event, Unreserved, BareLabel),
+    "except" => (134, KEYWORD_except, Reserved, AsLabel),
+    "exclude" => (135, KEYWORD_exclude, Unreserved, BareLabel),
+    "excluding" => (136, KEYWORD_excluding, Unreserved, BareLabel),
+    "exclusive" => (137, KEYWORD_exclusive, Unreserved, BareLabel),
+    "execute" => (138, KEYWORD_execute, Unreserved, BareLabel),
+    "exists" => (139, KEYWORD_exists, ColName, BareLabel),
+    "explain" => (140, KEYWORD_explain, Unreserved, BareLabel),
+    "expression" => (141, KEYWORD_expression, Unreserved, BareLabel),
+    "extension" => (142, KEYWORD_extension, Unreserved, BareLabel),
+    "external" => (143, KEYWORD_external, Unreserved, BareLabel),
+    "extract" => (144, KEYWORD_extract, ColName, BareLabel),
+    "false" => (145, KEYWORD_false, Reserved, BareLabel),
+    "family" => (146, KEYWORD_family, Unreserved, BareLabel),
+    "fetch" => (147, KEYWORD_fetch, Reserved, AsLabel),
+    "filter" => (148, KEYWORD_filter, Unreserved, AsLabel),
+    "finalize" => (149, KEYWORD_finalize, Unreserved, BareLabel),
+    "first" => (150, KEYWORD_first, Unreserved, BareLabel),
+    "float" => (151, KEYWORD_float, ColName, BareLabel),
+    "following" => (152, KEYWORD_following, Unreserved, BareLabel),
+    "for" => (153, KEYWORD_for, Reserved, AsLabel),
+    "force" => (154, KEYWORD_force, Unreserved, BareLabel),
+    "foreign" => (155, KEYWORD_foreign, Reserved, BareLabel),
+    "format" => (156, KEYWORD_format, Unreserved, BareLabel),
+    "forward" => (157, KEYWORD_forward, Unreserved, BareLabel),
+    "freeze" => (158, KEYWORD_freeze, TypeFuncName, BareLabel),
+    "from" => (159, KEYWORD_from, Reserved, AsLabel),
+    "full" => (160, KEYWORD_full, TypeFuncName, BareLabel),
+    "function" => (161, KEYWORD_function, Unreserved, BareLabel),
+    "functions" => (162, KEYWORD_functions, Unreserved, BareLabel),
+    "generated" => (163, KEYWORD_generated, Unreserved, BareLabel),
+    "global" => (164, KEYWORD_global, Unreserved, BareLabel),
+    "grant" => (165, KEYWORD_grant, Reserved, AsLabel),
+    "granted" => (166, KEYWORD_granted, Unreserved, BareLabel),
+    "greatest" => (167, KEYWORD_greatest, ColName, BareLabel),
+    "group" => (168, KEYWORD_group, Reserved, AsLabel),
+    "grouping" => (169, KEYWORD_grouping, ColName, BareLabel),
+    "groups" => (170, KEYWORD_groups, Unreserved, BareLabel),
+    "handler" => (171, KEYWORD_handler, Unreserved, BareLabel),
+    "having" => (172, KEYWORD_having, Reserved, AsLabel),
+    "header" => (173, KEYWORD_header, Unreserved, BareLabel),
+    "hold" => (174, KEYWORD_hold, Unreserved, BareLabel),
+    "hour" => (175, KEYWORD_hour, Unreserved, AsLabel),
+    "identity" => (176, KEYWORD_identity, Unreserved, BareLabel),
+    "if" => (177, KEYWORD_if, Unreserved, BareLabel),
+    "ignore" => (178, KEYWORD_ignore, Unreserved, BareLabel),
+    "ilike" => (179, KEYWORD_ilike, TypeFuncName, BareLabel),
+    "immediate" => (180, KEYWORD_immediate, Unreserved, BareLabel),
+    "immutable" => (181, KEYWORD_immutable, Unreserved, BareLabel),
+    "implicit" => (182, KEYWORD_implicit, Unreserved, BareLabel),
+    "import" => (183, KEYWORD_import, Unreserved, BareLabel),
+    "in" => (184, KEYWORD_in, Reserved, BareLabel),
+    "include" => (185, KEYWORD_include, Unreserved, BareLabel),
+    "including" => (186, KEYWORD_including, Unreserved, BareLabel),
+    "increment" => (187, KEYWORD_increment, Unreserved, BareLabel),
+    "indent" => (188, KEYWORD_indent, Unreserved, BareLabel),
+    "index" => (189, KEYWORD_index, Unreserved, BareLabel),
+    "indexes" => (190, KEYWORD_indexes, Unreserved, BareLabel),
+    "inherit" => (191, KEYWORD_inherit, Unreserved, BareLabel),
+    "inherits" => (192, KEYWORD_inherits, Unreserved, BareLabel),
+    "initially" => (193, KEYWORD_initially, Reserved, BareLabel),
+    "inline" => (194, KEYWORD_inline, Unreserved, BareLabel),
+    "inner" => (195, KEYWORD_inner, TypeFuncName, BareLabel),
+    "inout" => (196, KEYWORD_inout, ColName, BareLabel),
+    "input" => (197, KEYWORD_input, Unreserved, BareLabel),
+    "insensitive" => (198, KEYWORD_insensitive, Unreserved, BareLabel),
+    "insert" => (199, KEYWORD_insert, Unreserved, BareLabel),
+    "instead" => (200, KEYWORD_instead, Unreserved, BareLabel),
+    "int" => (201, KEYWORD_int, ColName, BareLabel),
+    "integer" => (202, KEYWORD_integer, ColName, BareLabel),
+    "intersect" => (203, KEYWORD_intersect, Reserved, AsLabel),
+    "interval" => (204, KEYWORD_interval, ColName, BareLabel),
+    "into" => (205, KEYWORD_into, Reserved, AsLabel),
+    "invoker" => (206, KEYWORD_invoker, Unreserved, BareLabel),
+    "is" => (207, KEYWORD_is, TypeFuncName, BareLabel),
+    "isnull" => (208, KEYWORD_isnull, TypeFuncName, AsLabel),
+    "isolation" => (209, KEYWORD_isolation, Unreserved, BareLabel),
+    "join" => (210, KEYWORD_join, TypeFuncName, BareLabel),
+    "json" => (211, KEYWORD_json, ColName, BareLabel),
+    "json_array" => (212, KEYWORD_json_array, ColName, BareLabel),
+    "json_arrayagg" => (213, KEYWORD_json_arrayagg, ColName, BareLabel),
+    "json_exists" => (214, KEYWORD_json_exists, ColName, BareLabel),
+    "json_object" => (215, KEYWORD_json_object, ColName, BareLabel),
+    "json_objectagg" => (216, KEYWORD_json_objectagg, ColName, BareLabel),
+    "json_query" => (217, KEYWORD_json_query, ColName, BareLabel),
+    "json_scalar" => (218, KEYWORD_json_scalar, ColName, BareLabel),
+    "json_serialize" => (219, KEYWORD_json_serialize, ColName, BareLabel),
+    "json_table" => (220, KEYWORD_json_table, ColName, BareLabel),
+    "json_value" => (221, KEYWORD_json_value, ColName, BareLabel),
+    "keep" => (222, KEYWORD_keep, Unreserved, BareLabel),
+    "key" => (223, KEYWORD_key, Unreserved, BareLabel),
+    "keys" => (224, KEYWORD_keys, Unreserved, BareLabel),
+    "label" => (225, KEYWORD_label, Unreserved, BareLabel),
+    "language" => (226, KEYWORD_language, Unreserved, BareLabel),
+    "large" => (227, KEYWORD_large, Unreserved, BareLabel),
+    "last" => (228, KEYWORD_last, Unreserved, BareLabel),
+    "lateral" => (229, KEYWORD_lateral, Reserved, BareLabel),
+    "leading" => (230, KEYWORD_leading, Reserved, BareLabel),
+    "leakproof" => (231, KEYWORD_leakproof, Unreserved, BareLabel),
+    "least" => (232, KEYWORD_least, ColName, BareLabel),
+    "left" => (233, KEYWORD_left, TypeFuncName, BareLabel),
+    "level" => (234, KEYWORD_level, Unreserved, BareLabel),
+    "like" => (235, KEYWORD_like, TypeFuncName, BareLabel),
+    "limit" => (236, KEYWORD_limit, Reserved, AsLabel),
+    "listen" => (237, KEYWORD_listen, Unreserved, BareLabel),
+    "load" => (238, KEYWORD_load, Unreserved, BareLabel),
+    "local" => (239, KEYWORD_local, Unreserved, BareLabel),
+    "localtime" => (240, KEYWORD_localtime, Reserved, BareLabel),
+    "localtimestamp" => (241, KEYWORD_localtimestamp, Reserved, BareLabel),
+    "location" => (242, KEYWORD_location, Unreserved, BareLabel),
+    "lock" => (243, KEYWORD_lock, Unreserved, BareLabel),
+    "locked" => (244, KEYWORD_locked, Unreserved, BareLabel),
+    "logged" => (245, KEYWORD_logged, Unreserved, BareLabel),
+    "lsn" => (246, KEYWORD_lsn, Unreserved, BareLabel),
+    "mapping" => (247, KEYWORD_mapping, Unreserved, BareLabel),
+    "match" => (248, KEYWORD_match, Unreserved, BareLabel),
+    "matched" => (249, KEYWORD_matched, Unreserved, BareLabel),
+    "materialized" => (250, KEYWORD_materialized, Unreserved, BareLabel),
+    "maxvalue" => (251, KEYWORD_maxvalue, Unreserved, BareLabel),
+    "merge" => (252, KEYWORD_merge, Unreserved, BareLabel),
+    "merge_action" => (253, KEYWORD_merge_action, ColName, BareLabel),
+    "method" => (254, KEYWORD_method, Unreserved, BareLabel),
+    "minute" => (255, KEYWORD_minute, Unreserved, AsLabel),
+    "minvalue" => (256, KEYWORD_minvalue, Unreserved, BareLabel),
+    "mode" => (257, KEYWORD_mode, Unreserved, BareLabel),
+    "month" => (258, KEYWORD_month, Unreserved, AsLabel),
+    "move" => (259, KEYWORD_move, Unreserved, BareLabel),
+    "name" => (260, KEYWORD_name, Unreserved, BareLabel),
+    "names" => (261, KEYWORD_names, Unreserved, BareLabel),
+    "national" => (262, KEYWORD_national, ColName, BareLabel),
+    "natural" => (263, KEYWORD_natural, TypeFuncName, BareLabel),
+    "nchar" => (264, KEYWORD_nchar, ColName, BareLabel),
+    "nested" => (265, KEYWORD_nested, Unreserved, BareLabel),
+    "new" => (266, KEYWORD_new, Unreserved, BareLabel),
+    "next" => (267, KEYWORD_next, Unreserved, BareLabel),
+    "nfc" => (268, KEYWORD_nfc, Unreserved, BareLabel),
+    "nfd" => (269, KEYWORD_nfd, Unreserved, BareLabel),
+    "nfkc" => (270, KEYWORD_nfkc, Unreserved, BareLabel),
+    "nfkd" => (271, KEYWORD_nfkd, Unreserved, BareLabel),
+    "no" => (272, KEYWORD_no, Unreserved, BareLabel),
+    "none" => (273, KEYWORD_none, ColName, BareLabel),
+    "normalize" => (274, KEYWORD_normalize, ColName, BareLabel),
+    "normalized" => (275, KEYWORD_normalized, Unreserved, BareLabel),
+    "not" => (276, KEYWORD_not, Reserved, BareLabel),
+    "nothing" => (277, KEYWORD_nothing, Unreserved, BareLabel),
+    "notify" => (278, KEYWORD_notify, Unreserved, BareLabel),
+    "notnull" => (279, KEYWORD_notnull, TypeFuncName, AsLabel),
+    "nowait" => (280, KEYWORD_nowait, Unreserved, BareLabel),
+    "null" => (281, KEYWORD_null, Reserved, BareLabel),
+    "nullif" => (282, KEYWORD_nullif, ColName, BareLabel),
+    "nulls" => (283, KEYWORD_nulls, Unreserved, BareLabel),
+    "numeric" => (284, KEYWORD_numeric, ColName, BareLabel),
+    "object" => (285, KEYWORD_object, Unreserved, BareLabel),
+    "objects" => (286, KEYWORD_objects, Unreserved, BareLabel),
+    "of" => (287, KEYWORD_of, Unreserved, BareLabel),
+    "off" => (288, KEYWORD_off, Unreserved, BareLabel),
+    "offset" => (289, KEYWORD_offset, Reserved, AsLabel),
+    "oids" => (290, KEYWORD_oids, Unreserved, BareLabel),
+    "old" => (291, KEYWORD_old, Unreserved, BareLabel),
+    "omit" => (292, KEYWORD_omit, Unreserved, BareLabel),
+    "on" => (293, KEYWORD_on, Reserved, AsLabel),
+    "only" => (294, KEYWORD_only, Reserved, BareLabel),
+    "operator" => (295, KEYWORD_operator, Unreserved, BareLabel),
+    "option" => (296, KEYWORD_option, Unreserved, BareLabel),
+    "options" => (297, KEYWORD_options, Unreserved, BareLabel),
+    "or" => (298, KEYWORD_or, Reserved, BareLabel),
+    "order" => (299, KEYWORD_order, Reserved, AsLabel),
+    "ordinality" => (300, KEYWORD_ordinality, Unreserved, BareLabel),
+    "others" => (301, KEYWORD_others, Unreserved, BareLabel),
+    "out" => (302, KEYWORD_out, ColName, BareLabel),
+    "outer" => (303, KEYWORD_outer, TypeFuncName, BareLabel),
+    "over" => (304, KEYWORD_over, Unreserved, AsLabel),
+    "overlaps" => (305, KEYWORD_overlaps, TypeFuncName, BareLabel),
+    "overlay" => (306, KEYWORD_overlay, ColName, BareLabel),
+    "overriding" => (307, KEYWORD_overriding, Unreserved, BareLabel),
+    "owned" => (308, KEYWORD_owned, Unreserved, BareLabel),
+    "owner" => (309, KEYWORD_owner, Unreserved, BareLabel),
+    "parallel" => (310, KEYWORD_parallel, Unreserved, BareLabel),
+    "parameter" => (311, KEYWORD_parameter, Unreserved, BareLabel),
+    "parser" => (312, KEYWORD_parser, Unreserved, BareLabel),
+    "partial" => (313, KEYWORD_partial, Unreserved, BareLabel),
+    "partition" => (314, KEYWORD_partition, Unreserved, BareLabel),
+    "partitions" => (315, KEYWORD_partitions, Unreserved, BareLabel),
+    "passing" => (316, KEYWORD_passing, Unreserved, BareLabel),
+    "password" => (317, KEYWORD_password, Unreserved, BareLabel),
+    "path" => (318, KEYWORD_path, Unreserved, BareLabel),
+    "period" => (319, KEYWORD_period, Unreserved, BareLabel),
+    "placing" => (320, KEYWORD_placing, Reserved, BareLabel),
+    "plan" => (321, KEYWORD_plan, Unreserved, BareLabel),
+    "plans" => (322, KEYWORD_plans, Unreserved, BareLabel),
+    "policy" => (323, KEYWORD_policy, Unreserved, BareLabel),
+    "position" => (324, KEYWORD_position, ColName, BareLabel),
+    "preceding" => (325, KEYWORD_preceding, Unreserved, BareLabel),
+    "precision" => (326, KEYWORD_precision, ColName, AsLabel),
+    "prepare" => (327, KEYWORD_prepare, Unreserved, BareLabel),
+    "prepared" => (328, KEYWORD_prepared, Unreserved, BareLabel),
+    "preserve" => (329, KEYWORD_preserve, Unreserved, BareLabel),
+    "primary" => (330, KEYWORD_primary, Reserved, BareLabel),
+    "prior" => (331, KEYWORD_prior, Unreserved, BareLabel),
+    "privileges" => (332, KEYWORD_privileges, Unreserved, BareLabel),
+    "procedural" => (333, KEYWORD_procedural, Unreserved, BareLabel),
+    "procedure" => (334, KEYWORD_procedure, Unreserved, BareLabel),
+    "procedures" => (335, KEYWORD_procedures, Unreserved, BareLabel),
+    "program" => (336, KEYWORD_program, Unreserved, BareLabel),
+    "publication" => (337, KEYWORD_publication, Unreserved, BareLabel),
+    "quote" => (338, KEYWORD_quote, Unreserved, BareLabel),
+    "quotes" => (339, KEYWORD_quotes, Unreserved, BareLabel),
+    "range" => (340, KEYWORD_range, Unreserved, BareLabel),
+    "read" => (341, KEYWORD_read, Unreserved, BareLabel),
+    "real" => (342, KEYWORD_real, ColName, BareLabel),
+    "reassign" => (343, KEYWORD_reassign, Unreserved, BareLabel),
+    "recursive" => (344, KEYWORD_recursive, Unreserved, BareLabel),
+    "ref" => (345, KEYWORD_ref, Unreserved, BareLabel),
+    "references" => (346, KEYWORD_references, Reserved, BareLabel),
+    "referencing" => (347, KEYWORD_referencing, Unreserved, BareLabel),
+    "refresh" => (348, KEYWORD_refresh, Unreserved, BareLabel),
+    "reindex" => (349, KEYWORD_reindex, Unreserved, BareLabel),
+    "relative" => (350, KEYWORD_relative, Unreserved, BareLabel),
+    "release" => (351, KEYWORD_release, Unreserved, BareLabel),
+    "rename" => (352, KEYWORD_rename, Unreserved, BareLabel),
+    "repeatable" => (353, KEYWORD_repeatable, Unreserved, BareLabel),
+    "replace" => (354, KEYWORD_replace, Unreserved, BareLabel),
+    "replica" => (355, KEYWORD_replica, Unreserved, BareLabel),
+    "reset" => (356, KEYWORD_reset, Unreserved, BareLabel),
+    "respect" => (357, KEYWORD_respect, Unreserved, BareLabel),
+    "restart" => (358, KEYWORD_restart, Unreserved, BareLabel),
+    "restrict" => (359, KEYWORD_restrict, Unreserved, BareLabel),
+    "return" => (360, KEYWORD_return, Unreserved, BareLabel),
+    "returning" => (361, KEYWORD_returning, Reserved, AsLabel),
+    "returns" => (362, KEYWORD_returns, Unreserved, BareLabel),
+    "revoke" => (363, KEYWORD_revoke, Unreserved, BareLabel),
+    "right" => (364, KEYWORD_right, TypeFuncName, BareLabel),
+    "role" => (365, KEYWORD_role, Unreserved, BareLabel),
+    "rollback" => (366, KEYWORD_rollback, Unreserved, BareLabel),
+    "rollup" => (367, KEYWORD_rollup, Unreserved, BareLabel),
+    "routine" => (368, KEYWORD_routine, Unreserved, BareLabel),
+    "routines" => (369, KEYWORD_routines, Unreserved, BareLabel),
+    "row" => (370, KEYWORD_row, ColName, BareLabel),
+    "rows" => (371, KEYWORD_rows, Unreserved, BareLabel),
+    "rule" => (372, KEYWORD_rule, Unreserved, BareLabel),
+    "savepoint" => (373, KEYWORD_savepoint, Unreserved, BareLabel),
+    "scalar" => (374, KEYWORD_scalar, Unreserved, BareLabel),
+    "schema" => (375, KEYWORD_schema, Unreserved, BareLabel),
+    "schemas" => (376, KEYWORD_schemas, Unreserved, BareLabel),
+    "scroll" => (377, KEYWORD_scroll, Unreserved, BareLabel),
+    "search" => (378, KEYWORD_search, Unreserved, BareLabel),
+    "second" => (379, KEYWORD_second, Unreserved, AsLabel),
+    "security" => (380, KEYWORD_security, Unreserved, BareLabel),
+    "select" => (381, KEYWORD_select, Reserved, BareLabel),
+    "sequence" => (382, KEYWORD_sequence, Unreserved, BareLabel),
+    "sequences" => (383, KEYWORD_sequences, Unreserved, BareLabel),
+    "serializable" => (384, KEYWORD_serializable, Unreserved, BareLabel),
+    "server" => (385, KEYWORD_server, Unreserved, BareLabel),
+    "session" => (386, KEYWORD_session, Unreserved, BareLabel),
+    "session_user" => (387, KEYWORD_session_user, Reserved, BareLabel),
+    "set" => (388, KEYWORD_set, Unreserved, BareLabel),
+    "setof" => (389, KEYWORD_setof, ColName, BareLabel),
+    "sets" => (390, KEYWORD_sets, Unreserved, BareLabel),
+    "share" => (391, KEYWORD_share, Unreserved, BareLabel),
+    "show" => (392, KEYWORD_show, Unreserved, BareLabel),
+    "similar" => (393, KEYWORD_similar, TypeFuncName, BareLabel),
+    "simple" => (394, KEYWORD_simple, Unreserved, BareLabel),
+    "skip" => (395, KEYWORD_skip, Unreserved, BareLabel),
+    "smallint" => (396, KEYWORD_smallint, ColName, BareLabel),
+    "snapshot" => (397, KEYWORD_snapshot, Unreserved, BareLabel),
+    "some" => (398, KEYWORD_some, Reserved, BareLabel),
+    "source" => (399, KEYWORD_source, Unreserved, BareLabel),
+    "split" => (400, KEYWORD_split, Unreserved, BareLabel),
+    "sql" => (401, KEYWORD_sql, Unreserved, BareLabel),
+    "stable" => (402, KEYWORD_stable, Unreserved, BareLabel),
+    "standalone" => (403, KEYWORD_standalone, Unreserved, BareLabel),
+    "start" => (404, KEYWORD_start, Unreserved, BareLabel),
+    "statement" => (405, KEYWORD_statement, Unreserved, BareLabel),
+    "statistics" => (406, KEYWORD_statistics, Unreserved, BareLabel),
+    "stdin" => (407, KEYWORD_stdin, Unreserved, BareLabel),
+    "stdout" => (408, KEYWORD_stdout, Unreserved, BareLabel),
+    "storage" => (409, KEYWORD_storage, Unreserved, BareLabel),
+    "stored" => (410, KEYWORD_stored, Unreserved, BareLabel),
+    "strict" => (411, KEYWORD_strict, Unreserved, BareLabel),
+    "string" => (412, KEYWORD_string, Unreserved, BareLabel),
+    "strip" => (413, KEYWORD_strip, Unreserved, BareLabel),
+    "subscription" => (414, KEYWORD_subscription, Unreserved, BareLabel),
+    "substring" => (415, KEYWORD_substring, ColName, BareLabel),
+    "support" => (416, KEYWORD_support, Unreserved, BareLabel),
+    "symmetric" => (417, KEYWORD_symmetric, Reserved, BareLabel),
+    "sysid" => (418, KEYWORD_sysid, Unreserved, BareLabel),
+    "system" => (419, KEYWORD_system, Unreserved, BareLabel),
+    "system_user" => (420, KEYWORD_system_user, Reserved, BareLabel),
+    "table" => (421, KEYWORD_table, Reserved, BareLabel),
+    "tables" => (422, KEYWORD_tables, Unreserved, BareLabel),
+    "tablesample" => (423, KEYWORD_tablesample, TypeFuncName, BareLabel),
+    "tablespace" => (424, KEYWORD_tablespace, Unreserved, BareLabel),
+    "target" => (425, KEYWORD_target, Unreserved, BareLabel),
+    "temp" => (426, KEYWORD_temp, Unreserved, BareLabel),
+    "template" => (427, KEYWORD_template, Unreserved, BareLabel),
+    "temporary" => (428, KEYWORD_temporary, Unreserved, BareLabel),
+    "text" => (429, KEYWORD_text, Unreserved, BareLabel),
+    "then" => (430, KEYWORD_then, Reserved, BareLabel),
+    "ties" => (431, KEYWORD_ties, Unreserved, BareLabel),
+    "time" => (432, KEYWORD_time, ColName, BareLabel),
+    "timestamp" => (433, KEYWORD_timestamp, ColName, BareLabel),
+    "to" => (434, KEYWORD_to, Reserved, AsLabel),
+    "trailing" => (435, KEYWORD_trailing, Reserved, BareLabel),
+    "transaction" => (436, KEYWORD_transaction, Unreserved, BareLabel),
+    "transform" => (437, KEYWORD_transform, Unreserved, BareLabel),
+    "treat" => (438, KEYWORD_treat, ColName, BareLabel),
+    "trigger" => (439, KEYWORD_trigger, Unreserved, BareLabel),
+    "trim" => (440, KEYWORD_trim, ColName, BareLabel),
+    "true" => (441, KEYWORD_true, Reserved, BareLabel),
+    "truncate" => (442, KEYWORD_truncate, Unreserved, BareLabel),
+    "trusted" => (443, KEYWORD_trusted, Unreserved, BareLabel),
+    "type" => (444, KEYWORD_type, Unreserved, BareLabel),
+    "types" => (445, KEYWORD_types, Unreserved, BareLabel),
+    "uescape" => (446, KEYWORD_uescape, Unreserved, BareLabel),
+    "unbounded" => (447, KEYWORD_unbounded, Unreserved, BareLabel),
+    "uncommitted" => (448, KEYWORD_uncommitted, Unreserved, BareLabel),
+    "unconditional" => (449, KEYWORD_unconditional, Unreserved, BareLabel),
+    "unencrypted" => (450, KEYWORD_unencrypted, Unreserved, BareLabel),
+    "union" => (451, KEYWORD_union, Reserved, AsLabel),
+    "unique" => (452, KEYWORD_unique, Reserved, BareLabel),
+    "unknown" => (453, KEYWORD_unknown, Unreserved, BareLabel),
+    "unlisten" => (454, KEYWORD_unlisten, Unreserved, BareLabel),
+    "unlogged" => (455, KEYWORD_unlogged, Unreserved, BareLabel),
+    "until" => (456, KEYWORD_until, Unreserved, BareLabel),
+    "update" => (457, KEYWORD_update, Unreserved, BareLabel),
+    "user" => (458, KEYWORD_user, Reserved, BareLabel),
+    "using" => (459, KEYWORD_using, Reserved, BareLabel),
+    "vacuum" => (460, KEYWORD_vacuum, Unreserved, BareLabel),
+    "valid" => (461, KEYWORD_valid, Unreserved, BareLabel),
+    "validate" => (462, KEYWORD_validate, Unreserved, BareLabel),
+    "validator" => (463, KEYWORD_validator, Unreserved, BareLabel),
+    "value" => (464, KEYWORD_value, Unreserved, BareLabel),
+    "values" => (465, KEYWORD_values, ColName, BareLabel),
+    "varchar" => (466, KEYWORD_varchar, ColName, BareLabel),
+    "variadic" => (467, KEYWORD_variadic, Reserved, BareLabel),
+    "varying" => (468, KEYWORD_varying, Unreserved, AsLabel),
+    "verbose" => (469, KEYWORD_verbose, TypeFuncName, BareLabel),
+    "version" => (470, KEYWORD_version, Unreserved, BareLabel),
+    "view" => (471, KEYWORD_view, Unreserved, BareLabel),
+    "views" => (472, KEYWORD_views, Unreserved, BareLabel),
+    "virtual" => (473, KEYWORD_virtual, Unreserved, BareLabel),
+    "volatile" => (474, KEYWORD_volatile, Unreserved, BareLabel),
+    "wait" => (475, KEYWORD_wait, Unreserved, BareLabel),
+    "when" => (476, KEYWORD_when, Reserved, BareLabel),
+    "where" => (477, KEYWORD_where, Reserved, AsLabel),
+    "whitespace" => (478, KEYWORD_whitespace, Unreserved, BareLabel),
+    "window" => (479, KEYWORD_window, Reserved, AsLabel),
+    "with" => (480, KEYWORD_with, Reserved, AsLabel),
+    "within" => (481, KEYWORD_within, Unreserved, AsLabel),
+    "without" => (482, KEYWORD_without, Unreserved, AsLabel),
+    "work" => (483, KEYWORD_work, Unreserved, BareLabel),
+    "wrapper" => (484, KEYWORD_wrapper, Unreserved, BareLabel),
+    "write" => (485, KEYWORD_write, Unreserved, BareLabel),
+    "xml" => (486, KEYWORD_xml, Unreserved, BareLabel),
+    "xmlattributes" => (487, KEYWORD_xmlattributes, ColName, BareLabel),
+    "xmlconcat" => (488, KEYWORD_xmlconcat, ColName, BareLabel),
+    "xmlelement" => (489, KEYWORD_xmlelement, ColName, BareLabel),
+    "xmlexists" => (490, KEYWORD_xmlexists, ColName, BareLabel),
+    "xmlforest" => (491, KEYWORD_xmlforest, ColName, BareLabel),
+    "xmlnamespaces" => (492, KEYWORD_xmlnamespaces, ColName, BareLabel),
+    "xmlparse" => (493, KEYWORD_xmlparse, ColName, BareLabel),
+    "xmlpi" => (494, KEYWORD_xmlpi, ColName, BareLabel),
+    "xmlroot" => (495, KEYWORD_xmlroot, ColName, BareLabel),
+    "xmlserialize" => (496, KEYWORD_xmlserialize, ColName, BareLabel),
+    "xmltable" => (497, KEYWORD_xmltable, ColName, BareLabel),
+    "year" => (498, KEYWORD_year, Unreserved, AsLabel),
+    "yes" => (499, KEYWORD_yes, Unreserved, BareLabel),
+    "zone" => (500, KEYWORD_zone, Unreserved, BareLabel),
 );
 
 #[allow(non_upper_case_globals)]
@@ -812,6 +891,56 @@ mod tests {
         assert_eq!(hash1, hash2);
     }
 
+    #[test]
+    fn test_symbol_keyword_category() {
+        assert_eq!(
+            Symbol::KEYWORD_abort.keyword_category(),
+            Some(KeywordCategory::Unreserved)
+        );
+        assert_eq!(
+            Symbol::KEYWORD_between.keyword_category(),
+            Some(KeywordCategory::ColName)
+        );
+        assert_eq!(
+            Symbol::KEYWORD_join.keyword_category(),
+            Some(KeywordCategory::TypeFuncName)
+        );
+        assert_eq!(
+            Symbol::KEYWORD_select.keyword_category(),
+            Some(KeywordCategory::Reserved)
+        );
+        assert_eq!(Symbol::from("foo").keyword_category(), None);
+        assert_eq!(Symbol::default().keyword_category(), None);
+    }
+
+    #[test]
+    fn test_symbol_is_bare_col_label() {
+        assert!(Symbol::from("foo").is_bare_col_label());
+        assert!(Symbol::KEYWORD_select.is_bare_col_label());
+        assert!(Symbol::KEYWORD_user.is_bare_col_label());
+        assert!(!Symbol::KEYWORD_from.is_bare_col_label());
+        assert!(!Symbol::KEYWORD_year.is_bare_col_label());
+        assert!(!Symbol::KEYWORD_char.is_bare_col_label());
+    }
+
+    #[test]
+    fn test_symbol_name_contexts() {
+        assert!(Symbol::from("foo").is_col_id());
+        assert!(Symbol::KEYWORD_abort.is_col_id());
+        assert!(Symbol::KEYWORD_time.is_col_id());
+        assert!(!Symbol::KEYWORD_left.is_col_id());
+        assert!(!Symbol::KEYWORD_order.is_col_id());
+
+        assert!(Symbol::from("foo").is_type_function_name());
+        assert!(Symbol::KEYWORD_left.is_type_function_name());
+        assert!(!Symbol::KEYWORD_time.is_type_function_name());
+        assert!(!Symbol::KEYWORD_order.is_type_function_name());
+
+        assert!(Symbol::KEYWORD_left.is_non_reserved_word());
+        assert!(Symbol::KEYWORD_time.is_non_reserved_word());
+        assert!(!Symbol::KEYWORD_order.is_non_reserved_word());
+    }
+
     #[test]
     fn test_symbol_default() {
         let sym = Symbol::default();
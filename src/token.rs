@@ -10,6 +10,24 @@ pub(crate) struct Token {
     pub range: CodeRange,
 }
 
+impl Token {
+    /// Whether the token is the given keyword, i.e. an unquoted identifier of that name.
+    pub fn is_keyword(&self, keyword: &Symbol) -> bool {
+        self.keyword() == Some(keyword)
+    }
+
+    /// The name of an unquoted identifier, which may be a keyword.
+    pub fn keyword(&self) -> Option<&Symbol> {
+        match &self.kind {
+            TokenKind::Identifier {
+                name,
+                quoted: false,
+            } => Some(name),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) enum TokenKind {
     /// A virtual token representing the end of the input stream.
@@ -17,7 +35,7 @@ pub(crate) enum TokenKind {
     /// Keyword, unquoted identifier, or quoted identifier.
     ///
     /// - Unquoted (`foo`), always folded to lowercase.
-    /// - Quoted(`"foo"`)
+    /// - Quoted (`"foo"`), or with Unicode escapes (`U&"d\0061t\+000061"`).
     Identifier {
         /// The name value.
         ///
@@ -29,12 +47,48 @@ pub(crate) enum TokenKind {
         /// When false, the identifier may be treated as a keyword.
         quoted: bool,
     },
-    /// A nonnegative integer literal. It ultimately results in one of:
+    /// A nonnegative integer literal, in decimal (`123`), hexadecimal (`0x7B`),
+    /// octal (`0o173`), or binary (`0b1111011`) notation.
+    /// It ultimately results in one of:
     ///
     /// - integer (i32)
     /// - bigint (i64)
     /// - numeric (BigInt plus scale of 10^(-n))
     Integer(BigInt),
+    /// A nonnegative numeric literal with a decimal point or an exponent,
+    /// such as `1.5`, `.5`, `1.`, or `1e10`.
+    ///
+    /// The value is `mantissa * 10^(-scale)`. Trailing zeros are kept in the mantissa
+    /// so that `1.50` has scale 2. The scale is negative for something like `1e10`.
+    Numeric { mantissa: BigInt, scale: i64 },
+    /// A character string literal: standard (`'foo'`), escape (`E'foo'`),
+    /// or with Unicode escapes (`U&'d\0061t\+000061'`).
+    ///
+    /// Quotation marks have been removed, and escape sequences have been processed.
+    /// Adjacent literals separated by whitespace containing a newline
+    /// have already been concatenated.
+    String(String),
+    /// A dollar-quoted string literal (`$$foo$$` or `$tag$foo$tag$`).
+    ///
+    /// The value is the raw content between the delimiters; no escape sequences
+    /// are processed. The parser treats it in the same way as [`TokenKind::String`].
+    DollarString(String),
+    /// A national character string literal (`N'foo'`).
+    ///
+    /// PostgreSQL treats it as a string literal of type `nchar`.
+    NationalString(String),
+    /// A bit-string literal in binary notation (`B'0101'`).
+    ///
+    /// The value is the raw content between the quotes; it is validated
+    /// when it is converted to a bit string.
+    BitString(String),
+    /// A bit-string literal in hexadecimal notation (`X'1F'`).
+    ///
+    /// The value is the raw content between the quotes; it is validated
+    /// when it is converted to a bit string.
+    HexString(String),
+    /// A positional parameter (`$1`, `$2`, ...), holding its number.
+    Param(u32),
     /// `(`
     LParen,
     /// `)`
@@ -89,6 +143,17 @@ pub(crate) enum TokenKind {
     Ge,
     /// User-defined operator, such as `<->` or `@>`.
     UserOp(String),
+    /// `FORMAT` followed by `JSON`. See [`crate::token_filter::TokenFilter`].
+    FormatLa,
+    /// `NOT` followed by `BETWEEN`, `IN`, `LIKE`, `ILIKE`, or `SIMILAR`.
+    /// See [`crate::token_filter::TokenFilter`].
+    NotLa,
+    /// `NULLS` followed by `FIRST` or `LAST`. See [`crate::token_filter::TokenFilter`].
+    NullsLa,
+    /// `WITH` followed by `TIME` or `ORDINALITY`. See [`crate::token_filter::TokenFilter`].
+    WithLa,
+    /// `WITHOUT` followed by `TIME`. See [`crate::token_filter::TokenFilter`].
+    WithoutLa,
     /// An unknown token. The error has already been reported.
     Unknown,
 }
@@ -0,0 +1,319 @@
+// https://github.com/postgres/postgres/blob/REL_18_1/src/backend/parser/parser.c
+
+use crate::{
+    Symbol,
+    diag::CodeDiagnostics,
+    lexer::Lexer,
+    token::{Token, TokenKind},
+};
+
+/// A filter between the lexer and the parser, corresponding to `base_yylex` in PostgreSQL.
+///
+/// Some keywords are replaced with special lookahead tokens depending on the next token,
+/// so that the grammar can be handled with one token of lookahead:
+///
+/// - `FORMAT` followed by `JSON` becomes [`TokenKind::FormatLa`].
+/// - `NOT` followed by `BETWEEN`, `IN`, `LIKE`, `ILIKE`, or `SIMILAR`
+///   becomes [`TokenKind::NotLa`].
+/// - `NULLS` followed by `FIRST` or `LAST` becomes [`TokenKind::NullsLa`].
+/// - `WITH` followed by `TIME` or `ORDINALITY` becomes [`TokenKind::WithLa`].
+/// - `WITHOUT` followed by `TIME` becomes [`TokenKind::WithoutLa`].
+#[derive(Debug)]
+pub(crate) struct TokenFilter<'a> {
+    lexer: Lexer<'a>,
+    /// A raw token read from the lexer to decide how to filter the previous one.
+    lookahead: Option<Token>,
+    /// A filtered token read by [`TokenFilter::peek_token`].
+    peeked: Option<Token>,
+    /// The end position of the most recently returned token.
+    last_end: usize,
+    /// The end position of the token returned before that.
+    prev_end: usize,
+}
+
+impl<'a> TokenFilter<'a> {
+    pub(crate) fn new(src: &'a str) -> Self {
+        Self {
+            lexer: Lexer::new(src),
+            lookahead: None,
+            peeked: None,
+            last_end: 0,
+            prev_end: 0,
+        }
+    }
+
+    pub(crate) fn next_token(&mut self, diags: &mut CodeDiagnostics) -> Token {
+        let tok = match self.peeked.take() {
+            Some(tok) => tok,
+            None => self.filter_token(diags),
+        };
+        self.prev_end = self.last_end;
+        self.last_end = tok.range.end;
+        tok
+    }
+
+    /// Returns the token that the next call to [`TokenFilter::next_token`] returns,
+    /// for the few places where the grammar needs two tokens of lookahead.
+    pub(crate) fn peek_token(&mut self, diags: &mut CodeDiagnostics) -> &Token {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.filter_token(diags));
+        }
+        self.peeked.as_ref().unwrap()
+    }
+
+    /// The end position of the token returned before the most recent one.
+    ///
+    /// While the parser holds the most recent token as its lookahead,
+    /// this is the end of the last token it has consumed.
+    pub(crate) fn prev_end(&self) -> usize {
+        self.prev_end
+    }
+
+    fn filter_token(&mut self, diags: &mut CodeDiagnostics) -> Token {
+        let tok0 = match self.lookahead.take() {
+            Some(tok) => tok,
+            None => self.lexer.next_token(diags),
+        };
+        let TokenKind::Identifier {
+            name: ref name0,
+            quoted: false,
+        } = tok0.kind
+        else {
+            return tok0;
+        };
+        if !matches!(
+            *name0,
+            Symbol::KEYWORD_format
+                | Symbol::KEYWORD_not
+                | Symbol::KEYWORD_nulls
+                | Symbol::KEYWORD_with
+                | Symbol::KEYWORD_without
+        ) {
+            return tok0;
+        }
+
+        let tok1 = self.lexer.next_token(diags);
+        let name1 = match &tok1.kind {
+            TokenKind::Identifier {
+                name,
+                quoted: false,
+            } => Some(name.clone()),
+            _ => None,
+        };
+        let kind = match (name0.clone(), name1) {
+            (Symbol::KEYWORD_format, Some(Symbol::KEYWORD_json)) => Some(TokenKind::FormatLa),
+            (
+                Symbol::KEYWORD_not,
+                Some(
+                    Symbol::KEYWORD_between
+                    | Symbol::KEYWORD_in
+                    | Symbol::KEYWORD_like
+                    | Symbol::KEYWORD_ilike
+                    | Symbol::KEYWORD_similar,
+                ),
+            ) => Some(TokenKind::NotLa),
+            (Symbol::KEYWORD_nulls, Some(Symbol::KEYWORD_first | Symbol::KEYWORD_last)) => {
+                Some(TokenKind::NullsLa)
+            }
+            (Symbol::KEYWORD_with, Some(Symbol::KEYWORD_time | Symbol::KEYWORD_ordinality)) => {
+                Some(TokenKind::WithLa)
+            }
+            (Symbol::KEYWORD_without, Some(Symbol::KEYWORD_time)) => Some(TokenKind::WithoutLa),
+            _ => None,
+        };
+        self.lookahead = Some(tok1);
+        match kind {
+            Some(kind) => Token {
+                kind,
+                range: tok0.range,
+            },
+            None => tok0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pos::{CodeRange, pos};
+
+    use super::*;
+
+    fn lex_filtered(src: &str) -> Vec<Token> {
+        let mut diags = CodeDiagnostics::new();
+        let mut filter = TokenFilter::new(src);
+        let mut tokens = Vec::new();
+        loop {
+            let token = filter.next_token(&mut diags);
+            if token.kind == TokenKind::Eof {
+                break;
+            }
+            tokens.push(token);
+        }
+        assert_eq!(diags.diagnostics, vec![]);
+        tokens
+    }
+
+    fn tok(kind: TokenKind, range: CodeRange) -> Token {
+        Token { kind, range }
+    }
+
+    fn ident(name: &str, range: CodeRange) -> Token {
+        tok(
+            TokenKind::Identifier {
+                name: Symbol::from(name),
+                quoted: false,
+            },
+            range,
+        )
+    }
+
+    #[test]
+    fn test_peek_token() {
+        let src = "select a, b";
+        let mut diags = CodeDiagnostics::new();
+        let mut filter = TokenFilter::new(src);
+        assert_eq!(
+            filter.next_token(&mut diags),
+            ident("select", pos(src, "select", 0))
+        );
+        assert_eq!(filter.peek_token(&mut diags), &ident("a", pos(src, "a", 0)));
+        assert_eq!(filter.prev_end(), 0);
+        assert_eq!(filter.next_token(&mut diags), ident("a", pos(src, "a", 0)));
+        assert_eq!(filter.prev_end(), pos(src, "select", 0).end);
+        assert_eq!(
+            filter.next_token(&mut diags),
+            tok(TokenKind::Comma, pos(src, ",", 0))
+        );
+        assert_eq!(filter.prev_end(), pos(src, "a", 0).end);
+    }
+
+    #[test]
+    fn test_filter_not_la() {
+        for next in ["between", "in", "like", "ILIKE", "similar"] {
+            let src = format!("not {next}");
+            let tokens = lex_filtered(&src);
+            assert_eq!(
+                tokens,
+                vec![
+                    tok(TokenKind::NotLa, pos(&src, "not", 0)),
+                    ident(&next.to_ascii_lowercase(), pos(&src, next, 0)),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_filter_not_other() {
+        let src = "not null";
+        let tokens = lex_filtered(src);
+        assert_eq!(
+            tokens,
+            vec![
+                ident("not", pos(src, "not", 0)),
+                ident("null", pos(src, "null", 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_nulls_la() {
+        let src = "nulls first nulls last nulls x";
+        let tokens = lex_filtered(src);
+        assert_eq!(
+            tokens,
+            vec![
+                tok(TokenKind::NullsLa, pos(src, "nulls", 0)),
+                ident("first", pos(src, "first", 0)),
+                tok(TokenKind::NullsLa, pos(src, "nulls", 1)),
+                ident("last", pos(src, "last", 0)),
+                ident("nulls", pos(src, "nulls", 2)),
+                ident("x", pos(src, "x", 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_with_la() {
+        let src = "with time with ordinality with recursive";
+        let tokens = lex_filtered(src);
+        assert_eq!(
+            tokens,
+            vec![
+                tok(TokenKind::WithLa, pos(src, "with", 0)),
+                ident("time", pos(src, "time", 0)),
+                tok(TokenKind::WithLa, pos(src, "with", 1)),
+                ident("ordinality", pos(src, "ordinality", 0)),
+                ident("with", pos(src, "with", 2)),
+                ident("recursive", pos(src, "recursive", 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_without_la() {
+        let src = "without time";
+        let tokens = lex_filtered(src);
+        assert_eq!(
+            tokens,
+            vec![
+                tok(TokenKind::WithoutLa, pos(src, "without", 0)),
+                ident("time", pos(src, "time", 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_format_la() {
+        let src = "format json";
+        let tokens = lex_filtered(src);
+        assert_eq!(
+            tokens,
+            vec![
+                tok(TokenKind::FormatLa, pos(src, "format", 0)),
+                ident("json", pos(src, "json", 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_consecutive_candidates() {
+        // The lookahead token is itself subject to replacement.
+        let src = "not not in";
+        let tokens = lex_filtered(src);
+        assert_eq!(
+            tokens,
+            vec![
+                ident("not", pos(src, "not", 0)),
+                tok(TokenKind::NotLa, pos(src, "not", 1)),
+                ident("in", pos(src, "in", 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_quoted_keywords_are_not_replaced() {
+        let src = r#"not "in" "not" in"#;
+        let tokens = lex_filtered(src);
+        assert_eq!(
+            tokens,
+            vec![
+                ident("not", pos(src, "not", 0)),
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from("in"),
+                        quoted: true
+                    },
+                    pos(src, r#""in""#, 0)
+                ),
+                tok(
+                    TokenKind::Identifier {
+                        name: Symbol::from("not"),
+                        quoted: true
+                    },
+                    pos(src, r#""not""#, 0)
+                ),
+                ident("in", pos(src, "in", 1)),
+            ]
+        );
+    }
+}